@@ -0,0 +1,368 @@
+//! Animations of the days that simulate something step by step. The simulation calls
+//! [`Recorder::record`] after every step, and a [`Recording`] turns those steps into the frames of
+//! an animated GIF, or into a directory with a numbered PNG file per frame.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use gif::{DisposalMethod, Encoder, Frame, Repeat};
+
+use crate::render::{Crop, Picture, Rgb};
+
+/// The time every frame of a GIF is shown, in hundredths of a second.
+const FRAME_DELAY: u16 = 5;
+
+/// Something that keeps track of the steps of a simulation.
+pub trait Recorder {
+    /// Records a step of the simulation. `frame` is only drawn for the steps that end up in the
+    /// animation, so skipped steps cost next to nothing.
+    fn record(&mut self, frame: impl FnOnce() -> Picture);
+}
+
+/// Records nothing, for when the simulation only runs for its answer.
+impl Recorder for () {
+    fn record(&mut self, _frame: impl FnOnce() -> Picture) {}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// The number of steps that are left out after every frame.
+    pub frame_skip: usize,
+    /// The part of every frame to keep.
+    pub crop: Option<Crop>,
+}
+
+/// Writes the recorded steps of a simulation to a file as they come in, so long simulations
+/// don't have to fit in memory. Nothing is written until the first step is recorded.
+pub struct Recording {
+    options: Options,
+    output: Output,
+    steps: usize,
+    frames: usize,
+    error: Option<String>,
+}
+
+enum Output {
+    Gif { path: PathBuf, gif: Option<Gif> },
+    Frames(PathBuf),
+}
+
+impl Recording {
+    /// Records to an animated GIF when the path ends in `.gif`, and to numbered PNG files in the
+    /// directory at the path otherwise.
+    pub fn new(path: &Path, options: Options) -> Recording {
+        let is_gif = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("gif"));
+
+        let output = match is_gif {
+            true => Output::Gif {
+                path: path.to_path_buf(),
+                gif: None,
+            },
+            false => Output::Frames(path.to_path_buf()),
+        };
+
+        Recording {
+            options,
+            output,
+            steps: 0,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// Finishes the animation, returning the number of frames in it.
+    pub fn finish(self) -> Result<usize, String> {
+        if let Some(message) = self.error {
+            return Err(message);
+        }
+
+        if self.frames == 0 {
+            return Err("The simulation didn't record any steps".to_string());
+        }
+
+        if let Output::Gif {
+            path,
+            gif: Some(gif),
+            ..
+        } = self.output
+        {
+            gif.encoder
+                .into_inner()
+                .and_then(|mut file| Ok(file.flush()?))
+                .map_err(|error| format!("Could not write {}: {error}", path.display()))?;
+        }
+
+        Ok(self.frames)
+    }
+
+    fn write(&mut self, picture: &Picture) -> Result<(), String> {
+        if picture.width() == 0 || picture.height() == 0 {
+            return Err("The crop is outside of the picture".to_string());
+        }
+
+        match &mut self.output {
+            Output::Gif { path, gif } => {
+                let (width, height, pixels) = picture.rasterise();
+                let error = |error| format!("Could not write {}: {error}", path.display());
+
+                let gif = match gif {
+                    Some(gif) => gif,
+                    None => gif.insert(Gif::create(path, width, height).map_err(error)?),
+                };
+
+                if (width, height) != (gif.width, gif.height) {
+                    return Err("Every frame of an animation must have the same size".to_string());
+                }
+
+                gif.write_frame(pixels).map_err(error)
+            }
+            Output::Frames(directory) => {
+                if self.frames == 0 {
+                    fs::create_dir_all(&directory).map_err(|error| {
+                        format!("Could not create {}: {error}", directory.display())
+                    })?;
+                }
+
+                picture.save(&directory.join(format!("{:05}.png", self.frames + 1)))
+            }
+        }
+    }
+}
+
+impl Recorder for Recording {
+    fn record(&mut self, frame: impl FnOnce() -> Picture) {
+        let step = self.steps;
+        self.steps += 1;
+
+        if self.error.is_some() || !step.is_multiple_of(self.options.frame_skip + 1) {
+            return;
+        }
+
+        let mut picture = frame();
+
+        if let Some(crop) = self.options.crop {
+            picture = picture.crop(crop);
+        }
+
+        match self.write(&picture) {
+            Ok(()) => self.frames += 1,
+            Err(message) => self.error = Some(message),
+        }
+    }
+}
+
+/// An animated GIF that is being written. Simulations tend to change only a small part of the
+/// picture every step, so every frame only holds the rectangle that changed since the last one.
+struct Gif {
+    encoder: Encoder<BufWriter<File>>,
+    width: usize,
+    height: usize,
+    previous: Option<Vec<Rgb>>,
+}
+
+impl Gif {
+    fn create(path: &Path, width: usize, height: usize) -> Result<Gif, gif::EncodingError> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = Encoder::new(file, width as u16, height as u16, &[])?;
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        Ok(Gif {
+            encoder,
+            width,
+            height,
+            previous: None,
+        })
+    }
+
+    fn write_frame(&mut self, pixels: Vec<Rgb>) -> Result<(), gif::EncodingError> {
+        let (left, top, width, height) = match &self.previous {
+            Some(previous) => changed_rectangle(previous, &pixels, self.width),
+            None => (0, 0, self.width, self.height),
+        };
+
+        let changed: Vec<Rgb> = (top..top + height)
+            .flat_map(|y| &pixels[y * self.width + left..y * self.width + left + width])
+            .copied()
+            .collect();
+
+        let mut frame = match palette(&changed) {
+            Some((palette, indices)) => {
+                Frame::from_palette_pixels(width as u16, height as u16, indices, palette, None)
+            }
+            None => {
+                let rgb: Vec<u8> = changed.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+                Frame::from_rgb_speed(width as u16, height as u16, &rgb, 10)
+            }
+        };
+
+        frame.left = left as u16;
+        frame.top = top as u16;
+        frame.delay = FRAME_DELAY;
+        frame.dispose = DisposalMethod::Keep;
+
+        self.encoder.write_frame(&frame)?;
+        self.previous = Some(pixels);
+
+        Ok(())
+    }
+}
+
+/// The smallest rectangle that holds all pixels that differ, as `(left, top, width, height)`. A
+/// frame that didn't change at all still gets a single pixel, so it keeps its place in time.
+fn changed_rectangle(
+    previous: &[Rgb],
+    pixels: &[Rgb],
+    width: usize,
+) -> (usize, usize, usize, usize) {
+    let mut changed = previous
+        .iter()
+        .zip(pixels)
+        .enumerate()
+        .filter(|(_, (before, after))| before != after)
+        .map(|(index, _)| (index % width, index / width));
+
+    let Some((x, y)) = changed.next() else {
+        return (0, 0, 1, 1);
+    };
+
+    // The pixels come row by row, so the first one is in the top row.
+    let (left, right, bottom) = changed.fold((x, x, y), |(left, right, _), (x, y)| {
+        (left.min(x), right.max(x), y)
+    });
+
+    (left, y, right - left + 1, bottom - y + 1)
+}
+
+/// The colours of the pixels as a GIF palette, with the index of every pixel in it. Pictures with
+/// more than 256 colours don't fit in a palette.
+fn palette(pixels: &[Rgb]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut colours: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette = vec![];
+    let mut indices = Vec::with_capacity(pixels.len());
+
+    for &pixel in pixels {
+        let index = match colours.get(&pixel) {
+            Some(&index) => index,
+            None => {
+                let index = u8::try_from(colours.len()).ok()?;
+                colours.insert(pixel, index);
+                palette.extend([pixel.0, pixel.1, pixel.2]);
+                index
+            }
+        };
+
+        indices.push(index);
+    }
+
+    Some((palette, indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-animation-{name}-{}", std::process::id()))
+    }
+
+    fn simulate(recorder: &mut impl Recorder, steps: usize) {
+        for step in 0..steps {
+            recorder.record(|| Picture::new(4, 1, Rgb::BLACK).highlight([(step % 4, 0)], Rgb::RED));
+        }
+    }
+
+    #[test]
+    fn writes_a_gif() {
+        let path = directory("gif").with_extension("gif");
+        let mut recording = Recording::new(&path, Options::default());
+        simulate(&mut recording, 6);
+
+        assert_eq!(Ok(6), recording.finish());
+
+        let gif = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new().read_info(&gif[..]).unwrap();
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.left, frame.top, frame.width, frame.height));
+        }
+
+        // The picture is 4 cells of 16 pixels, after the first frame only the two cells where
+        // the red cell moved from and to change.
+        assert_eq!((64, 16), (decoder.width(), decoder.height()));
+        assert_eq!(6, frames.len());
+        assert_eq!((0, 0, 64, 16), frames[0]);
+        assert_eq!((0, 0, 32, 16), frames[1]);
+        assert_eq!((0, 0, 64, 16), frames[4]);
+    }
+
+    #[test]
+    fn writes_numbered_frames_with_skips_and_crops() {
+        let path = directory("frames");
+        let options = Options {
+            frame_skip: 2,
+            crop: Some(Crop {
+                x: 1,
+                y: 0,
+                width: 2,
+                height: 1,
+            }),
+        };
+
+        let mut recording = Recording::new(&path, options);
+        simulate(&mut recording, 7);
+
+        assert_eq!(Ok(3), recording.finish());
+
+        let mut frames: Vec<_> = fs::read_dir(&path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        frames.sort();
+        fs::remove_dir_all(&path).unwrap();
+
+        assert_eq!(vec!["00001.png", "00002.png", "00003.png"], frames);
+    }
+
+    #[test]
+    fn reports_crops_outside_of_the_picture() {
+        let path = directory("outside");
+        let options = Options {
+            frame_skip: 0,
+            crop: Some(Crop {
+                x: 10,
+                y: 10,
+                width: 2,
+                height: 2,
+            }),
+        };
+
+        let mut recording = Recording::new(&path, options);
+        simulate(&mut recording, 2);
+
+        assert_eq!(
+            Err("The crop is outside of the picture".to_string()),
+            recording.finish()
+        );
+        assert!(!path.exists(), "Nothing is written for a failed frame");
+    }
+
+    #[test]
+    fn finds_the_changed_rectangle() {
+        let before = vec![Rgb::BLACK; 12];
+        let mut after = before.clone();
+        after[5] = Rgb::RED;
+        after[10] = Rgb::RED;
+
+        // A 4x3 picture where (1, 1) and (2, 2) changed.
+        assert_eq!((1, 1, 2, 2), changed_rectangle(&before, &after, 4));
+        assert_eq!((0, 0, 1, 1), changed_rectangle(&before, &before, 4));
+    }
+}
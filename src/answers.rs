@@ -0,0 +1,227 @@
+//! The registry of known answers, stored in `answers.toml` and keyed by day, input and part:
+//!
+//! ```toml
+//! [day20.input]
+//! part1 = 898557000
+//! part2 = { skip = "never terminates" }
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::solution::{Answer, Failure, Part};
+
+/// The registry file, relative to the working directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// What a part is expected to answer for a single input.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+    /// The part is not run for this input, e.g. because it never finishes.
+    Skip {
+        skip: String,
+    },
+}
+
+impl Expected {
+    fn matches(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (Expected::Number(expected), Answer::Number(answer)) => expected == answer,
+            (Expected::Text(expected), Answer::Text(answer)) => expected == answer,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => write!(f, "{text}"),
+            Expected::Skip { skip } => write!(f, "skipped ({skip})"),
+        }
+    }
+}
+
+/// How a computed answer compares to the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    /// There is no known answer yet, so the computed one can be recorded.
+    New(Answer),
+    Skipped(String),
+    Unimplemented,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Registry {
+    days: BTreeMap<String, BTreeMap<String, BTreeMap<String, Expected>>>,
+}
+
+impl Registry {
+    /// Loads the registry from the given file. A missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = match fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(format!("Could not read {}: {error}", path.display())),
+        };
+
+        toml::from_str(&raw).map_err(|error| format!("Could not parse {}: {error}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_toml())
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: Part) -> Option<&Expected> {
+        self.days
+            .get(&day_key(day))?
+            .get(input)?
+            .get(&part_key(part))
+    }
+
+    /// Stores the answer as the known answer, unless the part is unimplemented.
+    pub fn record(&mut self, day: u8, input: &str, part: Part, answer: &Answer) {
+        let expected = match answer {
+            Answer::Number(number) => Expected::Number(*number),
+            Answer::Text(text) => Expected::Text(text.clone()),
+            Answer::Unimplemented => return,
+        };
+
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+            .insert(part_key(part), expected);
+    }
+
+    pub fn is_skipped(&self, day: u8, input: &str, part: Part) -> bool {
+        matches!(self.expected(day, input, part), Some(Expected::Skip { .. }))
+    }
+
+    pub fn verdict(
+        &self,
+        day: u8,
+        input: &str,
+        part: Part,
+        result: &Result<Answer, Failure>,
+    ) -> Verdict {
+        let expected = self.expected(day, input, part);
+
+        match (expected, result) {
+            (Some(Expected::Skip { skip }), _) => Verdict::Skipped(skip.clone()),
+            (_, Ok(Answer::Unimplemented)) => Verdict::Unimplemented,
+            (_, Err(failure)) => Verdict::Fail(failure.to_string()),
+            (None, Ok(answer)) => Verdict::New(answer.clone()),
+            (Some(expected), Ok(answer)) if expected.matches(answer) => Verdict::Pass,
+            (Some(expected), Ok(answer)) => {
+                Verdict::Fail(format!("expected {expected}, got {answer}"))
+            }
+        }
+    }
+
+    /// Writes the registry by hand, because the serializer would turn skipped parts into tables of
+    /// their own and put the examples before the puzzle input.
+    fn to_toml(&self) -> String {
+        let mut raw = String::new();
+
+        for (day, inputs) in &self.days {
+            let inputs = inputs
+                .iter()
+                .sorted_by_key(|(name, _)| (*name != "input", name.as_str()));
+
+            for (name, parts) in inputs {
+                raw.push_str(&format!("\n[{day}.{name}]\n"));
+
+                for (part, expected) in parts {
+                    let value = match expected {
+                        Expected::Number(number) => number.to_string(),
+                        Expected::Text(text) => toml::Value::from(text.as_str()).to_string(),
+                        Expected::Skip { skip } => {
+                            format!("{{ skip = {} }}", toml::Value::from(skip.as_str()))
+                        }
+                    };
+
+                    raw.push_str(&format!("{part} = {value}\n"));
+                }
+            }
+        }
+
+        raw.trim_start().to_string()
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{part}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"
+        [day20.input]
+        part1 = 898557000
+        part2 = { skip = "never terminates" }
+
+        [day20.example-2]
+        part1 = 11687500
+    "#;
+
+    #[test]
+    fn compares_answers_to_the_registry() {
+        let registry: Registry = toml::from_str(REGISTRY).unwrap();
+
+        assert_eq!(
+            Verdict::Pass,
+            registry.verdict(20, "input", Part::One, &Ok(898557000.into()))
+        );
+        assert_eq!(
+            Verdict::Fail("expected 11687500, got 32000000".to_string()),
+            registry.verdict(20, "example-2", Part::One, &Ok(32000000.into()))
+        );
+        assert_eq!(
+            Verdict::Skipped("never terminates".to_string()),
+            registry.verdict(20, "input", Part::Two, &Ok(1.into()))
+        );
+        assert_eq!(
+            Verdict::New(Answer::from(32000000)),
+            registry.verdict(20, "example", Part::One, &Ok(32000000.into()))
+        );
+        assert_eq!(
+            Verdict::Unimplemented,
+            registry.verdict(20, "example", Part::Two, &Ok(Answer::Unimplemented))
+        );
+    }
+
+    #[test]
+    fn records_new_answers() {
+        let mut registry: Registry = toml::from_str(REGISTRY).unwrap();
+
+        registry.record(19, "input", Part::Two, &130745440937650i64.into());
+        registry.record(19, "input", Part::One, &Answer::Unimplemented);
+
+        let saved: Registry = toml::from_str(&registry.to_toml()).unwrap();
+
+        assert_eq!(
+            Some(&Expected::Number(130745440937650)),
+            saved.expected(19, "input", Part::Two)
+        );
+        assert_eq!(None, saved.expected(19, "input", Part::One));
+        assert!(saved.is_skipped(20, "input", Part::Two));
+    }
+}
@@ -0,0 +1,162 @@
+//! An Aho–Corasick automaton, which finds every occurrence of a set of patterns in a single pass
+//! over the text. Occurrences may overlap, so `eightwo` holds both `eight` and `two`.
+
+use std::collections::VecDeque;
+
+/// The state every search starts in, for the empty prefix.
+const ROOT: usize = 0;
+
+/// A compiled set of patterns, each with a value that is handed back for its matches.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    /// The state to go to from every state for every byte, with the failure links already
+    /// followed, so a search does a single lookup per byte.
+    transitions: Vec<[usize; 256]>,
+    /// The patterns that end in every state, including the ones that end in a suffix of it.
+    outputs: Vec<Vec<usize>>,
+    patterns: Vec<(usize, T)>,
+}
+
+/// A pattern that was found in the text, at the bytes `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, T> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a T,
+}
+
+impl<T> Automaton<T> {
+    /// Compiles the patterns. Empty patterns would match everywhere, so they're left out.
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = (P, T)>) -> Automaton<T> {
+        let mut automaton = Automaton {
+            transitions: vec![[ROOT; 256]],
+            outputs: vec![vec![]],
+            patterns: vec![],
+        };
+
+        // The trie of the patterns, where a missing edge still points to the root.
+        let mut has_edge = vec![[false; 256]];
+
+        for (pattern, value) in patterns {
+            let pattern = pattern.as_ref();
+
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut state = ROOT;
+
+            for &byte in pattern {
+                if !has_edge[state][byte as usize] {
+                    automaton.transitions.push([ROOT; 256]);
+                    automaton.outputs.push(vec![]);
+                    has_edge.push([false; 256]);
+
+                    has_edge[state][byte as usize] = true;
+                    automaton.transitions[state][byte as usize] = automaton.transitions.len() - 1;
+                }
+
+                state = automaton.transitions[state][byte as usize];
+            }
+
+            automaton.outputs[state].push(automaton.patterns.len());
+            automaton.patterns.push((pattern.len(), value));
+        }
+
+        automaton.link(&has_edge);
+        automaton
+    }
+
+    /// Turns the trie into an automaton, breadth first, so the failure link of every state is done
+    /// before the states below it need it.
+    fn link(&mut self, has_edge: &[[bool; 256]]) {
+        let mut fail = vec![ROOT; self.transitions.len()];
+        let mut queue: VecDeque<usize> = (0..256)
+            .filter(|&byte| has_edge[ROOT][byte])
+            .map(|byte| self.transitions[ROOT][byte])
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            let inherited = self.outputs[fail[state]].clone();
+            self.outputs[state].extend(inherited);
+
+            for (byte, &edge) in has_edge[state].iter().enumerate() {
+                match edge {
+                    true => {
+                        let next = self.transitions[state][byte];
+                        fail[next] = self.transitions[fail[state]][byte];
+                        queue.push_back(next);
+                    }
+                    false => self.transitions[state][byte] = self.transitions[fail[state]][byte],
+                }
+            }
+        }
+    }
+
+    /// Every occurrence of every pattern, ordered by where it ends, and shortest first for
+    /// occurrences that end at the same byte.
+    pub fn find_overlapping<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<'a, T>> {
+        text.bytes()
+            .enumerate()
+            .scan(ROOT, |state, (index, byte)| {
+                *state = self.transitions[*state][byte as usize];
+                Some((index + 1, *state))
+            })
+            .flat_map(move |(end, state)| {
+                self.outputs[state].iter().rev().map(move |&pattern| {
+                    let (length, value) = &self.patterns[pattern];
+
+                    Match {
+                        start: end - length,
+                        end,
+                        value,
+                    }
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(automaton: &'a Automaton<u32>, text: &'a str) -> Vec<(&'a str, u32)> {
+        automaton
+            .find_overlapping(text)
+            .map(|found| (&text[found.start..found.end], *found.value))
+            .collect()
+    }
+
+    #[test]
+    fn finds_overlapping_patterns() {
+        let automaton = Automaton::new([("eight", 8), ("two", 2), ("one", 1), ("nine", 9)]);
+
+        assert_eq!(
+            vec![("eight", 8), ("two", 2), ("one", 1)],
+            find(&automaton, "eightwone")
+        );
+        assert_eq!(vec![("nine", 9)], find(&automaton, "nnine"));
+        assert!(find(&automaton, "eigh tw o").is_empty());
+    }
+
+    #[test]
+    fn finds_patterns_inside_of_other_patterns() {
+        let automaton = Automaton::new([("seventeen", 17), ("seven", 7), ("teen", 0), ("", 5)]);
+
+        assert_eq!(
+            vec![("seven", 7), ("teen", 0), ("seventeen", 17)],
+            find(&automaton, "seventeen")
+        );
+    }
+
+    #[test]
+    fn matches_multi_byte_characters_on_their_boundaries() {
+        let automaton = Automaton::new([("zwölf", 12), ("drei", 3), ("één", 1)]);
+        let text = "ééénzwölfdrei";
+
+        assert_eq!(
+            vec![("één", 1), ("zwölf", 12), ("drei", 3)],
+            find(&automaton, text)
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Timing statistics for `aoc bench`.
+
+use std::{fmt::Display, time::Duration};
+
+use crate::solution::Part;
+
+/// What a benchmark times: parsing the input, or solving one of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    /// The name of the stage in JSON and CSV output.
+    pub fn key(&self) -> String {
+        match self {
+            Stage::Parse => "parse".to_string(),
+            Stage::Part(part) => format!("part{part}"),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises the durations of a benchmark. Percentiles use the nearest-rank method, so
+    /// every statistic is one of the measured durations.
+    pub fn new(durations: &[Duration]) -> Self {
+        assert!(!durations.is_empty(), "cannot summarise zero runs");
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let percentile = |percent: usize| sorted[(sorted.len() * percent).div_ceil(100) - 1];
+
+        Stats {
+            min: sorted[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_durations() {
+        let durations: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+
+        assert_eq!(
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(10),
+                p95: Duration::from_millis(19),
+            },
+            Stats::new(&durations)
+        );
+    }
+
+    #[test]
+    fn summarises_a_single_run() {
+        let stats = Stats::new(&[Duration::from_millis(7)]);
+
+        assert_eq!(Duration::from_millis(7), stats.min);
+        assert_eq!(Duration::from_millis(7), stats.median);
+        assert_eq!(Duration::from_millis(7), stats.p95);
+    }
+}
@@ -0,0 +1,202 @@
+//! Cooperative cancellation of parts that run for too long. The runner gives every part a time
+//! budget, and solvers with long loops ask a [`Token`] whether they should stop. The runner reports
+//! a cancelled part as timed out and ignores whatever it returns, so a solver can return anything
+//! once it's cancelled. Parts that never check keep running until they finish.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::watcher;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<State>>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug)]
+struct State {
+    /// When the budget started, which is moved up by every run of [`with_budget_per_run`].
+    started: Mutex<Instant>,
+    /// Set by a watchdog thread once the budget runs out.
+    expired: AtomicBool,
+    /// Set once a solver noticed it should stop.
+    cancelled: AtomicBool,
+}
+
+/// Tells a solver whether the part it's solving ran out of time. Tokens can be handed to other
+/// threads, like the ones of a parallel iterator.
+#[derive(Debug, Clone)]
+pub struct Token {
+    state: Option<Arc<State>>,
+}
+
+impl Token {
+    /// The token of the part that runs on this thread. Outside of the runner, like in the tests,
+    /// it's never cancelled.
+    pub fn current() -> Token {
+        Token {
+            state: CURRENT.with_borrow(Clone::clone),
+        }
+    }
+
+    /// Whether the solver should stop. Cheap enough to call in every iteration of a hot loop.
+    pub fn is_cancelled(&self) -> bool {
+        let Some(state) = &self.state else {
+            return false;
+        };
+
+        if !state.expired.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        state.cancelled.store(true, Ordering::Relaxed);
+        true
+    }
+}
+
+/// Runs `f`, cancelling the tokens it takes once `budget` has passed. Returns `None` when `f`
+/// was cancelled, and the result of `f` otherwise.
+pub fn with_budget<T>(budget: Option<Duration>, f: impl FnOnce() -> T) -> Option<T> {
+    with_budget_per_run(budget, |_| f())
+}
+
+/// Starts the budget of [`with_budget_per_run`] over for every run.
+#[derive(Debug)]
+pub struct Runs {
+    state: Option<Arc<State>>,
+}
+
+impl Runs {
+    /// Gives the run that starts now the whole budget.
+    pub fn start(&self) {
+        if let Some(state) = &self.state {
+            *state.started.lock().unwrap() = Instant::now();
+        }
+    }
+}
+
+/// Like [`with_budget`], but `f` runs something several times and every run gets the budget, as
+/// long as it calls [`Runs::start`] first. A single watchdog looks after all runs, so starting a
+/// run is cheap enough to time runs of a few nanoseconds.
+pub fn with_budget_per_run<T>(budget: Option<Duration>, f: impl FnOnce(&Runs) -> T) -> Option<T> {
+    let Some(budget) = budget else {
+        return Some(f(&Runs { state: None }));
+    };
+
+    let state = Arc::new(State {
+        started: Mutex::new(Instant::now()),
+        expired: AtomicBool::new(false),
+        cancelled: AtomicBool::new(false),
+    });
+
+    let watchdog = {
+        let state = Arc::clone(&state);
+
+        move |running: watcher::Running| loop {
+            let started = *state.started.lock().unwrap();
+            let left = (started + budget).saturating_duration_since(Instant::now());
+
+            if !running.wait(left) {
+                return;
+            }
+
+            // Only expire when no other run started while waiting.
+            if *state.started.lock().unwrap() == started {
+                state.expired.store(true, Ordering::Relaxed);
+                return;
+            }
+        }
+    };
+
+    let runs = Runs {
+        state: Some(Arc::clone(&state)),
+    };
+    let result = watcher::watch(&CURRENT, Arc::clone(&state), watchdog, || f(&runs));
+
+    match state.cancelled.load(Ordering::Relaxed) {
+        true => None,
+        false => Some(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    fn count_until_cancelled(limit: u64) -> u64 {
+        let token = Token::current();
+
+        (0..limit).take_while(|_| !token.is_cancelled()).count() as u64
+    }
+
+    #[test]
+    fn is_never_cancelled_without_a_budget() {
+        assert_eq!(
+            Some(10_000),
+            with_budget(None, || count_until_cancelled(10_000))
+        );
+        assert_eq!(10_000, count_until_cancelled(10_000));
+    }
+
+    #[test]
+    fn cancels_once_the_budget_runs_out() {
+        let budget = Some(Duration::from_millis(10));
+
+        assert_eq!(
+            None,
+            with_budget(budget, || count_until_cancelled(u64::MAX))
+        );
+        assert!(!Token::current().is_cancelled());
+    }
+
+    #[test]
+    fn keeps_parts_that_finish_in_time() {
+        let budget = Some(Duration::from_secs(60));
+
+        assert_eq!(
+            Some(100),
+            with_budget(budget, || count_until_cancelled(100))
+        );
+    }
+
+    #[test]
+    fn gives_every_run_the_whole_budget() {
+        let budget = Some(Duration::from_millis(30));
+
+        let runs = with_budget_per_run(budget, |runs| {
+            (0..4)
+                .map(|_| {
+                    runs.start();
+                    thread::sleep(Duration::from_millis(20));
+                    Token::current().is_cancelled()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(Some(vec![false; 4]), runs);
+        assert_eq!(
+            None,
+            with_budget_per_run(budget, |runs| {
+                runs.start();
+                count_until_cancelled(u64::MAX)
+            })
+        );
+    }
+
+    #[test]
+    fn keeps_parts_that_never_check() {
+        let budget = Some(Duration::from_millis(1));
+
+        assert_eq!(
+            Some(()),
+            with_budget(budget, || thread::sleep(Duration::from_millis(20)))
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! Downloads puzzle inputs and submits answers to the Advent of Code website.
+
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ureq::Agent;
+
+use crate::solution::{Answer, Part};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The least amount of time between two requests, to go easy on the website.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The file next to the inputs that holds the time of the last request, so requests of separate
+/// invocations are throttled as well.
+pub const LAST_REQUEST_FILE: &str = ".last-request";
+
+const USER_AGENT: &str = "github.com/OneBigOwnage/advent-of-code-2023-rust";
+
+/// How the website responded to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently, the message says how long to wait.
+    TooSoon(String),
+    /// The part has already been solved, or part 1 hasn't been solved yet.
+    WrongLevel,
+    /// A response we don't recognise, with its message.
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooSoon(message) => write!(f, "too soon: {message}"),
+            Verdict::WrongLevel => write!(f, "already solved, or the other part isn't yet"),
+            Verdict::Unknown(message) => write!(f, "unknown response: {message}"),
+        }
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+    year: u16,
+    min_interval: Duration,
+    last_request: Option<SystemTime>,
+    last_request_file: Option<PathBuf>,
+}
+
+impl Client {
+    /// A client that only needs a session once it makes a request.
+    pub fn new(base_url: &str, session: Option<&str>, year: u16) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::to_string),
+            year,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+            last_request_file: None,
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Shares the time of the last request with other clients through a file.
+    pub fn with_last_request_file(mut self, path: &Path) -> Self {
+        self.last_request_file = Some(path.to_path_buf());
+        self
+    }
+
+    /// Downloads the input of the given day to `path`, unless it's already there. An empty file,
+    /// like the one `aoc new-day` creates, doesn't count. Returns whether the input was downloaded.
+    pub fn cache_input(&mut self, day: u8, path: &Path) -> Result<bool, String> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(false);
+        }
+
+        let input = self.fetch_input(day)?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("Could not create {}: {error}", directory.display()))?;
+        }
+
+        fs::write(path, input)
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))?;
+
+        Ok(true)
+    }
+
+    pub fn fetch_input(&mut self, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let cookie = self.cookie()?;
+
+        self.throttle();
+
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", cookie)
+            .call()
+            .map_err(|error| format!("Could not download {url}: {error}"))?;
+
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| format!("Could not download {url}: {error}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Could not download {url}: {}: {}",
+                response.status(),
+                body.trim()
+            ));
+        }
+
+        // The inputs in `inputs/` are stored without the final newline.
+        Ok(body.trim_end_matches('\n').to_string())
+    }
+
+    pub fn submit(&mut self, day: u8, part: Part, answer: &Answer) -> Result<Verdict, String> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let cookie = self.cookie()?;
+
+        self.throttle();
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", cookie)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .map_err(|error| format!("Could not submit to {url}: {error}"))?;
+
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| format!("Could not submit to {url}: {error}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Could not submit to {url}: {}", response.status()));
+        }
+
+        Ok(parse_verdict(&body))
+    }
+
+    fn cookie(&self) -> Result<String, String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err("Set --session or AOC_SESSION to talk to the website".to_string()),
+        }
+    }
+
+    /// Sleeps until at least `min_interval` has passed since the previous request, of this client
+    /// or of any other client with the same last request file.
+    fn throttle(&mut self) {
+        let last_request = self.last_request.max(self.read_last_request());
+
+        if let Some(last_request) = last_request {
+            let elapsed = last_request.elapsed().unwrap_or_default();
+
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        self.write_last_request(now);
+    }
+
+    fn read_last_request(&self) -> Option<SystemTime> {
+        let nanos = fs::read_to_string(self.last_request_file.as_ref()?).ok()?;
+
+        Some(UNIX_EPOCH + Duration::from_nanos(nanos.trim().parse().ok()?))
+    }
+
+    /// Failing to write the file only means the next invocation might not wait long enough.
+    fn write_last_request(&self, time: SystemTime) {
+        let Some(path) = &self.last_request_file else {
+            return;
+        };
+
+        // Whole nanoseconds, as rounding down would make the next request a little too early.
+        let nanos = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        if let Some(directory) = path.parent() {
+            let _ = fs::create_dir_all(directory);
+        }
+
+        let _ = fs::write(path, nanos.to_string());
+    }
+}
+
+/// Reads the verdict from the page that is shown after submitting an answer.
+fn parse_verdict(page: &str) -> Verdict {
+    let message = article(page);
+
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        let wait = message
+            .find("You have ")
+            .map(|start| &message[start..])
+            .and_then(|rest| rest.split_once('.'))
+            .map_or(message.as_str(), |(wait, _)| wait);
+
+        Verdict::TooSoon(wait.to_string())
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(message)
+    }
+}
+
+/// The text of the `<article>` of a page, without any tags.
+fn article(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            char if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// Serves the given responses in order from a local port, and passes on every request it
+    /// receives. Returns the base URL of the server.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.send(request).unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, received)
+    }
+
+    #[test]
+    fn downloads_an_input_once() {
+        let (base_url, requests) = mock_server(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let path = std::env::temp_dir().join(format!("aoc-client-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut client =
+            Client::new(&base_url, Some("secret"), 2023).with_min_interval(Duration::ZERO);
+
+        assert_eq!(Ok(true), client.cache_input(1, &path));
+        assert_eq!(Ok(false), client.cache_input(1, &path));
+        assert_eq!("1abc2\npqr3stu8vwx", fs::read_to_string(&path).unwrap());
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input "));
+        assert!(request.contains("session=secret"));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn downloads_over_an_empty_input() {
+        let (base_url, _requests) = mock_server(vec![(200, "1abc2\n")]);
+        let path = std::env::temp_dir().join(format!("aoc-empty-{}.txt", std::process::id()));
+        fs::write(&path, "").unwrap();
+
+        let mut client =
+            Client::new(&base_url, Some("secret"), 2023).with_min_interval(Duration::ZERO);
+
+        assert_eq!(Ok(true), client.cache_input(1, &path));
+        assert_eq!("1abc2", fs::read_to_string(&path).unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn only_needs_a_session_to_download() {
+        let path = std::env::temp_dir().join(format!("aoc-cached-{}.txt", std::process::id()));
+        fs::write(&path, "1abc2").unwrap();
+
+        let mut client = Client::new("http://localhost:0", None, 2023);
+
+        assert_eq!(Ok(false), client.cache_input(1, &path));
+        assert!(client.fetch_input(1).unwrap_err().contains("--session"));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reports_a_failed_download() {
+        let (base_url, _requests) = mock_server(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )]);
+
+        let mut client = Client::new(&base_url, Some("expired"), 2023);
+
+        let error = client.fetch_input(1).unwrap_err();
+        assert!(error.contains("400"), "{error}");
+        assert!(error.contains("Please log in"), "{error}");
+    }
+
+    #[test]
+    fn submits_an_answer() {
+        let (base_url, requests) = mock_server(vec![
+            (200, "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>"),
+            (200, "<main><article><p>That's the right answer!</p></article></main>"),
+        ]);
+
+        let mut client =
+            Client::new(&base_url, Some("secret"), 2023).with_min_interval(Duration::ZERO);
+
+        assert_eq!(
+            Ok(Verdict::TooHigh),
+            client.submit(20, Part::One, &Answer::from(900000000))
+        );
+        assert_eq!(
+            Ok(Verdict::Correct),
+            client.submit(20, Part::One, &Answer::from(898557000))
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/20/answer "));
+        assert!(request.ends_with("level=1&answer=900000000"));
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let (base_url, _requests) = mock_server(vec![(200, "1"), (200, "2")]);
+
+        let mut client = Client::new(&base_url, Some("secret"), 2023)
+            .with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.fetch_input(1).unwrap();
+        client.fetch_input(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn waits_for_the_requests_of_other_clients() {
+        let (base_url, _requests) = mock_server(vec![(200, "1"), (200, "2")]);
+        let path = std::env::temp_dir().join(format!("aoc-last-request-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let client = || {
+            Client::new(&base_url, Some("secret"), 2023)
+                .with_min_interval(Duration::from_millis(200))
+                .with_last_request_file(&path)
+        };
+
+        let start = Instant::now();
+        client().fetch_input(1).unwrap();
+        client().fetch_input(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let page = |message: &str| {
+            format!("<html><main><article><p>{message}</p></article></main></html>")
+        };
+
+        assert_eq!(
+            Verdict::TooLow,
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low. If you're stuck, ..."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            parse_verdict(&page("That's not the right answer. If you're stuck, ..."))
+        );
+        assert_eq!(
+            Verdict::TooSoon("You have 42s left to wait".to_string()),
+            parse_verdict(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 42s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>"))
+        );
+        assert_eq!(
+            Verdict::WrongLevel,
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Verdict::Unknown("Something else".to_string()),
+            parse_verdict(&page("Something <b>else</b>"))
+        );
+    }
+}
@@ -1,1095 +1,415 @@
-use std::collections::HashMap;
-
-fn main() {
-    let calibration_values = vec![
-        "shrzvdcghblt21",
-        "sixdddkcqjdnzzrgfourxjtwosevenhg9",
-        "threevt1onegxgvc9flk",
-        "7dmqzksnlcpbsqkzqlfour1four",
-        "4seven9gdlnhqxfseven94five",
-        "nldeightwoshgnsjnzmbkbxcxltsqtstrgdmvqvxbfour6six",
-        "87mmlvfr4",
-        "six1vvrlxx8two",
-        "znmfvdlhvjtwo9three4tzjqcfcgnsevenccvnsjczlpm",
-        "5sixninesixnh",
-        "three1tbtwo",
-        "five9seventwobqsjqzxkptkhsix1",
-        "74fivemn",
-        "sevenfivetsglnine8three",
-        "spnvnfkbv4eightlmdkxmsfour574eight",
-        "one4four2fivedcpqjmgc8",
-        "two6sevensix33",
-        "krc34five",
-        "doneight64qgc251four7",
-        "vpjthc6",
-        "threethxbtffhbfourfive3",
-        "onetwo5",
-        "1sevenngjqsfrptq8stgzxgjfp",
-        "1576sevenninefhfqd4",
-        "nine4nine9ninefive",
-        "zp8sfveight9twotgbvscb",
-        "eight27nrzmfsix7nine7two",
-        "gsfivefivepmq9nineftndvlcph",
-        "znggdvvkjthreethree79eight4",
-        "7twofivethree7ninevtspmbfxnr",
-        "four9twoxthdxdc7two1",
-        "8ddcmt87xhkqjbgtmkpjlkhd7sevenfour",
-        "4four6",
-        "seven5tmzksnsv9six",
-        "6fivesixlxfrqbkfive",
-        "seven9one8jxqkhbfppt",
-        "hxsbgn6four7",
-        "7pglchqndpnknhvcnkthreefour2",
-        "bkkfjq6dklbppfvgsshgrbmtwo97",
-        "66eightsix3nine652",
-        "six6zjjfffhrflrvkjgdrstcqpqt1eight",
-        "four252bqdxzzfttkq85tqnl",
-        "pbgjmdfxjvzdxjjdtwo5sixtwofivesxdcxtn",
-        "jonesix2five4snxcbgmx",
-        "62rceightsix",
-        "fivenine5",
-        "nzcxfqhvninefive3nz",
-        "4474rcl",
-        "1bjlvthree1tjthreethree",
-        "bgx2six8vlbdmgsrkone9bdgzsfljlk",
-        "seven52",
-        "fivesixtwo1two",
-        "njznm98",
-        "3xhrsfshnine7five",
-        "four9eightzcmsevennzfrxtsixone",
-        "jjsxs4mgqnstgpreight1",
-        "bhv9kr8three",
-        "sklnine1six4",
-        "five52734eighttghkkszrdllrr",
-        "93one",
-        "qrlmcmfgsx55onetwo",
-        "86dnnrmms6gxskhcghct97glkrlzrdrscfcld",
-        "xxb283cftvdg",
-        "hxtwoneqpmbfgkhnr6three86eight7five",
-        "zjfczdbpgxtkffkl5",
-        "fivexnqvfiveoneszqkzdfclxkrj8",
-        "8onerj7mhl",
-        "6fivefive8eightsevenqc8",
-        "eight89nineonelmfst193",
-        "ninetwothree5six5gd8",
-        "eight6dvvd",
-        "sevenztbdzndhv5threefour",
-        "two8onebppzkln6",
-        "twothree54six",
-        "seven8ninetwonndtgkj1",
-        "cpjckcfnqrrgdzfbrtbdfive8vkl",
-        "6six5jzxqqtrpflpdqbkgktsvpjgrhggsmvltwomq",
-        "sixhbft5lnqfseventhreefour",
-        "23pqkgleightxvscbnxnp55rb",
-        "6fivesfhqmzm7qkdvjss8",
-        "twofivefiveeightdcl4",
-        "9fourfourdqfqhpbbkgtchk2nrvmrghnkdc",
-        "sixlzbpttdhlssixdhmvrrgxj6",
-        "glphjgxqvbjcqjpdsixlsgd3",
-        "qcghmvgbmj9fourfivepztxbjpnzpbxzckgnhd4",
-        "kjvoneighteightfivepsbkgdgpndxx7six8",
-        "five95oneeighttpdk64xxndq",
-        "1vqxhglhnhrpbnlvq",
-        "sixtwo34sevensevenrnflkfxmlthree",
-        "pbscshhhpeighteight668fourphkdcrjrf",
-        "threex1jpdsmc",
-        "4pnhtjzlfdcgffour3",
-        "338",
-        "62six7nn",
-        "1fgq3onethree",
-        "7eightzvxmvxbq965nine1hr",
-        "gsdqtgkhlhdff7fourtwo4threesixthree",
-        "918hjpgbz6kbdxeight9one",
-        "7dxhsk9ninejjbninefour",
-        "one2mblcjnine",
-        "9twoc",
-        "fxrkzvpkb3tdvlxxchbfsixbmmmctknxh1",
-        "pbzdkmkxczkgh5xsixtwo6kfgqsgx",
-        "6eighttwodsfppgxrzx9threeseven",
-        "zzkbhltv7ngthreetwofivegdzrmllnxp",
-        "sgfour67three2seven3",
-        "sqglnvlrn4sixmdnineeightthree",
-        "1kbkmkdnc35eightxbbknllscr",
-        "2bzrg",
-        "3onenine",
-        "threevkzrmdbnbsxrmzjtdhbeight12rncbzmq",
-        "sxcrgkvbrtwo17six527five",
-        "9rbsbcjp239jvlfpn5jrr",
-        "nine8vjcbjcxxprdcf4",
-        "nineeightsevenchjczgdvjvtqlkjsqvrzxxvlqlp65",
-        "hdgqninefive9sevensix3gzb",
-        "6fjv5",
-        "pmgdvcdp1fivetxkmjzone",
-        "eightsevenklxgcj8sixccqlnxzmbeight",
-        "bkcdmscssixsixtwozbdzcpgpbsvf38",
-        "ninefourrcmrfvszsqhpxcs89onecnzjvhrphs",
-        "54plhhmnmkvonethree2ktzncds",
-        "pkglbgdplgvlstpgpnsmlsixcpmfxfbfbtpsdxninenine4",
-        "one6qhcqnkqc9hfrsqpxmcm7eight3",
-        "threepdtshdskxgeightjtdpfbtlnph91",
-        "fmpjgjqpgbpx8two8jzqchdglhtfiveeightpzktj",
-        "njqhghlvm2",
-        "dj3",
-        "3j",
-        "eight9cgbcppzjbl1tgjhm",
-        "fvkhcvflpknqnh9ninetwoneqn",
-        "threemfour3eighteight",
-        "qjmtvcbvzzgxmmpdvrfv8",
-        "tlzqpfsix1fbhgdvhblvgk",
-        "eight451qvnhv4",
-        "7mxcvmpkdvdsjqdd",
-        "6ninetwodmbjfour52",
-        "jdq2sheight",
-        "seven2foursixvhlmsjld",
-        "ndoneight361",
-        "poneightfourfourdhlnmlnpvnsixone77",
-        "6gnxprrthree9",
-        "ktbhqxmvcbczfspfddnjjcz7",
-        "4nineqsk5two5ksljzqmnrrhftqvmvhfqvxtg",
-        "cgllkmq1fivevlcxkmj4twohpclqj",
-        "fivebdkfgmlzckstbmone58fourc1",
-        "6sixninebkmlgpspthreesevenhfrhtfqnl85",
-        "27fivezqskxvqqp7",
-        "7vklrcvmq2nldrglnlkmdjgzone",
-        "4fivemzjhhvmm",
-        "76fournineeightthreesix",
-        "threen45nctdxmgd",
-        "eightfivetwotjbsvvrfourseven4djjfftk9",
-        "lrxjcgdxhtprfpvn9eightsix",
-        "foursix7lbjqxb1lzsztxcrhx",
-        "dlxftspksone9mfive",
-        "ninefiveeightone2",
-        "nzbvf3ninexsixsix",
-        "1eightjsqbvpcfsevenvdkhbxzfournine5pttjffj",
-        "6kxdmlhtslf2rzmbrdrvvqmtceightwojj",
-        "3fjxhq",
-        "j9",
-        "lj7onesevennine7threeeightvmgvtzcg",
-        "pmmrbq4",
-        "seven3fourq81l",
-        "2four4gqdpczpg",
-        "6sevengzdxmfpflfivefivevgnhspxchsfive",
-        "3c7gpgbnlfhlrthpmtsvpctjhzf5seven",
-        "kgtczfjdeight8hpcmkz",
-        "one5xkhnfvjh92ninevxkzvkg6",
-        "nzp2",
-        "eight226three5sevenhhxhqxns",
-        "9hgfj85zxvppflblvjlq",
-        "sixfour8hzlrbqjtjcgrxseight",
-        "6tlxtwo4258kbdtx",
-        "ninemhvrlcqvdthree5",
-        "8xfmjzp",
-        "nineldrbpxqmhffrjrcx11",
-        "mqvjnkjr6dx8",
-        "575cfbnine14",
-        "threeqnxnfjmttwo7",
-        "pvmxfgvhrlqn4fivefivesevensix1znt",
-        "sevenphcrjjhnfive63three",
-        "sixxdjplseven8seven8qlxfvgktf",
-        "hdgnr6sevenddzptcvprbhvnine",
-        "onebrhnsqbnzvmckmtmprjkbfvn6",
-        "hxqdjrmr94five9qnkl5",
-        "g3fourfive6",
-        "22eight6vsmtrxsix78",
-        "jrlrfsnine9",
-        "tqpkqdtknvfj2xgzrjpsvgphlbtthree",
-        "qvczktnrfvmgpznspqtsevensevenslmjdqfdld5rrcfour",
-        "rmgeight36sxvxzgnine7bs",
-        "sevenlt2fnqjtkvrj",
-        "seven9lhfzhmhdonelxvxfqdlxlscjbqgvnlbm7",
-        "3183six4seven4two",
-        "bpl12mcdpzrzbph9fourninercpqzrn",
-        "eightzltzdmcd4615nmone",
-        "5bgzh8hrrtjhnine62",
-        "ndnzpnptz6twokdvg563fiveqkszkccqc",
-        "6npdfqtwo",
-        "onetwomcxkncm3mjsrknhktgdfbqjmone",
-        "sixtpmdfmrjone6six",
-        "7fourthreekqvbhfourfivenine",
-        "98337",
-        "pvkrzdzbdj9",
-        "8krztxtqkqksixr264",
-        "5sppmxxkdonezfvbdcsix",
-        "dhvmctjcjfivec7pjmtnlqs",
-        "dgzl9sixggxstwoneprr",
-        "gnbmxn1cjnq3three6rpsrqmtwo",
-        "176seven17six",
-        "sbvfive3six",
-        "8seventhree64pcffive",
-        "fourgvhbrsfjzsevenfivenineninesixznq2",
-        "nine426six8zk",
-        "4sevenfpnmsqlhllrxrhjonesmgfhzmhvstwo3",
-        "fivesixtwosevenlfbpczfv3six8",
-        "8fourltcpdnhg178fourglz",
-        "rdfqcdrxdc6twotwo8fourthreeftrlzseven",
-        "97threefive7",
-        "sevenoneoneszdxd77two",
-        "g91q392four",
-        "2twodb",
-        "jbtfkfourggc5zkc3nineninekv",
-        "379eight",
-        "hhxcbflcv6fiveszpch46",
-        "threexfsfv3dpfourfiveone",
-        "fivevd145one629",
-        "31vdzxxkxsmznrqjzpqj5onescbck",
-        "9onejbnvrbmtgm",
-        "thrjvpckm4vglthree",
-        "zvhmxzsixfjddvjq1four8lktthree",
-        "74seven84rslpcjtpfourcvvqlstmqn",
-        "dfxdqr3eighthfhcldhgmjcpbxbzp",
-        "sevenbgs1seven9bjntphktdktzhrzpfcfs",
-        "7kqkdzncd4sevensevenfourv8two",
-        "mjeightwolsk2sgnhgxstfourhbhthreeonenine3",
-        "4nts",
-        "nslvxzffoureightfour5eightczsptpg8",
-        "bcckncblzdsixxrdprtsmbjdzxzlnzfbgmkfd2",
-        "8fourninesix4fourseven",
-        "twospxvtbcjfour3seven",
-        "four2cqkxkbplbpb",
-        "54fourtwo2xd",
-        "tljfive7bsxmonesixl",
-        "8mxd8czpdrgxbtwoeightrqpghngcvt",
-        "3sxrzljnzlr9threetknfcc5seven4",
-        "eighteightfourtwo8fournine7",
-        "jjzxf153mdzhh",
-        "3three95one8vsmzkrlvhq",
-        "ninefour4pzqzntwo2nine",
-        "onefournine8gvp",
-        "45vmbrpblzjxthhonesix8",
-        "lmmrxm5",
-        "13mhm6zxmkhbcsixhz8",
-        "mhsjcprhdh7ninemgrxnqzt7pdtxxvsix",
-        "twothreesixqcjtzcxmd3ninezqrvnzlxt",
-        "hreightwo51tb27fivesevenseven",
-        "fivebktq86nine5kd",
-        "one6nglzxzkzrmfl12nine6",
-        "2th9mms4six3",
-        "msix5bqbtwosix",
-        "four1hfrmxsix3five",
-        "nrtjrkkfour6fivefour7fivertjnxbbzg",
-        "jmjqmxrtjr23phttwo",
-        "79",
-        "6twones",
-        "sn6fivesixfourhhjhrfthree",
-        "xdfxgzccsvonefivenmqleightfivel9nine",
-        "78sevensix21",
-        "rkmncslfr6hdhtlchnznineoneeight4",
-        "seven1496",
-        "8fivekhfrfjtwo3eight9six4",
-        "jnrtwoneslxrgkeight67fivetwolbhvfftqjhl",
-        "eight6eight4two",
-        "dhcxc1",
-        "fivenpblbgfive6moneighttzj",
-        "onef5nppkqcl6mnzhvsixjvcqpbtb",
-        "7tjggz68four4six28",
-        "zfntm7",
-        "3sgbsbn2fivefour3",
-        "8bmtcgtqcsmdktzmj3",
-        "617",
-        "7sjsshfgnpnjskhccdclrxmnl",
-        "trzeightwo1krbkxd9nslndznine",
-        "ninelmbxldnb1qfqkhqkp",
-        "3pf8vlmgtfouronefx",
-        "five7seven6",
-        "zckdhnzrppb3kqsix",
-        "6fivebgf6fourktdsqdxfqd46",
-        "four7nine5two4dkkdhvcfsf",
-        "5vtqrhbn1lhxm7one",
-        "three13fbtqxvmninegp",
-        "fiveseven1czvlmlncthreerstssbjdoneightpm",
-        "2lpdnfz3vghpszmtxnineeighthkfour",
-        "455hdvfivervnine",
-        "three64",
-        "six6threefivethreedbvvftm",
-        "667sevenzjxmrmpxd8",
-        "dsix62lrvqkfthree",
-        "sixthreefour5cpgsjznfdbtk",
-        "two4five4one",
-        "1hxbnvq",
-        "sevenkrzfvkkrprxzcfgchscbstwo73",
-        "nzhtgzzq448pftthpsneight",
-        "3197",
-        "one59fjdqjd9",
-        "pvcphzxnvnhhngl5dsix6fourone",
-        "5eightfourfivetbhh54eighthkbvcfrpc",
-        "threehvdqjdl45fourrqhqxs",
-        "8twonine",
-        "8mmc6tmc99rkttglnnxk4",
-        "pxnxckqbdhhvhbheightfivefivesix6two",
-        "3four2pzx23vhmzhrfourthree",
-        "five1four97",
-        "9eightpzznbpqgj",
-        "rrrgfxlb7vltwobjtdrghc",
-        "sgnd8ptgdfourbbcqsix",
-        "4chfmgsxnsd96",
-        "zthree3",
-        "zvvstf6twofivelftpmzkttwo3",
-        "1nineoneightxqt",
-        "2kzhkfoneeighteightqn",
-        "287cfour",
-        "lhmgblplseventhreenine9vcpnpvzhjronenhczffqt",
-        "5xrjqdjtdqnvlnrkkr7",
-        "38dbdthfive",
-        "onethktvdnfqgfourlxpksevenseven22nine",
-        "mjmt18blbfiverjsxjsktkgz",
-        "8hlrjjbvsix92grtmthree4",
-        "7threetxjztxseven",
-        "five5gfgfdjbkrpseven4",
-        "8jdzlvrgtcf8eightzpgstwo",
-        "bfpqdpfoursevennvgqt8",
-        "8one99",
-        "kfvmblbtthjrrmktmjmeight4d3lnctfzsvgmjtmd",
-        "2eightgppxscjvdgrzjgc3",
-        "5twonineknzone",
-        "onefourhb4",
-        "eightrxchxccxb7qqqqxzt",
-        "threeone14pvfive",
-        "nineseven1sixknvkmfkk86",
-        "onesmvscnf9ninezgfrps",
-        "ffhseven2",
-        "1sixrjseven8",
-        "rvzthzdtwosix6kzzqssrgc9tl",
-        "threeeight5svcrlrqtkmfpgqzhhs6",
-        "eight3bt7dp",
-        "zqhncxh25eight1",
-        "five3vmmmntnjtjrkbvpvphj6nine",
-        "eight6fsdqseventxgjkbvrleightfive2pqx",
-        "nkfbsdgrrfvsmfxx78onetwo",
-        "bsix5eightwox",
-        "fph3jzfmt9fiventnpvnxhjxplchjnvlxqkdbzv",
-        "kfxbjzcfpfk8chkrtvlrnine6",
-        "m7kfjz52eight4tworkpcqt",
-        "vmlhvdpxds8two",
-        "fourfiveoneeight478",
-        "3eightonefive",
-        "ninel5",
-        "lfqllfjcqztwofjnfnfx5",
-        "5tvqksfour",
-        "twogdg5fourmhninecrdcrheight4",
-        "two5six2",
-        "419vrvjln7",
-        "pbpmljqjzxghp3tkdeight7",
-        "sevensixfiveqmvtfggbqjone72fn",
-        "7four93cdgcpxpl86",
-        "9oneeight6three",
-        "threesixsixhzhpfive8ninetwothree",
-        "xlzddrthree2rmtwo6fivedhxsrrdcvpvgscnt",
-        "qfncszfsdgzqctdtbpkseven1fourpcmz5",
-        "7seven9fivefive",
-        "82nine7ztqmftkssm34j",
-        "twofoursv6",
-        "five2five51qrsrqrtmdk7",
-        "four7fhhjxcjtbr",
-        "hnbdbkmjc72",
-        "sxclpbzmbd4fivezjjnncmqpv6ckxzb2",
-        "98six4pkhfzkzj7",
-        "611thqkzttfffivenine7seven7",
-        "8sevensmqzxxlgpxpjqstsgkmxdgplrplsjxjgsmjdzzgf",
-        "rggphsjncpxkfzfplcgrnfgmnd9",
-        "three9sgfhdn3rbvqds3threeseven",
-        "nine9sixtthree94rs",
-        "sixthreebvmxtcqng67one",
-        "6ntrhzzkl8djptbnsixthreefourhnk",
-        "3sixxsj5five",
-        "1cghzppdcfnine",
-        "jtmkrvlmhthree58eightqbhlgppghc1",
-        "fivefourlpk5jtvrk9eight7gfzksqrv",
-        "tjrphpkhfgsix7eightwocj",
-        "threethree85ghjsnhgrv2jv",
-        "six64",
-        "3ptfzxjrtfsix",
-        "1583xpczlkvtwo",
-        "twoglkpldjthree717",
-        "six26three",
-        "three5qc1gz",
-        "9tworgcbxfourrxgxzzbljcb5mxn",
-        "838",
-        "four6zjdfd22kxpfhhgonefour7",
-        "seven132",
-        "zmml5",
-        "twozzzgrhseven5",
-        "four8eight4fivefour",
-        "g76nine4oneseven6eightwol",
-        "9kzlggh1ninefoursevenninelb2",
-        "three27kfmbdtpxncgpskvrq2",
-        "onegsnxggjlvn1six",
-        "8fivevgtszhfl2zqsqpnmgdbkfour",
-        "4fourtwo",
-        "mrllxmbfpqvpfzhghv8twotwo",
-        "3hjxpgkxpc91t3",
-        "four24",
-        "six385hsxtfone",
-        "8three9twotnpjkcdbrfive8",
-        "nine94tpfcmnzsjk",
-        "sevensevennbch64six",
-        "onefour99gcgvcfvfnzjtjzngc",
-        "fivefivedqdt4",
-        "onefrnpc4onejgvtwo7six",
-        "4568",
-        "tfrmonerljgxsbghm87hmgzfvlcmgbsbqc43",
-        "4kbxt9pqchtltg6sixjkzncffrpsixtzdtbdg",
-        "29pbpph",
-        "2szjrkkqvsix",
-        "six2twotwo1cfqzhjrlkbone4two",
-        "mdxmcknnmmnfzsbvh4",
-        "rzjnjrqjkmzmr4seight1two6",
-        "onehpqvvlsnkhcqhczbllbcfl3six",
-        "nine655nine81",
-        "two76threefourtwo54",
-        "8nine91",
-        "4threebspdskrp3",
-        "pktwonesx4sevensixfournine7",
-        "td2one4seven",
-        "eightseveneightfivetwothmngvb9one",
-        "9pkdcbgfhrf8mjtjksvjlf2",
-        "374four9two2seven6",
-        "98eight2",
-        "brmzpfgjone3",
-        "six4mdbthreezdgrktcdc5",
-        "mfmvcqdjlvzmeight8",
-        "smklgfour2",
-        "j3nineeight",
-        "fh45peight9nine6",
-        "two972fvkfzsfivefbgkktg",
-        "three9kdzkeightqmxcslnvhthree",
-        "ninekgjkdtcsrqgrmdtshsevenseven3four",
-        "2nineninetwomlt",
-        "jckvcvtwo7",
-        "348",
-        "cjzzxsix1hfqxxgdvphppxzvmqsv1xxxsqckhpbsix",
-        "jcnlxlccrtpmqqmfour8dzrn8lrzq",
-        "7sevenzrcgtldkjbhjt",
-        "three55gtrnineztxsdcdcdb2",
-        "sjnvr5",
-        "gvpcd4one2four2foursix8",
-        "mxskkrvdjkvpnine6834hsxsn2",
-        "one5fivesix",
-        "bfhbgfdkdftjtnhvgbr2zvmcmrpjs",
-        "threeeightmbjc5six",
-        "8zcxflfkbtdtwotwo44six4cnrvvft",
-        "sevenzggv2mkjsl",
-        "sevenvx8one55zhkjcxbpdz",
-        "zbxbftwo79leightsix2lgcmlpbpjz",
-        "one6twoqrfoursixtwofive",
-        "111threethreetwonehf",
-        "72fninesseven",
-        "gsrshxjfhr54jmsgnnninecvqdjtsevenfour",
-        "m49z51",
-        "2one27two",
-        "four9rlhsbfour",
-        "eight8sevenoneightjtj",
-        "pgv57nineeightxnblzsixxgjttbnzrqscb",
-        "3sevennine35gfbqvq8seven",
-        "fivesix252one1nsix",
-        "3qzvttqnmjb4pshrrdzpm",
-        "nvmlzzxfksevenlvngntztwotwo9mhjrjrxnckchs",
-        "seventwomzk699",
-        "33svrghbnine8seven",
-        "six48fourtwoeightphl",
-        "7fivekcrsvzdvsix",
-        "8sixjtdrl446btjlmfzqlg",
-        "g6sixjlsspqkthree",
-        "z2",
-        "sevenonec6xhddnxjzrn",
-        "one1bczmjxrnineonevjkbnine",
-        "eightttltnkcllm8snhbqptsztzkdffkcdbsixcncvr7",
-        "mnprx46jdttjtxrkhrninefvvphzthreethree",
-        "3bjrvbmlpv6threefivehrkcv",
-        "8hjkrlmg",
-        "lfrzztvtwomkgrtzvseven7",
-        "fcx2two",
-        "27hhbgl3x",
-        "mtveightwo3tcseventwox",
-        "nine8four1five4",
-        "six56qqvvtwo",
-        "fivelhhcjhpfnsvtzmnxdlp3fivefour",
-        "fourtsbq3six2",
-        "71vcmtqgfpdkx2",
-        "fourfiveninetwo5",
-        "qsfhrhkm4nvxdxzcnkcknvxjg2two",
-        "915dftd69foureight",
-        "qrhjdprpcbq3eight552kb5",
-        "rsone7nckeight",
-        "3ninecmsvkmlk8b",
-        "fourfrdgbrdzsfvjg2fthpptfjgzjsnvsxcxgxgcgdcl",
-        "sevenkpshht4threemthmfbdp7",
-        "sltzgqcqlvdbccvcbkzmmpsl957",
-        "75t3",
-        "4sevenkfourpxfkgkdpjccxpxrgsbmsrnxngd",
-        "2oneeight4mpndtnnone4onesix",
-        "six35sixfourfive2",
-        "81twokrqg",
-        "3dqcrlnzsnmhmzzxnsjsskchxqzvtfrdnkqg",
-        "17zzrcpnmshreight",
-        "gcvvczmldgh8mnsnpmjvdrpznine",
-        "oneeightjhmf9",
-        "1hlfour3three9",
-        "skjc82gthree6",
-        "eight9bszxphftdsixsixonesix",
-        "five9xbfgzfh",
-        "6nineone",
-        "tgnqfiveeight8qnlj",
-        "28fxrpdmdjgdnine6rpjdxgd58",
-        "gsxfivefourhgznkdhrtkn7nkt59",
-        "6m2eightbzndjnfthree2fjkpsm",
-        "9nine7one7rgsh",
-        "xnrqcllskcrlxglbshvrseven3nvjlcngxnine",
-        "sevenpltgmq9csxz1four",
-        "7threevbhfgnshtn5nine",
-        "gdj9",
-        "five6two931txb8",
-        "fourtwoone2hcbzlggvfive",
-        "7xlpjkrvbtq8qlgrqonetwo4bpxtcgjxv",
-        "nnxhcrgplrtrskbjzdd8lfive2six12",
-        "bqjdtwo98z",
-        "6ninerfqlbxpx",
-        "8seven642six",
-        "eight54",
-        "5two9eighteight7hzkfdzg",
-        "twofourninekhcztkhfour23xllcdb",
-        "zmpdb7jffqt7nhnmdrqqvtwo2krltbfjjdd",
-        "sixfourlxxshzfccgtzkdhjeightfourninehmrhqzfqld7",
-        "mblx1ninekfjhfnjtt4nine",
-        "seven7mhmkpqk",
-        "4rvzsftqscltzzj551",
-        "8gkvgs5oneninethree3three",
-        "5ldfllvzkhkfive8",
-        "jjblh64five1gzvvgcbrhbvrqfhjqvkrqvff",
-        "fourpqnpgjnclq7nine",
-        "eight4761",
-        "nddmqzx2four",
-        "8nkgjkkhqztwoeight",
-        "hlqtbfrgsfbgnine5qdfzrsnmb77",
-        "nineggdbpmhbmdnrfvpv5threefour3",
-        "jm4",
-        "sixfkxfzvrkxv9seventwo8one",
-        "threesix2oneoneightc",
-        "7three3615qqdbzsxrdv2",
-        "74fourdvgcgdgvxqgm4eightqqtmvone",
-        "5h58dmvkxbhtninefoureight",
-        "8fiveoneseven7",
-        "eight145zxgnzczcdkzrffnbfmsztwo",
-        "268nine3",
-        "6eight3nine86six",
-        "4qhtjbcd9",
-        "mblblmkhseven3tgdxslcnine",
-        "fivefivelgghthree5vkkfhjkc",
-        "4four5oneseventwosixfivetwo",
-        "6ninenine8",
-        "7ldg64five9twoninenine",
-        "3zmzlnninesrfourfivetzxlkcsr8",
-        "sgdzmk3lgxjrktsmzkczft",
-        "64nqhjdjs",
-        "9sznchv2nineclrfdpxnf378xhkjbbplqdeightwos",
-        "3sskrbzrpfqbbqtjzgcqjhnine3six5",
-        "vq95nine7lfiveonethree",
-        "3eight3eightfive4",
-        "twoninethreel7sixfour9",
-        "bszxlfz2mtmv",
-        "1cvhpxjlgjd",
-        "sixsevenksj77one",
-        "qglgjsh3zrsqqh9onehrkjxfsxt3nine",
-        "sevenlllqqkk4bxcgjqqllmcml4b3",
-        "bppqgfdfpm7753",
-        "68frjqbqmmq2fourncksn9",
-        "bdz42pjmmnmhkvd",
-        "6threetone2",
-        "8bmmdxf1dbnggxgpvhninezxmlzhvzb",
-        "48168",
-        "gvgkcsvmoneqvlxgzjsixfourshxzls9three",
-        "1zrgnghhm",
-        "klkrldxbjone6eight6sbglhrhlbnbq9dbkl",
-        "9threefivetplxdklc2zrnptzrzp2",
-        "sndxcznj9ninexttpfzbjcmbslghm83rp",
-        "73one8one",
-        "3eight8eighttlmpmh9five7",
-        "7vqcddvrgbm5foursix",
-        "fivedhzbdsnzd1sv",
-        "82fgntsxnsix3xdzk",
-        "threesix3qblcvkp",
-        "lfptgxqs2six35",
-        "eight33jtzdbstcmstjxtbseven8",
-        "seven4five958three9",
-        "3sevenhpvklhxxvx",
-        "rzsb5eighttwoglqtmf4",
-        "seven8fgkp",
-        "3rjfvpfiveonesfgdbb5",
-        "vpkkcrpdthree9threeninesix",
-        "3onethreenine7",
-        "9sixtwoninetqllkjtmjnine4",
-        "2lqsevenx1vsgbmgone",
-        "eight2kqsixfzcxvxfourp",
-        "gchhhrbxqlfphdjjdfsix4rcpmj",
-        "4pfrxtgr21",
-        "7fvndztkj",
-        "rtwonenine77lsglrqcxhgxrllftvqfvfive44one",
-        "two374343nine",
-        "eightseven7fthree",
-        "6lrrphgpnrlvgfqqzxfdxttdbfdfgjpmtztc9",
-        "61threegscxrcstkclln9",
-        "xk2dhkgbtggeightthree",
-        "5vbbrqfnfp49eightqvn71qpskg",
-        "sevenhgd9qxfn8twoeightsevengcqtt",
-        "1four17five",
-        "5seven5vnsbqpkjzeight7five",
-        "62qgxndqlninetmftkhp9",
-        "two8threeeightdpzzhhvmr8",
-        "kxgmtlzb7gcltm9cxp",
-        "8454nine",
-        "mtqqxgkvfdthree9rpbvl5sfourthreesix",
-        "7onenine",
-        "4fourthree",
-        "8fvr",
-        "1fourfhljbffive",
-        "lqltrsvkjsthjkxph3vrfive3vxvlf",
-        "xxfoursevenzbthcsdnrvjlnqqhlpbbpj8",
-        "6gvch2fiveeightsixmtfps",
-        "two7rljfhhdlcseventhree9four84",
-        "5lpseven",
-        "cdmnine1",
-        "oneeight97mxnine79",
-        "3thzbhxbcbf",
-        "two41fivefiveseventwo4r",
-        "shlz9",
-        "xxpgkqf9joneoneseven6",
-        "lpqvvzdbx27",
-        "gh1five",
-        "thzrmh2lpncngjrqrkmthlndl2six3",
-        "vrrhfour55",
-        "665qzpzlhfvrbsgblcvgzbqqkxbdtzctwonerx",
-        "five44",
-        "kpvqnxnlfouronerp5417j",
-        "4vxppbsfive235gqchjfhjsdgq",
-        "sixnine2lk8npzdbgcxvrd",
-        "3six6eightseventwo71pff",
-        "zbr1srxljjseven5ckjfour",
-        "one71z3threenvmtcd",
-        "six9zdchbfsdtbs",
-        "2qhqnrcmrninedqfivejseven8",
-        "zrhctxclslhlj24jhhseveneight1",
-        "knkvthzrbtxbbnnpsfbq1ninefivesix",
-        "vhr28rlrtpjbnn7",
-        "xcsjqrptg7one",
-        "gzdjq2fourpqmzjs5sixtc",
-        "threedzbjqd3568",
-        "threesixgcjx3onefourdskrfhldjhm",
-        "sixphnfkdtfxnvphxh3",
-        "rxldfbssevenfivemxtdbnvgpq6xxmbkdkxkjmth",
-        "five7five3four2qkrfmt",
-        "4fivefour",
-        "fivenine35tzhpqfkq31",
-        "9two2fourtwo8nlgzgbbp8",
-        "1threeoneqglrlnmcblpfhgcfhggm",
-        "7onerknmhpmonejzfqjng",
-        "nine81twoshgxqt",
-        "28dqfptxseven37jtqrklrqc",
-        "41two3",
-        "7gp",
-        "sixdzjfsv2sixeighteight",
-        "9three6",
-        "3fiveseven83tvtxf",
-        "ndtwone562kzfhdrhgcjv4two",
-        "jzvtjvnineeight42keightclbtddmdffsdttc",
-        "eight41mggmrzlkhsevenfoursix4two",
-        "rkllqvfjz13sixthreetdztlhlcldoneightj",
-        "7three4fbccblfvninelhvlthgh",
-        "btwone96two1onexconeonefour",
-        "jcvxrvkdsb6five",
-        "578947five",
-        "1819p",
-        "lgvxmnjkgmlkhkzrone6gnbgkxrlpspj2six",
-        "ntzsdxn8",
-        "mplcrhcz8",
-        "czggpkxcfthreefourjpcxnine4pvfqxcvh38",
-        "9dsfhtnrjv16",
-        "2ceighteight4ptzneight",
-        "2two8jvsgrtrht7lhxkdztqdhvcsdm",
-        "619six",
-        "cqmqkhpxjtwofm9one1one48",
-        "2nqbksgxxfoureightfiveeightbbvg",
-        "five5nineskphfdgxbp67",
-        "gsevenqgcsk9ninehlccsxn",
-        "pnqcdjknth94three4sevenninefivegl",
-        "eight9mnsb6",
-        "fzsfcbbvvqnksj85fbfour2cn",
-        "1kjzphbpp3spnbzhzeighthdnrdq",
-        "4nsglzjbk1",
-        "threetwoninethree2ninehm",
-        "35oneljcsixlmdxpxk1",
-        "jsix2",
-        "tgkfk8ninestnk2eightoneeightwotcs",
-        "chhflbq1xznbmj4oneightkc",
-        "3eighteightfivenine",
-        "six592bzj",
-        "xnqnxkpseventhreettv8jkds",
-        "honeighttpqxdbhsevengvzfourd4s5",
-        "sixeight8",
-        "pcflsgrvslxgmtqm6oneeight69zhlpddq",
-        "nqgeightthreelzcppk2vzdjrjqqx41",
-        "dqdcmtzbqeightfoureightthree7four3",
-        "ninesixhxrncgtwofivetwo5eightrvq",
-        "kzxgsfive644lthreefive",
-        "nine2one14four7",
-        "59gjc8fivetvjcjcq",
-        "lczn92c",
-        "3seven9tprznnsmznineqbpchxlnbqkssgqx1",
-        "3m22nine7",
-        "3mhzbqtscqrkjzjqtklxdn1",
-        "61pnjkfournine5",
-        "41one",
-        "99twonzsjsqk7fivedjlcgzjrfive",
-        "twoninexngksevenfive7xxrxpvq",
-        "1mfnggsgvqmplg5mrqmzsclpmeight",
-        "2ddgfivehxddfxbdfsseveneight",
-        "4nine579three13",
-        "fiveonethree44one",
-        "11four",
-        "cgqrbzdl6mnmvh6",
-        "qmzeightwosevenflbh72nine2",
-        "six59one9two1cvzslmdch4",
-        "fivesixtwo8",
-        "sixxvczqbbvctgmsjnffmfourfbr56",
-        "6seven5hndfourfcnone5",
-        "qdnjmcxtfgpk6eight",
-        "three24two7threetwoprztmh",
-        "foursevencfzcdzksevengh6nsnine3",
-        "sixseven4lmqshqzmk",
-        "xhrjx9eight",
-        "twodmcxbsn924",
-        "2zmcjlhqj4sevensjlnjjx4",
-        "pctwolxqvjsjdlfx8eighteightsevenhq",
-        "sgzzd5five87threershbhmqlbm9eightwoq",
-        "tlzmzt6jg7fiveeightgc",
-        "svlthlbrfive2fourdbfp9trvbjthvvthreetwo",
-        "nlqnmffxlsblnncqgcgsnkt7fjhfksvfxeight",
-        "hljgks3hfour5lzzpzpjpgdhzflrnqpfzjsfc",
-        "9eightfive",
-        "eightsb215qkphjjdrlg3",
-        "four7three",
-        "nine2eightrqh21",
-        "tnine8txbm121three",
-        "two1h495skpvbzsixnine",
-        "73five61pzlscqn2nine",
-        "threeeight3ffnjgk2mprkn9ninebqlvkl",
-        "58four",
-        "4pzb35twotldtcq363",
-        "jnzdhlzx4fvsjsffsxn45seventhree2",
-        "nsfrqntjhpq4qstwotwoonehs",
-        "fiveonethree5rnmlz",
-        "seven18jjcdcg",
-        "threesevenqqbrkbpzeightninethreefive1three",
-        "eight98",
-        "eightonetzggcjvpml2twoxlznrn",
-        "4dmndcrsc",
-        "9oneseventb",
-        "5pkf8zzmvfive6",
-        "rdlnxcx3nineqjpldqtwodkctfnn",
-        "8mpmgzgshszbcnrpzcszltd",
-        "2jvkc3",
-        "rc6clvgbz6bbtlzeight5",
-        "frnhbsgzpfeightqkt2ninethreetcnklsctc",
-        "threefivevgzhh2",
-        "tjoneightone9three9dkbnh",
-        "hv7hpx",
-        "7onetwozrl7fbxhqgrbtoneightpzv",
-        "3mtdnphsvtrrfeight9sevenfivethreedjcdxg",
-        "pqjcfjsix4tsxcthzbqzhklqncvb",
-        "fgrzvnnk3cfrxp6tsb1",
-        "czfdsjffqqrcgffx7seven",
-        "56jdtwo",
-        "sixxvjjmpcttwo7ninechbzv",
-        "8qckktnslsvrmpctknine91",
-        "4two9fivefshxvdfbdr",
-        "7sixtwo4rjspztpx",
-        "8onethreestjxv",
-        "3lvhvlnthreeonetwo323",
-        "hxjcbtrtcdgvg22ztn6xxnsdbffour",
-        "twolzone34crrcpcdb4",
-        "fourjhghcseven9gtmxrpbpxthreehkpfour5",
-        "qdrxfdlqtf4342threemlpb4d",
-        "nine6nfnrgd52jnqfourbzkgbxlvlmr",
-        "sevensixonem5two4",
-        "64one1nmxbmsq",
-        "fiveftmrkpmjthreekvsfqjchvf9two",
-        "ninepffbfjhjtg4grnzztqnfiveseven",
-        "1gkvsgsrrxvdfdtsrlgfthreedsrprjft3",
-        "foursixsevenninepxllctr4khsfxfvnine",
-        "onebfpbqmpmm6",
-        "cgkblkxtr2thqsixsixthree",
-        "qqfkvsntgf687",
-        "fournine2twotlvqhlrtbmckqlljhssevenghhttkvf2",
-        "ninenrvhhleightkxbzcmx2sevenvzsncp",
-        "eight766sixoneseven",
-        "8eight61gsxklbp",
-        "2ptzkzxsnsix9one",
-        "six9vfourseven5brmclpeight",
-        "821",
-        "one6hcsxp5njsthreesevenfive",
-        "pcf2",
-        "eightgxsjc456",
-        "threeseven57htjtqxbbdh",
-        "6twomsbq",
-        "gzknkt1twopccfh",
-        "7vpzmnt",
-        "8fiveqfzgbnxgnh",
-        "onepvtddfzn2three",
-        "5ninesix85",
-        "onejkgppztbmrbfqqrjzp24one",
-        "k3six817dvvjgqlnkd",
-        "zzvkseven9sixqxr4",
-        "lpgdnglg3threemtltwo",
-        "three9qfour8rxlzjllnskeight2t",
-        "sfxgmjzqs2cbqtwo5",
-        "one36",
-        "2tdknkgfhpj1nine9rtsjrjdlsix",
-        "59jvsoneninethree",
-        "fzjvgpsqrsninenlpxzbone6",
-        "fourkllsixbfive6fourone4",
-        "seven52zchsttccn3three7",
-        "6ncvnflrdqbfourx64nine",
-        "2vrhdchccppdseven4qkdghrjrxbdlfplc6",
-        "5cbdfvxxtwoone38dmjngzqlf",
-        "fsqgt6jqdqdj7cfdzrfd",
-        "9zqfiveznnmghtrtwozqbeight",
-        "twoninexmxnpbvtkn1tjzdsxjvbd",
-        "6fournine",
-        "15twotwo35kbbpcxbsmb9five",
-        "1two29dppbcqkxgq",
-        "six7sixpqqmdjlcgzrrnvkqfqmllqlbmfnvlnzjcs8",
-        "jhtgltv2qzhnkm2three8nine",
-        "mrttwonetjrt2eightoneqqgvllgpqqbpd",
-        "hhtnb72",
-        "sdzhtjmnkdtpfsfnsnk7rtwo",
-        "3sixsrqsjsts643five",
-        "338twomnqtwo4cl2",
-        "hgggrn2kvgkvrhngxx2xzmqsdpzbsxfqhg",
-        "hctwonefivenine4tnsixsfxlvppm17fvq",
-        "six3blppkhpjtr",
-        "trrxmdnhzsix47",
-        "5vsrhlvqpttwojczmeight",
-        "rzgrzbkk87nine",
-        "trgfsq1ninefour",
-        "lxbvlbpjz4eightsix",
-        "6zmmvchcgjqsrthkgc92",
-        "9thfthreemxlbt",
-        "eightpkhgpcnc8eightfive1hdtcjjdcsevennpz",
-        "fivepprkhhtg9cdfqhsqfivejsrxzvknndnvq",
-        "qfvjvgeight2vbpjnftcttwonegn",
-        "twolnln7pnlsxthfjpfivenine",
-        "hlseightwoone6qvcrsrttg1three7six",
-        "8xcsllfrlx6one7eight",
-        "fiveshnmbngmrnineqjjlvdrfnsmpdnine4c",
-        "three2rcfcrgmrvsjzcflqkb6487four",
-        "threelnbxcrhglnine534",
-        "5flztcjjeight7sevenfb",
-        "qgbplxgtdkrqqsvjntwothreens48eight",
-        "five34rbbxpnkftntrt",
-        "nmjtwonek4",
-        "cdnxnppnvthreexcsmbmcslh8ninefive1",
-        "6lttndjpcchdpgkfmmf",
-        "oneeight88",
-        "three4xctmtvvcp3sstxzfmgnine",
-        "6fivefivevnpnpknljlfp6lone3",
-        "one486twojknbmsqthree8",
-        "fkxfourfourlpslcbbtk29",
-        "6seveneight19four",
-        "fvcseven8hzlrgpmgfj136",
-        "sixfour9tn1tfmzkdjxxj",
-        "ffvmfqmsnineeight5",
-        "ninestmzctrthreesixqdbbvtkjfxpjpgzvpthree7zsfmsvsgxn",
-        "rz2eight6655",
-        "vhzqvbxr6eightninetwoeight66",
-        "2fiveeight7seven2",
-        "5six6vkvdbjnhbdht5rqhkfour",
-        "eightrntwocdchshssevenone2dxhmccpn",
-        "three4gsgleightxvgjpvqshlfzntwo43",
-        "9four5onebqlcmrrkvnine45",
-        "mtkkbxbfour5onemnxcglq",
-        "one18482sevenpkgxmfour",
-        "two3threexggpjbncmkvmd1",
-        "oneseven9",
-        "679b",
-        "7vc9threenine3",
-        "one35x",
-        "4eightthree9",
-        "1hclxskfgjfhvjtgqsztp",
-        "eight38d84fm",
-        "fiveone6five1five",
-        "qjpptvfsrs614fourssdrxtpqfz7",
-        "9qnrkp",
-        "six4one1zxhllkcd",
-        "six1fourtwormz7vxfbhmg",
-        "one3jxcjzz9",
-        "vzmtgdhpjneight83fivepcd",
-        "onexqhvqpvmncljmssix4twoseven",
-        "threeninenine2hg",
-        "bvjvgtdhjstsevengtbnqf4seven8two",
-        "twooneone5lrjpptxzd",
-        "hzlb1",
-        "nhrbfltwosix22rdjthctone",
-        "seven7eightthreepgj",
-        "fourfive45",
-        "nztwohnx3nine",
-        "fourhfxgmkcgbppdjkqpx2ninezdkbncsevengbdxb",
-        "sbzdcpxnine3nine",
-        "8grdrone78",
-        "1eightjldlllrxl2",
-        "588onethreesixlpdkxfc",
-        "vvvjrnzbfxgzxqlxx6vcqkkftxkdttnhpzrvtwo",
-        "3llpjrhrone5ninemdqjnpllprkphk",
-        "44pmxlrhsdsvsfxtg6",
-        "nine841jvpl",
-        "eightbpsqrkzhqbhjlrxmzsixvvmgtrseventwo7oneightjbx",
-        "znhjtgjk1three8",
-        "5xcsvvvzrqcxkqj346oneprztsfpdld6",
-        "67six1four1kqj",
-        "qvqlmqtzcj5",
-        "ninejrrhddqfivenine7psghccdhvfive",
-        "pznvtxdklpvqbsevenfiveninehzjfvrdxz8",
-        "eight46xpfdcqbmprchftkpfive",
-        "bkdvhbhlmn1",
-        "99sixv",
-        "gdoneight26pzghbjfeightttfbvhltwo8",
-        "three5nhd",
-        "three5nine",
-        "four9csdvvfdbkseven26peight",
-        "sevennszdbkkfdndjtzzpjmtqbxlkv43vxlnnrn",
-        "4fourpxzkmfksix39",
-        "9hz8crczlbbhkrsjblzd1q9jsbf",
-        "443twofour88one",
-        "2tpmlxltgsix",
-        "83835six",
-        "8twojvsgjtqvxgsevennjdvdbqccmgcldp",
-        "fivebpknnpxnjmpxntbhkjsp4fivefour",
-        "hvxnseven6two64five2six",
-        "5twoonefive",
-        "jzkd6hvcmfqsjztsixfivexlfrvn",
-        "xfbcskone12jvvbflfn",
-        "ninellrbqhshsxeight7two",
-        "4nine79jdkfkgvcr1",
-        "eight8twoltnpxckbqxnfbxtthree49",
-        "ctkfveight8",
-        "nine671seventwotwonejkf",
-        "5threesix",
-        "4sixsixzfmtzlspfcseven",
-        "4pqnrmqlscq1fkzxngndmgseven2",
-        "27four7znrkvxhvt6",
-        "lqkljjh79six7seven",
-        "2tlb84sixfour",
-        "onesevenseven5htsdxvfctkdgvqtwoeight3",
-        "66836qblqgdhnine",
-        "75ntphbdbpgktwo",
-        "jheightwovtone8fourtcsbhhntkq3nine1nine",
-        "5gcnnbjcgqn7",
-        "1nnxkfdmxhsqqttsfsgtwo5three",
-        "7sixtwoeight",
-        "9lsjcmcvqlconezxvrrptxlxleightlvghvxjgfive",
-        "one6xxf1bjjnkfeightwozv",
-        "4rqxnflktwo",
-        "5three1",
-        "vxqmbgjnr6one",
-        "eightfour538",
-        "gvjt1onetsevenonesixfive8",
-        "fourgtwopbjbcvgtwo3one",
-        "68four4htvj8bk",
-        "nmftgklbpj9onevbzzeightncszqgpl",
-        "154fnthzxccjxsztjzpvzcn4",
-        "three6fivefoursixgtzfzbkhmnplfm",
-        "63eightsixgdsdqqxzzsbnkt782",
-        "twovbntmfffivengfbkhzgm4",
-        "23sd6nnspq",
-        "nineeighttworhtvxdtxp8twoneh",
-        "four3threeonehbfhttgn39sqpctngqmzkhttn",
-        "rphtbkncs4nznsix",
-        "6three2sixsix9eightfour",
-    ];
-
-    let total: i32 = calibration_values
-        .iter()
-        .map(|input| get_two_digit_number(input))
-        .sum();
-
-    let special_total: i32 = calibration_values
-        .iter()
-        .map(|input| get_two_digit_number_extra_special(input))
-        .sum();
-
-    println!("Answer for part 1: {}", total);
-    println!("Answer for part 2: {}", special_total);
+use std::{
+    cmp::Reverse, collections::BTreeMap, error::Error, fmt::Display, fs, ops::Range, path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    automaton::Automaton,
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// The words of part 2.
+const ENGLISH: [(&str, usize); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parsing::lines(input).collect())
+    }
+
+    fn part1(calibration_values: &Self::Input<'_>) -> Answer {
+        part1(calibration_values).into()
+    }
+
+    fn part2(calibration_values: &Self::Input<'_>) -> Answer {
+        part2(calibration_values).into()
+    }
 }
 
-fn get_two_digit_number(input: &str) -> i32 {
-    let first = input
-        .split("")
-        .find_map(|char| char.parse().ok())
-        .or(Some(0))
-        .unwrap();
+/// The words that stand for a digit in a calibration line, like `eight` for 8. A vocabulary is
+/// written as TOML with a value per word, so other languages can be loaded at runtime:
+///
+/// ```toml
+/// een = 1
+/// twee = 2
+/// "één" = 1
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: BTreeMap<String, usize>,
+}
 
-    let second = input
-        .chars()
-        .rev()
-        .collect::<String>()
-        .split("")
-        .find_map(|char| char.parse().ok())
-        .or(Some(0))
-        .unwrap();
+impl Vocabulary {
+    /// `one` through `nine`.
+    pub fn english() -> Vocabulary {
+        Vocabulary {
+            words: ENGLISH
+                .iter()
+                .map(|&(word, value)| (word.to_string(), value))
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Vocabulary, String> {
+        fs::read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {error}", path.display()))?
+            .parse()
+            .map_err(|message| format!("Could not load {}: {message}", path.display()))
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let words: BTreeMap<String, usize> = toml::from_str(raw)
+            .map_err(|error| format!("expected a number per word, {}", error.message()))?;
 
-    let mut digits = "".to_owned();
+        // An empty word would match between every two characters.
+        if words.contains_key("") {
+            return Err("the vocabulary has an empty word".to_string());
+        }
 
-    digits.push_str(&first.to_string());
-    digits.push_str(&second.to_string());
+        Ok(Vocabulary { words })
+    }
+}
 
-    return digits.parse().unwrap();
+/// How a digit is written in a calibration line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Numeric,
+    Spelled,
 }
 
-fn get_two_digit_number_extra_special(input: &str) -> i32 {
-    let letter_digits = HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]);
-
-    let mut map: HashMap<usize, i32> = HashMap::new();
-
-    for (i, char) in input.chars().enumerate() {
-        if char.is_numeric() {
-            map.insert(i, char.to_digit(10).unwrap() as i32);
-            continue;
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Numeric => write!(f, "numeric"),
+            Kind::Spelled => write!(f, "spelled"),
+        }
+    }
+}
+
+/// A digit in a calibration line, found at the bytes of `span`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digit {
+    pub value: usize,
+    pub span: Range<usize>,
+    pub kind: Kind,
+}
+
+/// A calibration line without any digits, which has no calibration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigits {
+    /// The number of the line, counting from 1.
+    pub number: usize,
+    pub line: String,
+}
+
+impl Display for NoDigits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {} has no digits: '{}'", self.number, self.line)
+    }
+}
+
+impl Error for NoDigits {}
+
+/// Finds the digits of calibration lines, written as digits or spelled out as a word of the
+/// vocabulary, in a single pass over every line. Words may overlap, so `eightwo` is 82.
+#[derive(Debug, Clone)]
+pub struct Calibrator {
+    automaton: Automaton<(usize, Kind)>,
+}
+
+impl Calibrator {
+    pub fn new(vocabulary: &Vocabulary) -> Calibrator {
+        let digits = (0..10).map(|digit| (digit.to_string(), (digit, Kind::Numeric)));
+        let words = vocabulary
+            .words
+            .iter()
+            .map(|(word, &value)| (word.clone(), (value, Kind::Spelled)));
+
+        Calibrator {
+            automaton: Automaton::new(digits.chain(words)),
+        }
+    }
+
+    /// Every digit of the line, in the order they start in. Of the words that start at the same
+    /// place, the longest one comes first.
+    pub fn digits(&self, line: &str) -> Vec<Digit> {
+        let mut digits: Vec<Digit> = self
+            .automaton
+            .find_overlapping(line)
+            .map(|found| Digit {
+                value: found.value.0,
+                span: found.start..found.end,
+                kind: found.value.1,
+            })
+            .collect();
+
+        digits.sort_by_key(|digit| (digit.span.start, Reverse(digit.span.end)));
+        digits
+    }
+
+    /// The first digit of the line followed by the last one, when the line has any.
+    fn calibration_value(&self, line: &str) -> Option<usize> {
+        let digits = self.digits(line);
+        let (first, last) = first_and_last(&digits)?;
+
+        Some(combine(digits[first].value, digits[last].value))
+    }
+
+    /// The sum of the calibration values of the lines, which fails on the first line without
+    /// digits.
+    pub fn sum(&self, lines: &[&str]) -> Result<usize, NoDigits> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                self.calibration_value(line).ok_or_else(|| NoDigits {
+                    number: index + 1,
+                    line: line.to_string(),
+                })
+            })
+            .sum()
+    }
+
+    /// The line with its calibration value, followed by every digit in it, like:
+    ///
+    /// ```text
+    /// xtwone3four -> 24
+    ///   first  2  two   spelled  1..4
+    ///          1  one   spelled  3..6
+    ///          3  3     numeric  6..7
+    ///   last   4  four  spelled  7..11
+    /// ```
+    pub fn explain(&self, line: &str) -> String {
+        let digits = self.digits(line);
+
+        let Some((first, last)) = first_and_last(&digits) else {
+            return format!("{line} -> no digits");
+        };
+
+        let width = digits
+            .iter()
+            .map(|digit| line[digit.span.clone()].chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let value = combine(digits[first].value, digits[last].value);
+        let mut explanation = format!("{line} -> {value}");
+
+        for (index, digit) in digits.iter().enumerate() {
+            let role = match (index == first, index == last) {
+                (true, true) => "both",
+                (true, false) => "first",
+                (false, true) => "last",
+                (false, false) => "",
+            };
+
+            explanation.push_str(&format!(
+                "\n  {role:<5}  {}  {:<width$}  {}  {}..{}",
+                digit.value,
+                &line[digit.span.clone()],
+                digit.kind,
+                digit.span.start,
+                digit.span.end
+            ));
         }
 
-        letter_digits.iter().for_each(|(key, value)| {
-            if i + key.len() > input.len() {
-                return;
-            }
+        explanation
+    }
+}
+
+/// The indices of the first and the last digit, which are the longest of the digits that start at
+/// the first and the last place.
+/// The calibration value of the first and last digit: the two written one after the other. Words
+/// for numbers above 9 are written with all of their digits, so `tenten` is 1010.
+fn combine(first: usize, last: usize) -> usize {
+    let width = last.checked_ilog10().unwrap_or(0) + 1;
+
+    first * 10_usize.pow(width) + last
+}
+
+fn first_and_last(digits: &[Digit]) -> Option<(usize, usize)> {
+    let start = digits.last()?.span.start;
+    let last = digits.iter().position(|digit| digit.span.start == start)?;
+
+    Some((0, last))
+}
+
+/// The sum of the calibration values with the words of the vocabulary, after every line with its
+/// digits when `explain` is set. This is what `aoc explore day1` prints.
+pub fn explore(input: &str, vocabulary: &Vocabulary, explain: bool) -> Result<String, String> {
+    let input = parsing::normalise(input);
+    let lines = input.parse_in_day(Day1::DAY, Day1::parse)?;
+    let calibrator = Calibrator::new(vocabulary);
+    let mut report = vec![];
+
+    if explain {
+        report.extend(lines.iter().map(|line| calibrator.explain(line)));
+    }
+
+    let sum = calibrator
+        .sum(&lines)
+        .map_err(|error| format!("Could not calibrate: {error}"))?;
+    report.push(format!("Sum of the calibration values: {sum}"));
+
+    Ok(report.join("\n"))
+}
+
+fn part1(calibration_values: &[&str]) -> usize {
+    Calibrator::new(&Vocabulary::default())
+        .sum(calibration_values)
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
+fn part2(calibration_values: &[&str]) -> usize {
+    Calibrator::new(&Vocabulary::english())
+        .sum(calibration_values)
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input, second_example};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(142, part1(&Day1::parse(&example(1)).unwrap()));
+    }
 
-            if input[i..i + key.len()] == **key {
-                map.insert(i, *value);
-            }
-        });
+    #[test]
+    fn part1_input() {
+        assert_eq!(55538, part1(&Day1::parse(&input(1)).unwrap()));
     }
 
-    let mut digits = "".to_owned();
-
-    digits.push_str(
-        &map.iter()
-            .min_by(|(key_1, _), (key_2, _)| key_1.cmp(key_2))
-            .unwrap()
-            .1
-            .to_string(),
-    );
-    digits.push_str(
-        &map.iter()
-            .max_by(|(key_1, _), (key_2, _)| key_1.cmp(key_2))
-            .unwrap()
-            .1
-            .to_string(),
-    );
-
-    return digits.parse().unwrap();
+    #[test]
+    fn part2_example() {
+        assert_eq!(281, part2(&Day1::parse(&second_example(1)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(54875, part2(&Day1::parse(&input(1)).unwrap()));
+    }
+
+    #[test]
+    fn counts_overlapping_words() {
+        let calibrator = Calibrator::new(&Vocabulary::english());
+
+        assert_eq!(Some(82), calibrator.calibration_value("eightwo"));
+        assert_eq!(Some(21), calibrator.calibration_value("xtwone"));
+        assert_eq!(Some(77), calibrator.calibration_value("seven"));
+        assert_eq!(None, calibrator.calibration_value("abc"));
+    }
+
+    #[test]
+    fn finds_every_digit_with_its_span() {
+        let calibrator = Calibrator::new(&Vocabulary::english());
+
+        assert_eq!(
+            vec![
+                Digit {
+                    value: 2,
+                    span: 1..4,
+                    kind: Kind::Spelled
+                },
+                Digit {
+                    value: 1,
+                    span: 3..6,
+                    kind: Kind::Spelled
+                },
+                Digit {
+                    value: 3,
+                    span: 6..7,
+                    kind: Kind::Numeric
+                },
+            ],
+            calibrator.digits("xtwone3")
+        );
+    }
+
+    #[test]
+    fn refuses_lines_without_digits() {
+        let calibrator = Calibrator::new(&Vocabulary::default());
+
+        assert_eq!(Ok(47), calibrator.sum(&["a1b2", "3c4d5"]));
+        assert_eq!(
+            Err(NoDigits {
+                number: 2,
+                line: "two".to_string()
+            }),
+            calibrator.sum(&["a1b2", "two", "3"])
+        );
+    }
+
+    #[test]
+    fn explains_every_line() {
+        let calibrator = Calibrator::new(&Vocabulary::english());
+
+        assert_eq!(
+            "xtwone3four -> 24\n\
+            \x20 first  2  two   spelled  1..4\n\
+            \x20        1  one   spelled  3..6\n\
+            \x20        3  3     numeric  6..7\n\
+            \x20 last   4  four  spelled  7..11",
+            calibrator.explain("xtwone3four")
+        );
+        assert_eq!("abc -> no digits", calibrator.explain("abc"));
+        assert_eq!(
+            "a7b -> 77\n  both   7  7  numeric  1..2",
+            calibrator.explain("a7b")
+        );
+    }
+
+    #[test]
+    fn loads_other_vocabularies() {
+        let dutch: Vocabulary = "een = 1\ntwee = 2\n\"één\" = 1\nnul = 0".parse().unwrap();
+        let calibrator = Calibrator::new(&dutch);
+
+        assert_eq!(Some(21), calibrator.calibration_value("tweeëéén"));
+        assert_eq!(Some(2), calibrator.calibration_value("nulxtwee"));
+        assert_eq!(None, calibrator.calibration_value("one two"));
+
+        let ordinals: Vocabulary = "first = 1\nsecond = 2\nten = 10".parse().unwrap();
+        let calibrator = Calibrator::new(&ordinals);
+
+        assert_eq!(Some(12), calibrator.calibration_value("firstsecond"));
+        assert_eq!(Some(1010), calibrator.calibration_value("tenten"));
+        assert_eq!(Some(110), calibrator.calibration_value("firstxten"));
+
+        let teens: Vocabulary = "seven = 7\nseventeen = 17".parse().unwrap();
+        let calibrator = Calibrator::new(&teens);
+
+        assert_eq!(
+            Some(1717),
+            calibrator.calibration_value("seventeenxseventeen")
+        );
+        assert_eq!(Some(177), calibrator.calibration_value("seventeenxseven"));
+    }
+
+    #[test]
+    fn rejects_broken_vocabularies() {
+        assert!("one = \"1\"".parse::<Vocabulary>().is_err());
+        assert!("\"\" = 1".parse::<Vocabulary>().is_err());
+        assert!("one = -1".parse::<Vocabulary>().is_err());
+    }
 }
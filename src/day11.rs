@@ -1,7 +1,24 @@
-use std::collections::{HashMap, HashSet};
+use log::{debug, trace};
 use rayon::prelude::*;
+use std::collections::HashSet;
+
+use crate::{
+    graph::{dijkstra, Paths},
+    grid::Grid,
+    parsing::ParseError,
+    progress::Progress,
+    render::{Picture, Rgb},
+    solution::{Answer, Solution},
+};
 
 type Point = (usize, usize);
+type Universe = Grid<Space>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    Galaxy,
+}
 type Route = Vec<Point>;
 
 #[derive(Debug)]
@@ -12,37 +29,50 @@ struct GalaxyPair {
     length: Option<usize>,
 }
 
-fn main() {
-    assert_eq!(9605127, part1());
-    assert_eq!(458191688761, part2());
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Universe;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(universe: &Self::Input<'_>) -> Answer {
+        part1(universe).into()
+    }
+
+    fn part2(universe: &Self::Input<'_>) -> Answer {
+        part2(universe).into()
+    }
+
+    fn render(universe: &Self::Input<'_>) -> Option<Picture> {
+        Some(render(universe))
+    }
 }
 
-fn part1() -> usize {
-    let universe = input();
-    let galaxies = find_galaxies(&universe);
+fn part1(universe: &Universe) -> usize {
+    let galaxies = find_galaxies(universe);
     let galaxy_groups = make_galaxy_groups(&galaxies);
     let expansion_rate = 2;
 
-    println!(
+    debug!(
         "We found {} galaxies, which makes for {} smart pairs.",
         galaxies.len(),
         galaxy_groups.len()
     );
 
-    let expansion_points = get_expansion_points(&universe);
+    let expansion_points = get_expansion_points(universe);
+    let progress = Progress::new("galaxies", galaxy_groups.len());
 
     let pairs_with_shortest_paths: Vec<GalaxyPair> = galaxy_groups
         .iter()
         .flat_map(|(origin, destinations)| {
-            let routes = find_shortest_path_dijkstras(
-                &universe,
-                origin,
-                &destinations.iter().map(|&&foo| foo).collect(),
-                expansion_rate,
-                &expansion_points,
-            );
+            let routes = find_shortest_paths(universe, origin, expansion_rate, &expansion_points);
 
-            println!("Found all routes for origin {:?}", origin);
+            progress.advance(1);
 
             destinations
                 .iter()
@@ -50,14 +80,7 @@ fn part1() -> usize {
                     origin: **origin,
                     destination: **destination,
                     route: None,
-                    length: Some(
-                        routes
-                            .iter()
-                            .find(|route| route.0 == **destination)
-                            .unwrap()
-                            .1
-                             .1,
-                    ),
+                    length: routes.cost(destination),
                 })
                 .collect::<Vec<_>>()
         })
@@ -68,207 +91,116 @@ fn part1() -> usize {
     })
 }
 
-fn get_expansion_points(universe: &String) -> Vec<Point> {
-    let mut expansion_points = vec![];
+fn get_expansion_points(universe: &Universe) -> HashSet<Point> {
+    let mut expansion_points = HashSet::new();
 
-    for (y, line) in universe.lines().enumerate() {
-        if !line.chars().any(|ch| ch == '#') {
-            expansion_points.extend((0..line.len()).map(|x| (x, y)));
+    for (y, row) in universe.rows().enumerate() {
+        if !row.contains(&Space::Galaxy) {
+            expansion_points.extend((0..universe.width()).map(|x| (x, y)));
         }
     }
 
-    for x in (0..universe.lines().nth(0).unwrap().len())
-        .into_iter()
-        .filter(|i| {
-            universe
-                .lines()
-                .all(|line| line.chars().nth(*i).unwrap() != '#')
-        })
-    {
-        expansion_points.extend((0..universe.lines().count()).map(|y| (x, y)));
+    for (x, mut column) in universe.columns().enumerate() {
+        if !column.any(|&space| space == Space::Galaxy) {
+            expansion_points.extend((0..universe.height()).map(|y| (x, y)));
+        }
     }
 
     expansion_points
 }
 
-fn find_galaxies(universe: &String) -> Vec<Point> {
-    let mut galaxies = vec![];
-
-    for (y, line) in universe.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if ch == '#' {
-                galaxies.push((x, y));
-            }
-        }
-    }
-
-    galaxies
+fn find_galaxies(universe: &Universe) -> Vec<Point> {
+    universe
+        .iter()
+        .filter(|(_, &space)| space == Space::Galaxy)
+        .map(|(position, _)| position)
+        .collect()
 }
 
-fn make_galaxy_groups(galaxy_coordinates: &Vec<Point>) -> Vec<(&Point, Vec<&Point>)> {
+fn make_galaxy_groups(galaxy_coordinates: &[Point]) -> Vec<(&Point, Vec<&Point>)> {
     let mut groups = vec![];
 
-    for i in 0..galaxy_coordinates.len() {
-        let mut destinations = vec![];
-        for ii in (i + 1)..galaxy_coordinates.len() {
-            destinations.push(&galaxy_coordinates[ii]);
-        }
+    for (i, origin) in galaxy_coordinates.iter().enumerate() {
+        let destinations: Vec<&Point> = galaxy_coordinates[i + 1..].iter().collect();
 
-        if destinations.len() > 0 {
-            groups.push((&galaxy_coordinates[i], destinations));
+        if !destinations.is_empty() {
+            groups.push((origin, destinations));
         }
     }
 
     groups
 }
 
-fn find_shortest_path_dijkstras(
-    universe: &String,
+/// The shortest paths from the origin to every point in the universe, where crossing an
+/// expanded row or column takes `expansion_rate` steps.
+fn find_shortest_paths(
+    universe: &Universe,
     origin: &Point,
-    destinations: &Vec<Point>,
     expansion_rate: usize,
-    expansion_points: &Vec<Point>,
-) -> Vec<(Point, (Route, usize))> {
-    let mut nodes_discovered: HashMap<Point, (Vec<Point>, usize)> = HashMap::new();
-    nodes_discovered.insert(*origin, (vec![*origin], 0));
-    let mut destinations_discovered: HashMap<Point, (Route, usize)> = HashMap::new();
-
-    let max_x = universe
-        .lines()
-        .nth(0)
-        .expect("The universe should have a first line")
-        .chars()
-        .count()
-        - 1;
-    let max_y = universe.lines().count() - 1;
-
-    let mut edge_nodes: HashSet<Point> = HashSet::new();
-
-    edge_nodes.insert(*origin);
-
-    loop {
-        if destinations_discovered.len() == destinations.len() {
-            return destinations_discovered.into_iter().collect();
-        }
-
-        let mut next_edge_nodes: HashSet<Point> = HashSet::new();
-
-        for edge_node in &edge_nodes {
-            for (x, y) in get_neighbors_bounded(&edge_node, &(0, 0), &(max_x, max_y)) {
-                let new_dest = (x, y);
-
-                let (mut new_path, mut new_length) =
-                    nodes_discovered.get(&edge_node).unwrap().clone();
-
-                new_path.push(new_dest);
-                new_length += match expansion_points.contains(&new_dest) {
+    expansion_points: &HashSet<Point>,
+) -> Paths<Point, usize> {
+    dijkstra(
+        *origin,
+        |&point| {
+            universe.neighbours4(point).map(|neighbor| {
+                let steps = match expansion_points.contains(&neighbor) {
                     true => expansion_rate,
                     false => 1,
                 };
 
-                next_edge_nodes.insert(new_dest);
-
-                if destinations.contains(&new_dest) {
-                    if let Some((_, stored_length)) =
-                        destinations_discovered.get(&new_dest)
-                    {
-                        if new_length < *stored_length {
-                            destinations_discovered
-                                .insert(new_dest, (new_path.clone(), new_length));
-                        }
-                    } else {
-                        destinations_discovered.insert(new_dest, (new_path.clone(), new_length));
-                    }
-                }
-
-                if let Some(node) = nodes_discovered.get(&(x, y)) {
-                    if new_length < node.1 {
-                        nodes_discovered.insert(new_dest, (new_path, new_length));
-                    }
-                } else {
-                    nodes_discovered.insert(new_dest, (new_path, new_length));
-                }
-            }
-        }
-
-        edge_nodes = next_edge_nodes;
-    }
+                (neighbor, steps)
+            })
+        },
+        |_| false,
+    )
 }
 
-fn get_neighbors_bounded(
-    (x, y): &Point,
-    (min_x, min_y): &Point,
-    (max_x, max_y): &Point,
-) -> Vec<Point> {
-    let mut neighbors = vec![];
+/// The galaxies in white and the expanded rows and columns in blue, with the shortest path from
+/// the first galaxy to the last one in yellow.
+fn render(universe: &Universe) -> Picture {
+    let galaxies = find_galaxies(universe);
+    let expansion_points = get_expansion_points(universe);
 
-    if *x > *min_x {
-        neighbors.push((*x - 1, *y));
-    }
-
-    if *x < *max_x {
-        neighbors.push((*x + 1, *y));
-    }
+    let picture = Picture::of_grid(universe, |&space| match space {
+        Space::Empty => Rgb::BLACK,
+        Space::Galaxy => Rgb::WHITE,
+    })
+    .highlight(
+        expansion_points.iter().copied(),
+        Rgb::BLACK.blend(Rgb::BLUE, 0.5),
+    );
 
-    if *y > *min_y {
-        neighbors.push((*x, *y - 1));
-    }
+    let (Some(first), Some(last)) = (galaxies.first(), galaxies.last()) else {
+        return picture;
+    };
 
-    if *y < *max_y {
-        neighbors.push((*x, *y + 1));
-    }
+    let route = find_shortest_paths(universe, first, 2, &expansion_points)
+        .path(last)
+        .expect("Every galaxy can be reached");
 
-    neighbors
+    picture.path(route, Rgb::YELLOW)
 }
 
-#[allow(dead_code)]
-fn visualize_path_in_universe(universe: &String, path: &Route) {
-    let mut output: String = "".to_owned();
-
-    for (y, line) in universe.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if path.first() == Some(&(x, y)) {
-                output += "1";
-            } else if path.last() == Some(&(x, y)) {
-                output += "2";
-            } else if path.contains(&(x, y)) {
-                output += "+";
-            } else {
-                output += &ch.to_string();
-            }
-        }
-        output += "\n";
-    }
-
-    println!("{output}\n----------------------------");
-}
-
-fn part2() -> usize {
-    let universe = input();
-    let galaxies = find_galaxies(&universe);
+fn part2(universe: &Universe) -> usize {
+    let galaxies = find_galaxies(universe);
     let galaxy_groups = make_galaxy_groups(&galaxies);
     let expansion_rate = 1_000_000;
 
-    println!(
+    debug!(
         "We found {} galaxies, which makes for {} smart pairs.",
         galaxies.len(),
         galaxy_groups.len()
     );
 
-    let expansion_points = get_expansion_points(&universe);
+    let expansion_points = get_expansion_points(universe);
+    let progress = Progress::new("galaxies", galaxy_groups.len());
 
     let pairs_with_shortest_paths: Vec<GalaxyPair> = galaxy_groups
         .par_iter()
         .flat_map(|(origin, destinations)| {
-            let routes = find_shortest_path_dijkstras(
-                &universe,
-                origin,
-                &destinations.iter().map(|&&foo| foo).collect(),
-                expansion_rate,
-                &expansion_points,
-            );
+            let routes = find_shortest_paths(universe, origin, expansion_rate, &expansion_points);
 
-            println!("Found all routes for origin {:?}", origin);
+            progress.advance(1);
 
             destinations
                 .iter()
@@ -276,21 +208,14 @@ fn part2() -> usize {
                     origin: **origin,
                     destination: **destination,
                     route: None,
-                    length: Some(
-                        routes
-                            .iter()
-                            .find(|route| route.0 == **destination)
-                            .unwrap()
-                            .1
-                             .1,
-                    ),
+                    length: routes.cost(destination),
                 })
                 .collect::<Vec<_>>()
         })
         .collect();
 
     for path in &pairs_with_shortest_paths {
-        println!(
+        trace!(
             "Path: from ({}, {}) to ({}, {}). Length: {}. Route: {:?}",
             path.origin.0,
             path.origin.1,
@@ -306,167 +231,36 @@ fn part2() -> usize {
     })
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct MinDistanceNode {
-    point: Point,
-    distance: usize,
+fn parse(input: &str) -> Result<Universe, ParseError> {
+    Grid::parse(input, |ch| match ch {
+        '.' => Some(Space::Empty),
+        '#' => Some(Space::Galaxy),
+        _ => None,
+    })
 }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#....."
-        .to_string()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(374, part1(&Day11::parse(&example(11)).unwrap()));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(9_605_127, part1(&Day11::parse(&input(11)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> String {
-    "...........................#.............#..........#.........#..................#.......#.............................................#....
-..............#...........................................................................................#...............#.................
-.....................#......................................................................................................................
-...................................#................................#................................................#....................#.
-.................#.......................................................#..........#..................#.............................#......
-...............................................................................................#.................#..........................
-......................................................#........................#........................................#...................
-........#.....................................................#...........................#.................#...............................
-.........................................#.....#...............................................................................#............
-...........................#.........................................................#...................................................#..
-.....#................................................................#............................................................#........
-.............#..........................................#.......................................#.................#.........#...............
-............................................#...............................................................................................
-.#...............#.....................#....................................#.............................#................................#
-............................#....................#..........................................................................................
-...................................................................................................#..........#.............................
-.......#............#.........................................#........#.........................................................#..........
-..............#.................................................................#..........#............................#...................
-................................#........................................................................................................#..
-...............................................#.....................................................#...........#..........................
-#...............................................................#...........................................................................
-............#......#...................................#....................................................#.............#.........#.......
-.......................................................................................#....................................................
-....................................#........................#......#...........................#...........................................
-.........#.....#.............#............#.....................................#.....................................#.....................
-..........................................................................................................#.....#...............#..........#
-........................................................#...................................................................................
-..........................#.................................................................................................................
-....#..................................................................#..................................................#.................
-.....................#..........................................#...........................#.........#.....................................
-...........#.........................#.......................................#......#.................................................#.....
-#...............................................................................................#..................#............#...........
-............................#.............#.........#.......................................................................................
-........#...........................................................#.......................................................................
-.........................................................................#..........................#.......................................
-..#..........#............................................#.....#.....................#...............................#.....................
-.....................................#...............................................................................................#......
-....................#......................#....................................................................................#...........
-.....#......................................................................................................................................
-................................#....................#............................#.........................................................
-........................#......................................................................#.............................#..............
-.......................................................................#....................................................................
-..............#.................................#......................................................#.......#..................#.......#.
-............................................................#............................#..................................................
-..........................................................................................................................#.................
-..#.................#.........#............................................#.......#........................................................
-.......#.............................................#........................................#.......................#.....................
-...............................................................#.................................................................#..........
-...........................#...............#.......................................................#.....................................#..
-.................................#..............#...............................................................#...........................
-...............#........................................................................#...................................................
-.........................................................#......................................#..........#................................
-..#......................................#................................#.................................................................
-...............................#............................................................#..............................................#
-..........#........#............................................#...............................................................#...........
-................................................................................#..................#...........#..........#.................
-..................................#........#................................................................................................
-............................................................................................................................................
-.....................#............................................#............................#.......#.....................#..............
-............................#........................#.....................#..........#..........................#.....................#....
-.........................................................................................................................#..................
-.#.........#...........................#.......................#...................................#........................................
-................#........#.....................................................................................................#..........#.
-...........................................................................................#.................#..............................
-...............................#......................................#............................................#........................
-.........................................#.......#......................................................#...................................
-..........#..................................................#.................#............................................................
-#.......................#..............................................................#...........................................#.......#
-.................................#......................#........................................................#........#.................
-............................................................................................................................................
-...............................................#...............................................#............................................
-................#.....................#.......................................#.............................................................
-......................................................#...............#.....................................................................
-...................................................................................#.............................................#..........
-..................................................................#........#....................................#...........................
-.#.......................................................#.................................#............#...................................
-............................................................................................................................................
-........#.......................#................................................#.........................................#................
-..................#......#......................................................................#...........................................
-......................................#...............#..........#.....................................................................#....
-...#....................................................................................#...................................................
-...............#..............................#.............................#...............................................................
-...................................#..............................................................#.....#........................#.........#
-.......#............#...................................................................................................#...................
-...........................#.................................................................#................#.............................
-.....................................................#...............................................#......................................
-...........#..............................................#.................................................................................
-..............................................#.................#...............#.........#.................................................
-................#.......#...........#.................................#...................................#.................................
-.....................................................................................#...........#...................#......................
-........................................................#......................................................................#.......#....
-.....#......................#...............................................................................................................
-.............#........#..........................#..........................................................#.............#................#
-............................................................................................................................................
-........#............................#............................................#..........#..............................................
-............................................#...................................................................#...........................
-............................................................................................................................................
-..#..............#......#.........................................#...............................#.........................................
-....................................................#......................#.......................................................#......#.
-...................................#........................#................................................................#..............
-.....................................................................#...........#..........................................................
-...............#.............................#...........................................................#..................................
-..........#..............................................................................#.....#.................#..........................
-.....................................................................................................................................#......
-.#................................#.........................................................................#...............................
-........................#.........................................#......#.........#................#.......................................
-.................#........................................#..................................................................#..............
-........................................#...................................................................................................
-....#.....#........................................#............................................#...........................................
-............................................................................................................................................
-..............................................................................#.....................................#................#......
-.....................#................................................................#.........................................#...........
-.............................#.........#........#.........#.............#...........................#.......................................
-................................................................#.........................#..................#............#.............#...
-........#.......................................................................#...........................................................
-........................#..................#............................................................#...................................
-.....................................#.................................................#.........#................#.........................
-...................................................#.....................#....................................................#............#
-............................................................................................................................................
-................#..............................................#.................#............................#.............................
-....#........................#..........................#............#......................................................................
-....................................#........................................................#..............................................
-.......................#..........................................................................#.....#...........#............#..........
-........#..................................#................#.............#...........................................................#.....
-................................................#.......................................................................#..................#
-..................#.................................................................#.......................................................
-............#........................#...........................................................................#.............#............
-...#........................................................................................................................................
-..........................................#.................................#................#..............................................
-.......................#............................#.............#...................................................#.............#.......
-.................................#....................................................#........................#............................
-.................................................................................#........................................................#.
-..........................................................#.............................................#...................................
-...#............#...........................................................................................................................
-..............................................#...................................................#.........................................
-.........................................................................#.....#.......#.........................................#..........
-............#.........#......................................................................#........#.....................................
-...........................#........................................#........................................#.......................#......
-.....#.................................#..............#........#.....................................................#......................
-...............................#............................................#........#....................................#.................".to_string()
+    #[test]
+    fn part2_example() {
+        assert_eq!(82_000_210, part2(&Day11::parse(&example(11)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(458_191_688_761, part2(&Day11::parse(&input(11)).unwrap()));
+    }
 }
@@ -1,5 +1,13 @@
 use std::fmt::Display;
 
+use rayon::prelude::*;
+
+use crate::{
+    grid::Grid,
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Split {
     Horizontal,
@@ -12,9 +20,18 @@ enum ObjectType {
     Rocks,
 }
 
+impl Display for ObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectType::Ash => write!(f, "."),
+            ObjectType::Rocks => write!(f, "#"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Pattern {
-    objects: Vec<Vec<ObjectType>>,
+pub struct Pattern {
+    objects: Grid<ObjectType>,
     split: Option<Split>,
     mirrored_after: Option<usize>,
 }
@@ -29,35 +46,36 @@ struct SmudgedPattern<'a> {
 
 impl Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut output = String::new();
+        write!(f, "{}", self.objects)
+    }
+}
 
-        for line in &self.objects {
-            for ch in line {
-                output.push_str(match ch {
-                    ObjectType::Ash => ".",
-                    ObjectType::Rocks => "#",
-                });
-            }
+pub struct Day13;
 
-            output.push_str("\n");
-        }
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Pattern>;
 
-        write!(f, "{}", output)
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(patterns: &Self::Input<'_>) -> Answer {
+        part1(patterns).into()
     }
-}
 
-fn main() {
-    assert_eq!(405, part1(&test_input()));
-    assert_eq!(27202, part1(&input()));
-    assert_eq!(400, part2(&test_input()));
-    assert_eq!(41566, part2(&input()));
+    fn part2(patterns: &Self::Input<'_>) -> Answer {
+        part2(patterns).into()
+    }
 }
 
-fn part1(input: &String) -> usize {
-    parse(input)
-        .iter_mut()
-        .map(|pattern| {
-            let binding = find_mirror_line(pattern);
+fn part1(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .cloned()
+        .map(|mut pattern| {
+            let binding = find_mirror_line(&pattern);
             let (split, after) = binding.first().unwrap();
             pattern.mirrored_after = Some(*after);
             pattern.split = Some(*split);
@@ -72,8 +90,10 @@ fn part1(input: &String) -> usize {
         .sum()
 }
 
-fn part2(input: &String) -> usize {
-    parse(input)
+fn part2(patterns: &[Pattern]) -> usize {
+    let mut patterns = patterns.to_vec();
+
+    patterns
         .iter_mut()
         .map(|pattern| {
             let binding = find_mirror_line(pattern);
@@ -83,17 +103,15 @@ fn part2(input: &String) -> usize {
 
             let mut potential_patterns: Vec<(Pattern, Point)> = vec![];
 
-            for i in 0..pattern.objects.len() {
-                for ii in 0..pattern.objects[0].len() {
-                    let mut potential = pattern.clone();
+            for position in pattern.objects.positions() {
+                let mut potential = pattern.clone();
 
-                    potential.objects[i][ii] = match potential.objects[i][ii] {
-                        ObjectType::Ash => ObjectType::Rocks,
-                        ObjectType::Rocks => ObjectType::Ash,
-                    };
+                potential.objects[position] = match potential.objects[position] {
+                    ObjectType::Ash => ObjectType::Rocks,
+                    ObjectType::Rocks => ObjectType::Ash,
+                };
 
-                    potential_patterns.push((potential, (ii, i)));
-                }
+                potential_patterns.push((potential, position));
             }
 
             let all_smudged = SmudgedPattern {
@@ -101,31 +119,35 @@ fn part2(input: &String) -> usize {
                 potential_patterns: potential_patterns.clone(),
             };
 
-            let mut valid_smudged = SmudgedPattern {
-                original: pattern,
-                potential_patterns: vec![],
-            };
-
-            for potential in &all_smudged.potential_patterns {
-                for result in find_mirror_line(&potential.0) {
-                    if result.0 == all_smudged.original.split.unwrap()
-                        && result.1 == all_smudged.original.mirrored_after.unwrap()
-                    {
-                        continue;
-                    }
+            // Collecting keeps the order of the candidates, so the first valid smudge is the same
+            // on every run.
+            let potential_patterns = all_smudged
+                .potential_patterns
+                .par_iter()
+                .flat_map_iter(|potential| {
+                    find_mirror_line(&potential.0)
+                        .into_iter()
+                        .filter(|result| {
+                            result.0 != all_smudged.original.split.unwrap()
+                                || result.1 != all_smudged.original.mirrored_after.unwrap()
+                        })
+                        .map(|result| {
+                            (
+                                Pattern {
+                                    objects: potential.0.objects.clone(),
+                                    split: Some(result.0),
+                                    mirrored_after: Some(result.1),
+                                },
+                                potential.1,
+                            )
+                        })
+                })
+                .collect();
 
-                    valid_smudged.potential_patterns.push((
-                        Pattern {
-                            objects: potential.0.objects.clone(),
-                            split: Some(result.0),
-                            mirrored_after: Some(result.1),
-                        },
-                        potential.1,
-                    ));
-                }
+            SmudgedPattern {
+                original: pattern,
+                potential_patterns,
             }
-
-            valid_smudged
         })
         .map(|smudged| {
             if let Some(valid) = &smudged.potential_patterns.first() {
@@ -144,9 +166,13 @@ fn part2(input: &String) -> usize {
 fn find_mirror_line(pattern: &Pattern) -> Vec<(Split, usize)> {
     let mut mirror_lines = vec![];
 
-    mirror_lines.extend(find_horizontal_mirror_lines(&pattern));
+    mirror_lines.extend(find_horizontal_mirror_lines(pattern));
 
-    let rotated = rotate_objects_clockwise(&pattern);
+    let rotated = Pattern {
+        objects: pattern.objects.rotate_clockwise(),
+        split: None,
+        mirrored_after: None,
+    };
 
     mirror_lines.extend(
         find_horizontal_mirror_lines(&rotated)
@@ -161,25 +187,21 @@ fn find_mirror_line(pattern: &Pattern) -> Vec<(Split, usize)> {
 fn find_horizontal_mirror_lines(pattern: &Pattern) -> Vec<(Split, usize)> {
     let mut mirror_lines = vec![];
 
-    let width = pattern.objects[0].len();
+    let height = pattern.objects.height();
 
-    'mirror_line: for line_nr in 0..pattern.objects.len() - 1 {
+    'mirror_line: for line_nr in 0..height - 1 {
         // Check if "after" this line is the mirror line.
         for check_iter in 0..=line_nr {
             // When either of the lines isn't present, we can ignore it
-            if pattern.objects.get(line_nr - check_iter).is_none()
-                || pattern.objects.get(line_nr + check_iter + 1).is_none()
-            {
+            if line_nr + check_iter + 1 >= height {
                 mirror_lines.push((Split::Horizontal, line_nr));
                 continue;
             }
 
-            for char_index in 0..width {
-                if pattern.objects.get(line_nr - check_iter).unwrap()[char_index]
-                    != pattern.objects.get(line_nr + check_iter + 1).unwrap()[char_index]
-                {
-                    continue 'mirror_line;
-                }
+            if pattern.objects.row(line_nr - check_iter)
+                != pattern.objects.row(line_nr + check_iter + 1)
+            {
+                continue 'mirror_line;
             }
         }
 
@@ -192,1451 +214,46 @@ fn find_horizontal_mirror_lines(pattern: &Pattern) -> Vec<(Split, usize)> {
     mirror_lines
 }
 
-fn rotate_objects_clockwise(original: &Pattern) -> Pattern {
-    let width = original.objects[0].len();
-    let height = original.objects.len();
-
-    let mut rotated: Vec<Vec<ObjectType>> = vec![vec![]; width];
-
-    for i in 0..height {
-        for ii in 0..width {
-            rotated[ii].push(original.objects[height - 1 - i][ii]);
-        }
-    }
-
-    Pattern {
-        objects: rotated,
-        split: None,
-        mirrored_after: None,
-    }
-}
-
-fn parse(input: &str) -> Vec<Pattern> {
-    input
-        .split("\n\n")
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    parsing::blocks(input)
         .map(|raw| {
-            let objects: Vec<Vec<ObjectType>> = raw
-                .split("\n")
-                .map(|row| {
-                    row.chars()
-                        .map(|ch| match ch {
-                            '.' => ObjectType::Ash,
-                            '#' => ObjectType::Rocks,
-                            other => {
-                                panic!("We encountered '{other}', which is not ash and not rocks")
-                            }
-                        })
-                        .collect()
-                })
-                .collect();
+            let objects = Grid::parse(raw, |ch| match ch {
+                '.' => Some(ObjectType::Ash),
+                '#' => Some(ObjectType::Rocks),
+                _ => None,
+            })?;
 
-            Pattern {
+            Ok(Pattern {
                 objects,
                 split: None,
                 mirrored_after: None,
-            }
+            })
         })
         .collect()
 }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#"
-        .to_string()
-}
-
-#[allow(dead_code)]
-fn input() -> String {
-    ".####..#.#.#.##..
-........#..##....
-..##..#.....#..##
-......##.##.#####
-######.#.####....
-..##....#..##.#..
-.#..#..#####.#...
-..##...#..#...#.#
-#######.#....####
-
-#.###..####
-..#####....
-#.##...####
-##..#.##..#
-##.#.##.##.
-##.#.##.##.
-##..#.##..#
-#.##...####
-..###.#....
-#.###..####
-.##....#..#
-
-.##...#...#####
-...######..#.#.
-..#.#..#.####..
-#..#...##.##..#
-#..#...##.##..#
-..#.#..#.####..
-#..######..#.#.
-.##...#...#####
-#..#..#.#...#.#
-####.#####..#..
-....#.##..##...
-#..######..#.##
-##.#...#..##.#.
-##.#...#..##.#.
-#..######..#.##
-....#.##..##...
-####.#####..#..
-
-#####..#####.##
-.............##
-#..######..##..
-.##.#..#.##.#.#
-....####....##.
-#..##..##..###.
-#.########.#.#.
-.##..##..##...#
-#..##..##..##..
-####....####..#
-....####....#..
-
-...#....##...
-.#.##....#...
-#..##.####.#.
-#.#####.##.#.
-##...#.##.#.#
-##...#.##.#.#
-#.#####.##.#.
-#..##.####.#.
-.#.##....#...
-...#....##...
-...#....##...
-.#.##....#...
-#..##.####.#.
-#.###.#.##.#.
-##...#.##.#.#
-
-####.##.#..#.#...
-....##.####.#.#..
-....##.####.#.#..
-####.##.#..#.#...
-..#####...####.##
-#####..##.###...#
-.##...##.....###.
-.##...##.....###.
-#####..##.###...#
-..#####...####.##
-#.##.##.#..#.#...
-
-#.##########.
-##.#..##..#.#
-..#.##..##.#.
-#...#....#...
-##.#......#.#
-#.#.#....#.#.
-###...##...##
-###...##...##
-#.#.#....#.#.
-##.#......#.#
-....#....#...
-
-....##....##.....
-.####.####.####..
-###....##....###.
-###....##....###.
-.####.####.####..
-....##....##.....
-..##..#..#..##..#
-.###############.
-.##.#..##..#.##..
-
-####.#..#.####..#
-####..##..#######
-#.#.#.##.#.#.####
-##.#..##..#.##..#
-.#####...####....
-.###.#..#.###....
-.##..#..#..##....
-..#.######.#..##.
-#..#......#..#..#
-######..######..#
-#.##..##..##.####
-..##.####.##..##.
-...##.##.##...##.
-..#.#....#.#..##.
-.##..#..#..##.##.
-#####....########
-##..##..##..#####
-
-..###.#..#.##
-#####.####.##
-...##.#..#.##
-...##.####.##
-###.#......#.
-###..######..
-###..........
-..##..##.#..#
-...###.##.###
-
-##...####...###
-##...####...###
-#....#..#....##
-##..........###
-#.##########.#.
-..#..#..#.##...
-.#...####...#.#
-#..#.####.#..#.
-.##..####..##.#
-###..####..###.
-#..##.##.##..#.
-#.#........#.#.
-#.....##.....#.
-
-#..#####.#.
-.#.#...####
-.##.#####.#
-#...###.#.#
-#..####.#.#
-.##.#####.#
-.#.#...####
-#..#####.#.
-####..#..##
-####....##.
-####....##.
-####..#..##
-#..#####.#.
-.#.#...####
-.##.#####.#
-
-.######.####...
-..#..#....##...
-..#..#....##...
-.######.####...
-###..######.##.
-.........#..###
-..#####.#.#####
-#.#..#.##.....#
-.##..##....####
-...##...##..#..
-.#....#.#.#..##
-...##....#####.
-.#.##.#.###.###
-
-###.#...#.#.##.#.
-#.#...####...#..#
-#.#...####...#..#
-###.#...#.#.##.#.
-..####..#####....
-.....###...#..###
-.....###...#..###
-..####..#####....
-###.#...#.#.##.##
-
-##.#..##.##.#
-.#...........
-.#####...##..
-##.#...#.##.#
-...#.###.##.#
-.##..#..#..#.
-#.##..#......
-#..###..#..#.
-#.#.##.......
-...#.###.##.#
-.#.###.#....#
-##..#....##..
-#..##########
-##.##########
-##..#....##..
-.#.###.#....#
-...#.###.##.#
-
-##.######.##.
-...#....#..##
-####....#####
-...#.##.#....
-###..##..###.
-#..######..##
-..#.#..#.#..#
-#.##.##.##.##
-...##..##....
-##...##...###
-##...##...###
-...##..##....
-#.##.##.##.##
-..#.#..#.#..#
-#..######..##
-###..##..###.
-...#.##.#....
-
-####..#.#####
-#..#...##.##.
-.##.##.######
-#..#...#.....
-#..#.##..####
-###.#..#.....
-#..###..#....
-#..#......##.
-####..##..##.
-
-###......
-..#.##..#
-...######
-...###...
-###.#####
-#..##....
-#..##....
-###.#####
-...###...
-
-..##..#########
-####.....#.#.#.
-###.#..####.###
-.#..##..####.#.
-#.#.##.##......
-....####..#..##
-###.##..##.....
-.#..##....###.#
-.#..##....###.#
-###.##..##.....
-....####..#..##
-#.#.##.##......
-.#..##..####.#.
-###.#..####.###
-####....##.#.#.
-..##..#########
-..##..#########
-
-......#.##.#.
-#....#......#
-##..###....##
-.#..#..#..#..
-#....########
-.#..#...##...
-.#..#.##..##.
-##..#########
-..##........#
-######......#
-.####...##...
-.#..#...##...
-#....#......#
-..##..#....#.
-##..###....##
-
-.###.###.
-#.#..####
-###.##.#.
-###.##.##
-#.#..####
-.###.###.
-.###.###.
-#.#..####
-###.##.##
-###.##.#.
-#.#..####
-.###.###.
-#...#.#.#
-
-.#....#..
-.##...#..
-.###.##..
-#.##.#...
-.####..##
-#.#.#.###
-..#...#..
-##...#...
-##..####.
-##..####.
-##...#...
-..#...#..
-#.#.#.###
-.####..##
-#.##.#...
-.###.##..
-.##...#..
-
-#..##....##..
-.##.#.##....#
-#..#.#..#..#.
-#####....##..
-....##.##...#
-#..#...######
-#..####.#..#.
-.##.###..##..
-....###......
-.##..#..####.
-#..###.#.##.#
-.......######
-.....####..##
-
-####.#..##.##
-.##.########.
-.##.#.#..##.#
-....#.#.#.###
-.##..#.##.###
-#..##..###.##
-#####.###....
-######.#.#..#
-.##....###...
-.........##.#
-#####.##.#...
-#..###.##.##.
-....###.#..##
-######.#..#.#
-#..#.#.#..###
-#..#.#.#..#.#
-######.#..#.#
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
 
-.#.#.####
-.###.#..#
-#..#.....
-..#...##.
-..#.##..#
-.#.#.....
-...#.####
-###......
-...###..#
-...###..#
-.##......
-
-#....####..
-.##.##..###
-.....####..
-.#..#...#.#
-..#...#..#.
-####.#.##..
-####.#.##..
-..#...#..#.
-.#..#...#.#
-.....####..
-.##.##..###
-#....####..
-.....####..
-
-.#..#..#.
-#.#.####.
-.#.######
-#..######
-####.##.#
-####.##.#
-....#..#.
-..##....#
-###......
-###......
-..##....#
-....#..#.
-.###.##.#
-
-.#.##...#..##
-#.#.#.#...#.#
-.##.....##.#.
-.##..##..####
-...#.#####.##
-#.#####..#...
-#.#####..#...
-.#.#.#####.##
-.##..##..####
-.##.....##.#.
-#.#.#.#...#.#
-.#.##...#..##
-.#.##...#..##
-
-####...##.#..##..
-#######........##
-..#..##.#....##.#
-##....##..###.#.#
-.####..###...###.
-..#.###.#..#.#..#
-..#.###.#..#.#..#
-.####..###...###.
-##....##..###.#.#
-..#..##.#....##.#
-#######........##
-####...##.#..##..
-#..#...#.##.##.#.
-.###.#..#.###.#.#
-.###.##.#.###.#.#
-#..#...#.##.##.#.
-####...##.#..##..
-
-#...####.#.....
-#..#.#.#.....#.
-####.#.#....##.
-#..#...#.#.####
-.##......#.##..
-.##...#####....
-.##.#..#.......
-.##.#..#.......
-.##...#####....
-.##......#.##..
-#..#...#.#.####
-
-.###.#.....
-...##..#.#.
-##....##...
-..#..#.#...
-##.##.##..#
-##.##.##..#
-..#..#.#...
-##....##...
-...##..#.#.
-.###.#.....
-..#.#.###.#
-##.##..#.##
-##.##..#.#.
-
-.#....#.#.#.#....
-#.####.#...####..
-#..##..##.##..###
-.#.##.#..#####.##
-#..##..##.#....##
-#.#..#.#.#.#.#...
-.######....#.#...
-#.####.#....#.###
-...##...###..##..
-..#..#..#.##..#..
-#..##..#.#.##.#..
-.#....#.#..##..##
-##..#.###.####.##
-
-.##..##.###
-.######.#..
-###..###...
-#.#..#.###.
-##....##...
-##....###..
-#..##..#.##
-#########.#
-.#.##.#..#.
-..#..#...##
-.#....#.##.
-.#....#.##.
-..#..#....#
-.#.##.#..#.
-#########.#
-#..##..#.##
-##....###..
-
-#...#.#..#.
-####.#..#.#
-##.#..####.
-#.###.#..#.
-......####.
-.###.######
-#.#.##.##.#
-...###....#
-.....#....#
-##..#..##..
-##.#.######
-##.#.######
-##..#..##..
-.....#....#
-...###....#
-#.#.##.##.#
-.###.######
-
-#.#####...#......
-#.#####...#......
-.##...###..#.#...
-.#.####.####...#.
-....##..#.##...##
-###...#..#.##...#
-....##.#.##.#.##.
-##.#.#....#####.#
-..##.#.#..###.#.#
-##.###.####.#...#
-#..###.####.#...#
-..##.#.#..###.#.#
-##.#.#....#####.#
-....##.#.##.#.##.
-###...#..#.##...#
-
-##..###
-.####..
-#....##
-..##...
-#.##...
-##..###
-#.##.##
-
-.#.#..#....##...#
-.###..#....##...#
-.#####.##..#.#..#
-#.##.##.#........
-#.##.##.#........
-.#####.##..#.#..#
-.###..#....##...#
-.#.#..#....##...#
-###..#..###.##.#.
-##.....###.#..###
-####...####..####
-
-##..##.##.#
-#...###..##
-.#...######
-#..##......
-.##########
-###........
-###.#......
-..#.#......
-#.#.#######
-.#..###..##
-#.#.#......
-.######..##
-...#.######
-..#.#..##..
-..#####..##
-#.#########
-##.########
-
-..##..#..
-#.#..##..
-#..#####.
-.####...#
-.####...#
-#..####..
-#.#..##..
-..##..#..
-##..#....
-..#....##
-..#....##
-##..#....
-..##..#..
-
-.#..#....
-#.##.###.
-#....####
-#....##.#
-#.##.#..#
-##..##.#.
-#....#.#.
-#....#..#
-#....##.#
-#....#.#.
-##..##.#.
-#.##.#..#
-#....##.#
-#....####
-#.##.###.
-.#..#....
-##..###..
-
-...####.##..#
-#.....####..#
-...#...#.#..#
-###..#.###..#
-.#.#.##.#.##.
-...#.##.#.##.
-###..#.###..#
-...#...#.#..#
-#.....####..#
-...####.##..#
-.....###.####
-
-#.##...
-.#.#...
-##.#.##
-.#.####
-.#.##..
-.##.#..
-.###.##
-#.#..##
-..###..
-.####..
-.###...
-
-.##..##.########.
-.#.##.#...####...
-.######.#.#..#.#.
-.##..##.##....##.
-.######.#.####.#.
-########..#..#..#
-..#..#..#.#..#.#.
-..#..#..#.#..#.#.
-.#......#.####.#.
-
-#...###..##..##
-.###..#####..##
-.##.#.##.#.##.#
-..#..###.##..##
-#...#....##..##
-##.#####..####.
-#####...#.#..#.
-###..#..###..##
-###.###.#######
-#...##...#....#
-##.#.#.#.#.##.#
-##.#.#.#.#.##.#
-#...###..#....#
-
-.#.##.#..##.#..
-##..#.##.##..##
-.#.#.#.....#...
-#...###.####.##
-#.#..#.##.##...
-###..#.##.##...
-#...###.####.##
-.#.#.#.....#...
-##..#.##.##..##
-.#.##.#..##.#..
-...#.#...#.##..
-..###.#..####..
-##..####.#.....
-######.##...###
-..#.....#.##.##
-
-......#
-......#
-..#..#.
-####.#.
-..#.##.
-##.##..
-..#.###
-...###.
-##.###.
-...###.
-..#...#
-##...#.
-##...#.
-####.##
-##.#.##
-###..#.
-.#.##.#
-
-..####..#.#...#..
-.#....#.######..#
-.#.##.#.....#....
-.#.##.#.....#....
-.#....#.######..#
-..####..#.#...#..
-..####..####.####
-##....##..#.###..
-.......##......#.
-
-###..##..
-..#.#..#.
-###.####.
-##..####.
-...#....#
-##.#.####
-...######
-...######
-..#.#..#.
-..#######
-##.......
-..#..##..
-..#......
-##...##..
-#####..##
-
-##........##...
-...######......
-..##....##..##.
-###..##..######
-##..#..#..#####
-..###..###..###
-.....##.....#.#
-..#.####.#...#.
-###..##..####..
-...######.....#
-..#..##..#..#..
-#...####...#..#
-##.#....#.##...
-###......###.#.
-..###..###..#..
-###.#..#.####..
-..#..##..#..#.#
-
-#...##.#.
-#...#..#.
-...#.#...
-.######..
-.....#.#.
-.#...####
-..#..#...
-..#..#...
-.#...####
-.....#.#.
-.######..
-...#.#...
-#...#..#.
-#...##.#.
-#.#..#.##
-#.#.##..#
-.#.###.##
-
-.######....#.#.
-.........#...##
-.#....#.#.#....
-..#..#....#.###
-#.####.##..#..#
-...##...#..####
-...##...#..####
-#.####.##..#..#
-..#..#....#.###
-.#....#.#.#....
-.........#..###
-
-....###.#..
-#...#...###
-#...#...###
-....###.#..
-.......#.#.
-#.##.##.###
-##..#..#...
-#####..#.##
-##..#......
-##...#.#.##
-#.#..##.#..
-
-..####...##.#
-..####....###
-##.##.##..###
-...##...##.#.
-.##..##.#####
-##.##.#####..
-..####...#.#.
-........#...#
-###..###..#.#
-...##....#...
-##....##..###
-..####..##.##
-##.##.###.##.
-
-##...####...#
-...#......##.
-#####.##.####
-##.#.#..#.#.#
-###..#..#..##
-....#....#...
-...##.##.##..
-
-.##.##...
-.#...#...
-###.#.###
-#......##
-.##.#####
-###.#..##
-.#....#..
-##...#...
-#.##.#.##
-#.##.####
-##...#...
-
-####.#..#
-...##.###
-##.....#.
-..#..###.
-..#..###.
-##.....#.
-..###.###
-####.#..#
-....#.#.#
-.##.#..#.
-..#..##..
-#.##.#.#.
-#.##.#.#.
-..#..##..
-.##.#..#.
-
-...#.#.
-###.#..
-......#
-#####..
-##..###
-##.....
-##.#...
-##.#...
-##.....
-##..###
-#####..
-...#..#
-###.#..
-
-..#.#.#
-####.##
-######.
-...#.#.
-...#...
-..##..#
-...###.
-###.##.
-#####..
-#####..
-######.
-...###.
-..##..#
-...#...
-...#.#.
-######.
-####.##
-
-.#..##.#.####
-###.##....##.
-..#####......
-####.#.......
-.##..###..##.
-....#...#####
-####..#.#####
-#.##.##.#....
-#######......
-##.#.....####
-#.##.###..##.
-#.##.###..##.
-##.#.....####
-#######......
-#.##.#..#....
-
-....#.#...##.....
-#..#..###...###.#
-#..######..##.##.
-#..#.#.#...#.#..#
-.......#.##......
-.##.##...####....
-.....#....####..#
-
-..####...
-...##....
-...##....
-..####...
-#.#..#.##
-.#.##.#.#
-..####...
-########.
-##...####
-#..##..##
-##....###
-.######..
-...##....
-
-#..##.#..#..#.#
-#..##.#..#..#.#
-#.###...##.###.
-....#.####.#.##
-.##..#...#.#..#
-###.#####..#.##
-#.###...###.#.#
-#..#.##....#.##
-#..#.##....#.##
-#.###...###.#.#
-###.#####..#.##
-.##..#...#.#..#
-....#.####.#.##
-#.###...##.#.#.
-#..##.#..#..#.#
-
-.##.#..#.
-####....#
-#####..##
-.##.####.
-.##.####.
-..#.#..#.
-####....#
-#..#.##.#
-....#..#.
-####.##.#
-.##.#..#.
-####.##.#
-.##......
-
-....#..##..##
-....#..##..##
-..###.....##.
-####.##..#.#.
-#..#.#.#....#
-.##.#.#..#.##
-.#..##..#..##
-#.#.....#.#..
-###.....#.#..
-.#..##..#..##
-.##.#.#..#.##
-#..#.#.#....#
-####.##..#.#.
-..###.....##.
-....#..##..##
-
-##.#.######.#
-....##.##.##.
-#####.#..#.##
-##.##..##..##
-...#.##..##.#
-..##..####..#
-..#....##....
-##.###.##.#.#
-####.#....#.#
-
-.###.....###.##
-####.#.##..#.#.
-###..#.##..#.#.
-.###.....###.##
-#.#######.#..#.
-#..#...#.#.#...
-#...#.#####..#.
-#...#.#####..#.
-#..#...#.#.#...
-#.#######.#..#.
-.###.....###.##
-###..#.##..#.#.
-####.#.##..#.#.
-.###.....###.##
-.#....###.#.#..
-
-..##.#....#
-.######.##.
-.######.##.
-..##.#....#
-.#.##.#....
-###.##.####
-##..#..###.
-...#..#.#.#
-###...#.#.#
-###...#.#.#
-...#..#.#.#
-##..#..###.
-.##.##.####
-.#.##.#....
-..##.#....#
-
-#..#......###..
-#..#......###..
-#.####........#
-#..##.....##..#
-#..#.#######..#
-.##..#...#...##
-.....####..#...
-#..##..##.#.#..
-.##.###.#..#.##
-
-###.###..
-###.###..
-..#.#.#.#
-####.....
-..#.....#
-#....##..
-###.##.##
-#####..#.
-#####..#.
-###.#..##
-#....##..
-
-.###.#.##.#.###.#
-##..#..##..#..###
-#..##.#..#.##..##
-##..#.#..#.#..##.
-...###....###...#
-.#...##..##...#..
-..........#......
-#....######....#.
-..####.##.####...
-.###.#.##.#.###.#
-######.##.#######
-##....#..#....###
-##.#.#....#.#.##.
-##.#.#....#.#.##.
-##....#..#....###
-######.##.#######
-.###.#.##.#.###.#
-
-.#.#..###..##
-#.##.#####...
-####.#..#.#..
-.##...#.#####
-#..#..###....
-#..###.......
-###..#.#.##..
-..###.##.####
-..#.#.##.####
-###..#.#.##..
-#..###.......
-#..#..###....
-.##...#.#####
-####.#..#.#..
-#.##.#####...
-.#.#..###..##
-##....#######
-
-#.###...#.#####
-######.#.####.#
-#....##...##.#.
-#....##...##.#.
-######.#.####.#
-#.###...#.#####
-####..#.#..#..#
-.##.###.##.##..
-....########..#
-##.##..###..#.#
-##.##..###..#.#
-....########..#
-.##.###.##.##.#
-####..#.#..#..#
-#.###...#.#####
-
-....##...
-#######..
-.......#.
-....#.#..
-####..#.#
-#####...#
-####.#..#
-.....#.##
-.##.#.#..
-######.#.
-.....#.##
-.....###.
-#####..#.
-####.###.
-######.##
-
-.#...#.####..
-.#...#.####..
-..########...
-.###..#.#.#..
-#...#....#.##
-.......#...#.
-.#.######.#.#
-###.#.#.####.
-....###..##.#
-...#.#.####..
-...#.#.####..
-....###..##.#
-###.#.#.####.
-.#.######.#.#
-..#....#...#.
-#...#....#.##
-.###..#.#.#..
-
-#.#..######
-#.#.#######
-.##.##....#
-#..........
-###.##.##.#
-.##########
-##.#...##..
-
-....####..###
-##.###.####.#
-##..#........
-##.#.#......#
-..#.#.#.##.#.
-....#.#....#.
-..##.###..###
-###.##..##..#
-...##...##.#.
-..#.....##...
-###.##......#
-
-.##..#.#..#..
-#.....#######
-#.#####.##...
-#######..####
-.####..####..
-.####...###..
-#######..####
-#.#####.##...
-#.....#######
-
-.#.##....##.#....
-.#.##....##.#....
-####.####.#####..
-#.##########.#.#.
-.#.##....##.#..#.
-####......#####.#
-#...######......#
-#.#.######.#.#..#
-#.##.####.##.#.##
-..#........#.....
-#.#...##...#.#.##
-
-####....#
-##.#....#
-.########
-#..######
-#.#......
-#.#######
-##..####.
-
-###........##.##.
-###...###.#..#...
-#####.#.....#####
-..#.##........#.#
-..#.##........#.#
-#####.#.....#####
-###...###.#..##..
-###........##.##.
-######.###...#...
-##..##.##.###.#.#
-#####.#.##..#....
-...#..#..#..#.##.
-...##.####..###..
-##.#....##.#....#
-##.#.##.#####.###
-
-#...#.##.
-.###.#...
-.#####...
-#...#.##.
-.......##
-#.#...###
-#.#...###
-
-..#.##.###.##.#
-.###.#.......##
-#..#.#...#..#.#
-########..####.
-########..####.
-#..#.#...#..#.#
-.###.#.......##
-..#.##.###.##.#
-#...#...##.....
-#.#..#.###.....
-#..##.#.##.####
-..#######.###.#
-..###########.#
-
-.#..##.
-##.##..
-##.##..
-.#..##.
-##.###.
-#.#####
-#..##.#
-####..#
-..##..#
-..##..#
-####...
-
-##..##..##..#
-....######...
-######..#####
-##.##.##.##.#
-...########..
-###.######.##
-###.######.##
-#####....####
-..#........#.
-###........##
-##.########.#
-..#.#....#.#.
-##...####...#
-######..#####
-..#.##..##.##
-
-#..####
-....###
-####.##
-.##.###
-####.#.
-.##.#.#
-.##.#..
-....#.#
-....#.#
-.##.#..
-.##.#.#
-######.
-.##.###
-
-...######
-.#..###.#
-####.##.#
-#.#.....#
-#.#.....#
-####.##.#
-.#..###.#
-...######
-####.####
-.#..##..#
-##..#.##.
-.....###.
-.#..#.#..
-.#..#.#..
-.....##..
-
-..#..#..#.#.....#
-##.##.##.#...#.#.
-##.##.##.#...#.#.
-..#..#..#.#.....#
-.#######.#..##.##
-.........###..##.
-.#....#..#.#..##.
-
-......#..#.
-..##...#.#.
-#######.###
-#....#.#..#
-######....#
-......#..#.
-..##.......
-##..######.
-#.#####..#.
-#....#.#.##
-..##...#.##
-..##......#
-..##......#
-
-...#.#..#
-...######
-..#......
-..#######
-...#..##.
-...#.####
-..#.#.##.
-..##..##.
-..#...##.
-##.##.#..
-##....##.
-..#..#..#
-###..#..#
-
-.##.#..###.#...##
-#..#.##....#.#..#
-.........##.#.###
-.##.....###...###
-.##.##.#...###.#.
-.##.##.#...###.#.
-.##.....###...###
-.........##.#.###
-#..#.##.#..#.#..#
-
-#.###.##.##
-#..#......#
-#.#.##..##.
-.###.#..#.#
-.###.#..#.#
-#...##..##.
-#..#......#
-#.###.##.##
-####......#
-
-...##.##.#.#.#.
-...##.##.#.#.#.
-.##...#..##.#.#
-.###.#.##..#.#.
-....###..#.####
-....###..#.####
-.###.#.##..#.#.
-.##...#..##.#.#
-#..##.##.#.#.#.
-
-#..##......##
-#.#....##....
-##.##.####.##
-.#...##..##..
-.#.####..####
-.##...#..#...
-.####.####.##
-.#.##..##..##
-#..##..##..##
-#.#..#.##.#..
-#..#.##..##.#
-#.###.####.##
-##.####..####
-#.#....##....
-..#..........
-
-.#.##.#.#..##.#
-#..##..##......
-##....###......
-.#....#.##..##.
-#.#..#.#..#.##.
-#......#.#.#..#
-...##...#.#....
-...##...#.#.##.
-#########.#.##.
-##....###......
-#.####.#..#.##.
-
-##..........##..#
-..####..####..##.
-..####..####.....
-..##......##..##.
-#..#..##..#..####
-#.#........#.####
-#####.###########
-
-###########..
-##.##.##...#.
-#.#..#.##.#.#
-###..######..
-#..##..##....
-#..##..##....
-###..######..
-#.#..#.##.#.#
-##.##.##...#.
-###########..
-.#...##.#.#.#
-
-..#.#.##..##.
-....##.#.#.#.
-#####......#.
-#...#.#####..
-##..##.....##
-.#.##..###.#.
-..###.##..#.#
-..###.##..#.#
-.#.##..###.#.
-##...#.....##
-#...#.#####..
-#####......#.
-....##.#.#.#.
-..#.#.##..##.
-..#.#.##..##.
+    #[test]
+    fn part1_example() {
+        assert_eq!(405, part1(&Day13::parse(&example(13)).unwrap()));
+    }
 
-..#.#.#..#...
-..#.#.#..#...
-....#...####.
-..##.#.#.#..#
-.#.#....#....
-#####..#.#.##
-.####.##.....
-..######.#...
-..##.#.#..###
-..##...#..###
-..######.#...
-.####.##.....
-#####..#.#.##
-.#.#....#....
-..##.#.#.#..#
-....#...####.
-..#.#.#..#...
+    #[test]
+    fn part1_input() {
+        assert_eq!(27_202, part1(&Day13::parse(&input(13)).unwrap()));
+    }
 
-.##.#.#.#..
-.##.#.#.#..
-#.####.#.#.
-#.##.....##
-...#.##.#..
-.##.##.#..#
-...##.##.#.
-#.....#.#.#
-#.....###.#
-...##.##.#.
-.##.##.#..#
+    #[test]
+    fn part2_example() {
+        assert_eq!(400, part2(&Day13::parse(&example(13)).unwrap()));
+    }
 
-.###..###
-.##....##
-#.#....#.
-.########
-##.#..#.#
-##......#
-##..###.#
-#.#.##.#.
-.#......#
-..#....#.
-####..###
-...#..#..
-...#..#..
-####..###
-..#....#."
-        .to_string()
+    #[test]
+    fn part2_input() {
+        assert_eq!(41_566, part2(&Day13::parse(&input(13)).unwrap()));
+    }
 }
@@ -1,5 +1,19 @@
 use std::collections::HashMap;
 
+use log::debug;
+
+use crate::{
+    animation::{Recorder, Recording},
+    geometry::Direction,
+    grid::Grid,
+    parsing::ParseError,
+    render::{Picture, Rgb},
+    solution::{Answer, Solution},
+};
+
+/// The number of spin cycles shown by `aoc run --animate`.
+const ANIMATED_CYCLES: usize = 3;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 enum ObjectType {
     RoundedRock,
@@ -7,34 +21,22 @@ enum ObjectType {
     EmptySpace,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-struct Platform {
-    objects: Vec<Vec<ObjectType>>,
+pub struct Platform {
+    objects: Grid<ObjectType>,
 }
 
 impl Platform {
-    fn swap(&mut self, from: (usize, usize), to: (usize, usize)) {
-        let obj = self.objects[to.1][to.0];
-        self.objects[to.1][to.0] = self.objects[from.1][from.0];
-        self.objects[from.1][from.0] = obj;
-    }
-
     fn get_total_load(&self) -> usize {
-        let len = self.objects.len();
+        let len = self.objects.height();
 
         let mut total_load: usize = 0;
 
         for i in 0..len {
             let relative_load = i + 1;
-            total_load += self.objects[len - i - 1]
+            total_load += self
+                .objects
+                .row(len - i - 1)
                 .iter()
                 .filter(|obj| **obj == ObjectType::RoundedRock)
                 .count()
@@ -43,35 +45,66 @@ impl Platform {
 
         total_load
     }
+
+    fn picture(&self) -> Picture {
+        Picture::of_grid(&self.objects, |object| match object {
+            ObjectType::RoundedRock => Rgb::WHITE,
+            ObjectType::CubeShapedRock => Rgb::GREY,
+            ObjectType::EmptySpace => Rgb::BLACK,
+        })
+    }
 }
 
-fn main() {
-    assert_eq!(136, part1(&test_input()));
-    assert_eq!(107430, part1(&input()));
-    assert_eq!(64, part2(&test_input()));
-    assert_eq!(0, part2(&input()));
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(platform: &Self::Input<'_>) -> Answer {
+        part1(platform).into()
+    }
+
+    fn part2(platform: &Self::Input<'_>) -> Answer {
+        part2(platform).into()
+    }
+
+    fn animate(platform: &Self::Input<'_>, recording: &mut Recording) -> bool {
+        let mut platform = platform.clone();
+        recording.record(|| platform.picture());
+
+        for _ in 0..ANIMATED_CYCLES {
+            platform = spin_cycle(&platform, recording);
+        }
+
+        true
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let tilted_platform = tilt_platform(&parse(input), Direction::North);
+fn part1(platform: &Platform) -> usize {
+    let tilted_platform = tilt_platform(platform, Direction::Up, &mut ());
 
     tilted_platform.get_total_load()
 }
 
-fn part2(input: &str) -> usize {
-    let mut cycled_platform = parse(input);
+fn part2(platform: &Platform) -> usize {
+    let mut cycled_platform = platform.clone();
     let mut seen_states: HashMap<Platform, usize> = HashMap::new();
 
     for cycle in 0..1_000_000_000 {
-        cycled_platform = tilt_platform(&cycled_platform, Direction::North);
-        cycled_platform = tilt_platform(&cycled_platform, Direction::West);
-        cycled_platform = tilt_platform(&cycled_platform, Direction::South);
-        cycled_platform = tilt_platform(&cycled_platform, Direction::East);
+        cycled_platform = spin_cycle(&cycled_platform, &mut ());
 
         if seen_states.contains_key(&cycled_platform) {
-            println!("We've seen this exact state before, meaning we found a loop! We can now easily extrapolate to determine what a future state will look like.");
-
             let loop_len = cycle - seen_states.get(&cycled_platform).unwrap();
+
+            debug!(
+                "Found a loop of {loop_len} spin cycles at cycle {cycle}, extrapolating from there"
+            );
             let offset = (1_000_000_000 - cycle - 1) % loop_len;
             let index = seen_states.get(&cycled_platform).unwrap() + offset;
 
@@ -89,190 +122,91 @@ fn part2(input: &str) -> usize {
     cycled_platform.get_total_load()
 }
 
-fn tilt_platform(original: &Platform, direction: Direction) -> Platform {
-    let mut tilted_platform = original.clone();
+/// A spin cycle tilts the platform north, west, south and then east.
+fn spin_cycle(platform: &Platform, recorder: &mut impl Recorder) -> Platform {
+    [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ]
+    .into_iter()
+    .fold(platform.clone(), |platform, direction| {
+        tilt_platform(&platform, direction, recorder)
+    })
+}
 
-    let width = tilted_platform
-        .objects
-        .first()
-        .expect("There should be at least a single row of platform")
-        .len();
-    let height = tilted_platform.objects.len();
+/// Tilts the platform, moving every rounded rock a single position at a time. Every round of
+/// moves is a step of the recording.
+fn tilt_platform(
+    original: &Platform,
+    direction: Direction,
+    recorder: &mut impl Recorder,
+) -> Platform {
+    let mut tilted_platform = original.clone();
 
     loop {
         let mut did_any_rocks_move = false;
 
-        for y in 0..height {
-            for x in 0..width {
-                if tilted_platform.objects[y][x] != ObjectType::RoundedRock {
-                    continue;
-                }
-
-                let (dest_x, dest_y): (i32, i32) = match direction {
-                    Direction::North => (x as i32, y as i32 - 1),
-                    Direction::East => (x as i32 + 1, y as i32),
-                    Direction::South => (x as i32, y as i32 + 1),
-                    Direction::West => (x as i32 - 1, y as i32),
-                };
-
-                if dest_x < 0
-                    || dest_x as usize > width - 1
-                    || dest_y < 0
-                    || dest_y as usize > height - 1
-                {
-                    continue;
-                }
-
-                let (dest_x, dest_y): (usize, usize) = (dest_x as usize, dest_y as usize);
-
-                if tilted_platform.objects[dest_y][dest_x] == ObjectType::EmptySpace {
-                    tilted_platform.swap((x, y), (dest_x, dest_y));
-                    did_any_rocks_move = true;
-                }
+        for position in tilted_platform.objects.positions() {
+            if tilted_platform.objects[position] != ObjectType::RoundedRock {
+                continue;
+            }
+
+            let Some(destination) = tilted_platform.objects.step(position, direction) else {
+                continue;
+            };
+
+            if tilted_platform.objects[destination] == ObjectType::EmptySpace {
+                tilted_platform.objects.swap(position, destination);
+                did_any_rocks_move = true;
             }
         }
 
         if !did_any_rocks_move {
             break;
         }
+
+        recorder.record(|| tilted_platform.picture());
     }
 
     tilted_platform
 }
 
-fn parse(input: &str) -> Platform {
-    let objects = input
-        .split("\n")
-        .map(|line| {
-            line.chars()
-                .map(|ch| match ch {
-                    'O' => ObjectType::RoundedRock,
-                    '#' => ObjectType::CubeShapedRock,
-                    '.' => ObjectType::EmptySpace,
-                    other => panic!("'{}' isn't parsable to an object type", other),
-                })
-                .collect::<Vec<ObjectType>>()
-        })
-        .collect::<Vec<Vec<ObjectType>>>();
+fn parse(input: &str) -> Result<Platform, ParseError> {
+    let objects = Grid::parse(input, |ch| match ch {
+        'O' => Some(ObjectType::RoundedRock),
+        '#' => Some(ObjectType::CubeShapedRock),
+        '.' => Some(ObjectType::EmptySpace),
+        _ => None,
+    })?;
 
-    Platform { objects }
+    Ok(Platform { objects })
 }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#...."
-        .to_string()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input, unknown};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(136, part1(&Day14::parse(&example(14)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> String {
-    ".OO.#.....#..O.O.O#....O....#....O....#..##.#...#..O....#.....O...##O...O#.O.#O.O....O......O.....OO
-.......#........O...O..#.....O....O.#..#O#.#.##O.#O.#..O.O....O.###O.........#.O.O......O....#O.....
-O..O.OOO....#O..#.#......OOO.#O#O.OO..#.O#.O.....O....#O.O####...##O..O....O#..O#...#..#......O..#..
-.#.#.##...#.O.#...O##..O....#........#..#.#.O...O.......#.OO...#........#...O#.#.......O..OO.OO.OO##
-....O.O.O#.....O#.OO..O...........O.OO..#.........O...O........#.OOOO..##.#....#.#..O..O#..O#.#O..OO
-#.#...O##.##...#.#O.OO..O.##O.#.##O...#O#....#OOOO.O........#......O.....O#....O....O..O#.OO#.O.....
-......O..O.O...O...#..OOO##...O.OO....#......OO.#.....OOO##..##.#.#..#OOO.......#O.#....O....O...#.#
-O..........OOO#.O.O.#.....OO...O.#..#...OO....OO#O.#OOO..OO..#.OO.....#O##.O..#.OOO...#.#O#...#.OO..
-....O..#O...........#.O..OO##....#..................O....O.O.O.#.O.......O.O.#.....O..#O..#..#......
-....##OO.#.....#OO...O......O#.....O.OO#....O..O.###.##.OO.#O....#.OO#O#.O......##O#..O...O#.#.O..O.
-#....OO.##...#.....O.O....#O.O#...OO#..#.O.O.#..O......###.O..O......#..........O...#.O.#O#OO..O.O.#
-........#....O..O###.#.#...OO##.#.....#O.O..........#...O..O...OO##....O#..O.#O......#..O..O...#..O#
-..O.#..O......#......O..#......#...O.#O...O...O.#..#.OO.O.O...O...O.O.#....#O...O#..##..O..O......O.
-...OO.............O....#O.O..O...OOO....O..#...O....#..#.O#.#..OO..#......O.....OOO....O..##..O#....
-O.#O#.#O#.#..#.O.#....#..O...#..O#O..#.#.O..O...OO.....O...O....#.O.#OO.OOO#.O.....O..O#.O..#.OO##..
-.....#..#.O.O##O.#O..O..OO.#O..O....O..O.....O..#.##..O.............#......O...###...O.....O....#...
-O...O..O#O..#O.#O.O#.O...#O#.......O.......#...O.#.#.O#.......O.#.OO.O.#O..O..........O#...O.OO..#..
-..O.O#.O.....O.OO..O#O...#.#....#..O.OO.....O.........O.O#...#....##......OO............O.O......#..
-...O#OO.....OOO.........#OOO.O.......O.....O....O....#OO##.O##...#.................O....#O...#......
-#.O#OO.#..#.......O.O.###O....O..O.....#.O.OOO.#.O#.#..O....#..#...#.O#.O#..##..#...##OO..O#...#...#
-O...#.......O##.OO...#O..#......O.......O..#.....#..#.#..OO....#.#.#O..OO..#.OO.#..##OO#.O#OO..#...#
-...O...#...O.#O....#.......O..OO.O..O.......O.#....#O..#O#.#...#....#.#....#....O.O......O.O........
-#..O...OOOO.......#.....O..O...##O...#OO...OO..OO#O.O..#.....#O...#..#.....O.#O.#...#....#...O#....#
-.O.#O.....#...#...O.OOO#...O#..##O#OO##.#.#...O#.....#O..O#..#OO....#O...O.#..O..#.....#.#.OO...O.#.
-..#..OO......#..O..#.O.O..O.#...#.#....#.......O.#O#....O.#.O#.O.O.O..O.###O.OOO...#O....O#..O.#...#
-....O.#.....#.....OO.......O#O.O..OO#..O.O...OOOO....OO..O.#..O.....OOO.O#O.O......#..#O.O....O.#..O
-..#......#O##....O.O.OO..#.#.....O.#.#O#.....OO#......###..#.O.O#....#O...O...O...OO...OO..O.#...O.O
-#...#.....O.#..##.......#.OO......#O..O.........O#.#O#.#..#O.O......O.O..O.OO#...O.#..O...O..O.O....
-O.#..##...O.O..O....O.O.OO......O...O.O....#..#.O.....OO...O.OO.O....O....O.O.O........O..O#...OO.O.
-....O..O.O...OO#O..O..........OO#...O..O.O#...#O......#.#.#...#..O.....O..O.#O#.....O.#O.O..O#.....O
-#O...O...O..O.#.O#OO.......O#.....O.#..O.OO.#.........O..OO.....OO.OO#OO...O.#.O....#...#..#.##.O...
-##O...#.##...OO.......##..#.#.#..OO.....OO#.....OOO.OO.......O.#.O...#..O.OO.#.....##.#.#........#.#
-...OO..#....#..O...O........OO.O...##.......O...#O#..O...#....#O....#....#O..##.#O.#..O.....##OO#.O.
-.O.#.O#O.#...#..O.##.#....OO..........O.#.O.O#O...#......O.O....O#.O.#.....O...##...O.#.O.O.#O##.O..
-...#.....O....#.OO.#.#..#..#..###.....#.......#.O...O#...O..#.O#O##O.O....#.O.#....O............O...
-#.##..##....#...O.....#.O.....OO....OOO......#...O.#....OO.#....O...##..#OO.#....O..O.O#O.O..#...O#.
-O##....OO...#.#..O...#......#.##..O......#........O.O.O.#O..#.#..O..#.....#O.O.....##OO.O..O..#.....
-.....O#..O.#.#..#O#.O.......OO.O.....#.....##...O..#...OO.O.OO....OOO........O#OO...O..#.OO##OO#.O#.
-...#.O#O......O....O#................#O.....#..O.OO#.......#.#.O##......OO.#..#..O#...OO..#.#.O#O.#.
-O......#.#....O.O...O.#.O......#.#.O..OOO.O..........#..O....#..O.O.#.O.##OO..#O#.O....#O..###...#..
-..##..#...#..#.#O.#.#..#O#.#.....O.#..#OO....OOO#O...O##O..#.##.O..O..O#O..........O.#..#....O#.....
-O.....#OO#...OO#O..O......#O.O..........O####.#.....#..#.....#...#..O#...#...#O#...#..O..#.O#......O
-....O###.OOO...O.O...O#...O##....OO..O..O#..O.#.OO.O#.#...#O.#......##.O.......O...#.O.O.#.......O.#
-O..O.....##....#OO#.OO....##.O#.O..#.#.......O......#....#O#.O.......OO..O.#..#.O..#..........OO..OO
-.......O.O......O.....#O..#.....#....#O#...#...O..#...O#....#O.O.....O...#OO...#......#...##...OOO..
-#..O.....#O.O..O.O...#.O...O.O.#.#OO.....OOO....O.........OO..O.OO#...O#..O...#.#..#.##.#..OO...#...
-...O..OO.#O...OOOOOO..O#....#.OO...O#...O.....#.#.O.....O..O.O#O.#.#.O..#....O..#.#...OO#.......#O..
-..............O.OO#.........##..............O..#........##....O...#.#.##O#.O....O#.OO....O.#O.OO.O.O
-....O....#..O..O....O##...OO...O....O#..#O#.....OO...#.OOO#....#.O..O..O...#.....#..OO#.#O....O.O#..
-#O#...O..O#.OO..OO....#.......O#.........##.....OOO......O..#....O.#.#.O.O..O.O..O...#.#..O.#......O
-O....O...#..##...OO.....#.#.#.........OO..#..#...O...O..#..#.OOOO....#O..O##.O.O#....#....O.##.O..O.
-....O..#.OO.##...O.OOO.O.O#.....#..O..O....#.#O...#O#O.....O..##...##.O.#O...#.....OO.#..#O#.O.#.O..
-O..O#.....O..OO........OO...##....O#OO#.O..OO.....O.....O...O.##..#..#O..O.#.OO....#.........O...#.O
-#...O.O..O..O........#OOOO......#..O...#..O.....OO#.....O.......O#...O..O.....##O.....OOO#...#..##O.
-#.##...##O.#....#.OO#.OO.#..OO#...#...O..O.O#..##..O.....O.OO.#OO#....#.#.O.#...OO..#.##O...#.....#O
-..#.O.....O##.....O.O#O.#O#..#...O...O#.......#O..OO.O..O..O.##O...O...........#..#....O............
-..O.#O....O...#O....#....O...OO.OO...#O.O..O.....O.........##.....#..#O.O.OO..O..#....#.............
-..#...O.OO.......#....OO.O...#.O...#O.O#......O..#..............#O......#.O#..#...O.......#......O..
-..O..O....O.....O#O....OO.....O.O##O...O....#..OO...O.OO....O.O...O.O.......#..#O.O..O.........#.#..
-...O..O#.......#..#...OOOO...O.###..#O.OO..O#.O.O.OOO.#.O..O.#......#OO....O....#..#O..O......O....O
-....##OO###.......OO.##O.....##.#.OOOO.#OO#...#.#.O..#.....O.#.##O..#OOO.#OO.OO#OO.......O.#...#O...
-....O.O.OO.O.#O...#.O.O.....OO#.OO....O.......O.O.O...##OO....O....#.#..#O###...OO..O...#.#.O.O.O.#.
-....O....O....#...O..O#.OO..O.OOO...#.......###O#....##O.O#..O...O.#.O.O.......O.###..O#.#.O.O.....#
-..#..O...O..O.......O..#.#O.O..O...O.........#.O......#....O..O..O#.O..O..#.O.......#...#...O#.OO..#
-O....OO#O..#....O.#OOO..O.....O...O.O....O.O#O..#........OOO..#O..O.O.....O#O...#.#.....#.O.O......#
-##OO.O#..#O#.....O.........O..O.#.......OO.....O...#........###.OO...O..#..OO.O#.O#O....#..##....O.O
-.#OOO........O#...O.#..O.O.O..#.....#..OOO...#.....O........#.........O.O.....#.#.O.O#O.#.#...#...#.
-.O.....#.#....O.O...#O..O###...O..#...#O#.O.O...........#.O....#O.OO......O....O..OO....#....#..##O#
-....#..O#...#....#.O..O.#......OOO.........O....#....O...O#......#.#...OO..O..#.#O.#......#.O.#.#...
-O.O.......O.O#OOO#.O......O#.O......OO.O..O.......##O....O...#.O...O..#....###.####...OO...OO..O..OO
-#..#.....O.....O..O.#....#O....O.OO..#...O.O.....O.O..#O...O#....O##...O.....#OO#..OO#..#.O..O.###..
-O##..#...O..#...........OO...#..#....#...#.O.....OOO...O.OO...O.#.#..O...OO.....OO#O...#O...#..#...O
-....O....#.....O.#.#.OO..#.........#..........O#..O#O.#....###..O.....#.......O..#.#.....OOOO.......
-O.O.O.#........#.#.O.#..##O.OO..O...#O#OO.....O...O.#.#.O#O......#O.....O...O..O##.##.#......O..#...
-....O.......O..#.OO###..#.O#....#O.OO#..OO...#.#...###...#OO#..#...........#OO#..O.#..#....O#....O.O
-....#O..#...#O..O..O...#....O....#....OOO.OO.O..#...OO.O#.....OO.O..#..#...O.#.#.O..#.#O.O#O.O......
-.OO....#.OO.............#..###.#...O...#O...OO#...OO#O..#...##.#..........O..#...#.....O.....O...O..
-#.O....#O....O#..#O......#####O....O..O....O#.......#..O.#..#....O...##..#.........O..O.....#.O#.O..
-...O.O.....#....O.#O....#....O..........###....O.#.O.O#O.#.#.O..#.O.O...#.###...OOO...##..O.#.O..O#.
-.##...#..O..O.#....O..OO..O.....#..O.##O#O.........OO#...#.......O#....O............#O.##.OO#..O.#..
-.O.........O.#O.......#O..O....#.##.O.O...##....#.##...#O#.#.....O#O.#...#..O.#.O...O..##..O.#.O.O..
-...O.#.O.O#.OO..OO#.O...O.O.O...O.O.###O.O..O.O.O........#.O....#....O.#.#OO.....OO.#.....##..#OO...
-....OO##...#O.#..#.....#.#..##O.O##..O...OO#O...........O..O.#.OO..O...OO.##.O.#..#.##OO...#..O.....
-...#....O....#..#.O....O..#....O...#....#O...O.O..#O#O..O.......#..O......#OO..OO.OO......#.##O...O.
-...O......O.....OO#.O.#..O..O..O.O.....O...........O....#.###...O.#......O....O.##..#....O...#.#.#..
-..O.O.#.O......OO..##.O......O#..#O.....##O.O#....#.#....O.....OO.#.....O.O#.#..O...O.#....O#...O..#
-O...OO#O.......#..#O#........#.#.##.O........#O...#..#O..#.....#....O#O.#......##O....O.OO.O........
-.#..O.....OO..O.#..O.O........O.......#.#.#.....O.OO.....#......O.......O##.O...#....#O....O........
-.O.OO....O.O.##..........O.....O.##.#.......OO....O.O....#........##O..O.O##.O......#.#.#.....OO.O#.
-O.....OOO.O###...OO.##.............#..O.#.....#O.....O..OO..O#....##....OO.....#.O.#OO......OO...O##
-O........###.##..O......O#.....O.O#O......#....O#..O........O..O.#.......OO......O..##..OOO.O..O.O#.
-OO.#O......OO...O..O..#..##O..#...O......#..............O..............O..#......#.#O....O...#...O..
-.O..O#.O#..O.#..O..OO#..#.##O.....O.O.#......##O#..O....O......#.#.O..#.....O#.O..O.#.##.......#....
-....OO.#....O..O...OO..#O..#...###..OO.......O##.##.#OO..OOO...#.O..O......O........##..#...OO...#..
-....#O........#.O......O##...##O......#.O..O.O........##...#.O.......O..#OOOOO..#......O###..O..O.O.
-#.#..O.#.........O#...#O.##..O.....#..O...........##......O..#...O......#.................OO...#O.#.
-..O..#OO...OOO...O.#...#.......#OOOO#...#...O....O..#........OO....O..O..##.OO.O#....OO#O#..#....O..
-.O.O..........OOOO.#.#......O##...............#.O.OO.###.#....O..O.O#O...OO..O#O........#.O.....O#.O
-...#......#.#.....#........#O#.........#O....#O.O..#.###.....O#OOO#O#.#.O..#.#OOO..O.#....#O......#.
-O..O...#O.O.....O..O.#.#......O.#O#...#.....#..OO#.....OO.O#.O.....#....O.#...O...O.OO.....O....O#..".to_string()
+    #[test]
+    fn part1_input() {
+        assert_eq!(107_430, part1(&Day14::parse(&input(14)).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(64, part2(&Day14::parse(&example(14)).unwrap()));
+    }
+
+    #[test]
+    #[ignore = "answer unknown"]
+    fn part2_input() {
+        unknown(part2(&Day14::parse(&input(14)).unwrap()));
+    }
 }
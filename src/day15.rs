@@ -1,3 +1,8 @@
+use crate::{
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 struct Lens<'a> {
     label: &'a str,
     focal_length: usize,
@@ -10,22 +15,40 @@ enum Operation {
     Insert,
 }
 
-struct Instruction<'a> {
+pub struct Instruction<'a> {
+    /// The step as it's written in the initialization sequence.
+    step: &'a str,
     label: &'a str,
     box_nr: usize,
     operation: Operation,
     focal_length: Option<usize>,
 }
 
-fn main() {
-    assert_eq!(1320, part1(&test_input()));
-    assert_eq!(511343, part1(&input()));
-    assert_eq!(145, part2(&test_input()));
-    assert_eq!(294474, part2(&input()));
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Instruction<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        part2(instructions).into()
+    }
 }
 
-fn part1(input: &String) -> usize {
-    input.split(",").map(|string| reindeer_hash(string)).sum()
+fn part1(instructions: &[Instruction]) -> usize {
+    instructions
+        .iter()
+        .map(|instruction| reindeer_hash(instruction.step))
+        .sum()
 }
 
 fn reindeer_hash(string: &str) -> usize {
@@ -40,9 +63,7 @@ fn reindeer_hash(string: &str) -> usize {
     current_value
 }
 
-fn part2(input: &String) -> usize {
-    let instructions = parse(input);
-
+fn part2(instructions: &[Instruction]) -> usize {
     let mut lens_boxes: Vec<LensBox> = (0..256).map(|_| vec![]).collect();
 
     for instruction in instructions {
@@ -77,8 +98,8 @@ fn part2(input: &String) -> usize {
         .map(|(i, lens_box)| {
             let mut total_focusing_power = 0;
 
-            for ii in 0..lens_box.len() {
-                total_focusing_power += (i + 1) * (ii + 1) * lens_box[ii].focal_length;
+            for (ii, lens) in lens_box.iter().enumerate() {
+                total_focusing_power += (i + 1) * (ii + 1) * lens.focal_length;
             }
 
             total_focusing_power
@@ -86,47 +107,53 @@ fn part2(input: &String) -> usize {
         .sum()
 }
 
-fn parse<'a>(input: &'a str) -> Vec<Instruction<'a>> {
-    input
-        .split(",")
-        .map(|raw_instruction| {
-            if raw_instruction.contains("-") {
-                let parts = raw_instruction.split("-").collect::<Vec<_>>();
-                let label = parts.first().unwrap();
-
-                return Instruction {
+fn parse_instructions(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    parsing::tokens(input, ',')
+        .map(|step| {
+            if let Some(label) = step.strip_suffix("-") {
+                return Ok(Instruction {
+                    step,
                     label,
                     box_nr: reindeer_hash(label),
                     operation: Operation::Remove,
                     focal_length: None,
-                };
-            } else {
-                let parts = raw_instruction.split("=").collect::<Vec<_>>();
-
-                let label = parts.first().unwrap();
+                });
+            }
 
-                let focal_length: usize = parts
-                    .last()
-                    .map(|num| num.parse().expect("The focal length should be a number"))
-                    .unwrap();
+            let (label, focal_length) = parsing::split_once(step, "=")?;
 
-                return Instruction {
-                    label,
-                    box_nr: reindeer_hash(label),
-                    operation: Operation::Insert,
-                    focal_length: Some(focal_length),
-                };
-            }
+            Ok(Instruction {
+                step,
+                label,
+                box_nr: reindeer_hash(label),
+                operation: Operation::Insert,
+                focal_length: Some(parsing::number(focal_length)?),
+            })
         })
-        .collect::<Vec<Instruction>>()
+        .collect()
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(1320, part1(&Day15::parse(&example(15)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".to_string()
-}
+    #[test]
+    fn part1_input() {
+        assert_eq!(511_343, part1(&Day15::parse(&input(15)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> String {
-    "dcb-,ds-,mdr=9,ddtp=6,sjhs=5,kgtl-,lms-,br-,vk=6,lms-,xd=4,kfs-,pdrct-,xft-,scr-,mn-,fq=3,hbqx-,ksjdgv-,ng=8,cpsh-,qxs-,qx=6,vjjr=5,fk=4,vpj-,zqgm-,zp=1,xz-,xnp-,csph-,zfgql=8,krsl-,fqdh=2,ng=2,lv-,nrl-,fqdh-,pjhdd-,phn-,lqzvsh=6,fnz=8,bdgknx=9,qjlv-,tgsq-,vqpn-,nrh=4,mr=1,tmqh-,qxs-,hkk=5,qjr=6,mm=3,pdrct-,np-,fsk=4,kzz-,blxnc-,skg-,sbsp=7,qvv=3,tgjr-,xtz=3,hbf-,sqk=3,mqhm=2,dq=1,thgd=1,djpj=7,lcvqz-,rpznm-,dbbd-,fxdkd-,sm-,zns=3,qmt-,cm-,kmsx=7,grq-,jr-,nhknz-,pzhfz=8,sfg-,ltf=1,rdm-,xv-,pz=4,dx=8,vj=4,grq-,cxbn-,dx=7,mzd=2,qs-,gkg-,nsg-,dvq=8,stqt-,pj-,plbc=8,tgsq=2,xftfd-,zp=2,bhj-,vfz=4,kzc-,gf=9,pxk=5,jhg=6,ptzd=9,jjh-,pr=8,gksbz-,dcnr-,dbd=7,rs-,fnz-,rg-,ttx=4,ng-,tg=6,fq=3,nz-,pzl-,lqzvsh=6,vvv-,rf=6,bp-,xntm-,mgk-,kq-,np=9,dhmxrf-,jtv=3,lf=1,rnd=6,jjh-,knzhf=1,jvc=9,jvf=1,krsl-,phn-,hbjl-,gmzd=7,nrh-,lsgj-,rdm-,zmc=4,jvf=8,jf-,jpb=5,sgq=9,scr-,pjq=3,zht=7,lrtd=1,vknrhf-,hpn=7,qhr=3,mx-,mppl=3,mx=1,jt-,ddb-,fq-,zfj=7,xdzl=8,hqq-,mdr-,dcb=7,zmx=8,bpk-,vb-,tdf-,hh=4,phhl=4,lg=2,ztgfc=4,qm=2,nmt=3,nzl-,pdrct=8,glq=1,smn-,xb-,gtd-,dshl=5,hpb=1,gr=8,lch-,qhr-,cjp=4,pcfvv-,kgtl-,rlqrc-,fnn=6,lcvqz-,xtz=9,rnd-,xtf-,sjj-,gtd-,vh-,qz=5,nfrmqc=3,mvf=7,ttr=4,zf=1,xftfd-,hkk-,crg=8,ps-,lfn=6,rdm=3,zh=7,rhsctp=6,vnhnt-,mxsl-,rpj-,dzlgmj-,qxs=5,cx=1,pzhfz-,rjjk=7,rfz=8,jzv=9,lcvqz-,grv-,sgq=4,pskfz-,vk=9,gg=5,ps=7,nfrmqc-,dldzn-,qt=1,zmc=3,jc=9,zdzd=9,btg=4,dcnr=4,jkb-,bdl-,gx=3,kq=3,kcpj-,zrlq=6,xdgq=9,cg=3,qr=6,zp=6,rpj-,dx-,kcvf=7,gr-,qst-,jk-,xz-,ng=3,dr=9,pjq=5,lms-,dsfln=2,gkg-,db=9,rkx-,mqhm=9,gnl-,vhff-,br-,cppk-,gkg=6,frz-,tgsq-,tgzb-,jzv=2,cg-,pztx=1,gnl-,kd-,jcdj-,jtv-,cx-,gr=7,dq-,cn=3,dg-,tcc=1,qcqp=8,pj-,hnrf=8,ml=2,cft=3,mvf-,pskfz-,mhcp-,lfn=8,pcfvv-,mtskcr-,glh-,nlvtt=5,mxcgz-,vjvqc=8,qqvd=8,vh=1,bhj-,nrh-,qg-,ps-,pjq=9,bthp-,gthvfr-,sfg=4,dss=8,hpb-,pv=9,pxtqn-,pz=5,xxg=2,rmf-,dqt-,tcvg-,zsv=1,cvl-,xpt-,jj=9,rfz=2,nc-,nsz=9,sk=1,zln-,hpn-,xntm-,vf-,ztgfc-,sqk=4,hj=1,crg-,kkj-,gt=3,tg-,pxb-,rlqrc-,pngv-,pdh=3,dq-,qg=7,cg=8,cn-,cg=3,jrzs=5,knb-,glh-,phrk=2,sk-,tgzb=3,sgq=6,gkz-,jcd=9,zpgrqr=9,qm=8,fnl=5,sdzm-,gnl-,flj=6,lh=9,dbd=5,mqhm=2,cn=8,fnn=4,kzz-,zn=4,hpb=2,pkkbjt-,zv=5,tdkgk-,jpb-,br-,fx=8,hnx=9,hnnkm=1,dng=1,br-,cppk=2,nlvtt=5,xdzl-,kmsx-,ntq=8,ptzd=8,zht=5,hnd=2,tn=4,phzv=5,qxk-,fq=3,mx=5,blnggt-,kzj-,dqt-,tbt-,thgd=1,cmqpn-,jd-,dbd=1,lrtd=7,qqvd-,tbt-,kfs=5,gpm=8,bh-,xvk=8,rrbhcr-,zvp-,sbh=9,qs=7,hrsf=4,ff-,lf-,fbm-,sm=9,khbsh=2,cft-,kzc-,cxhrzz-,jvf=2,crg=2,pxk=9,nmt-,qffgm=7,qnc-,tgjr=7,fnn-,rhsctp=9,fhf=1,vxjg-,dcr=7,cs=9,fhf-,hnnkm=5,jh=3,bdgknx=2,jvf-,cppk-,grq-,ddb-,jd-,mgv=2,sjhs-,vc-,mqhm-,nbx-,krsl-,fbm-,rgrtpd-,bdgknx-,hh=2,lg-,kkmgj=7,cbdz-,zfj=3,vjvqc-,kkj-,fk-,vctsd=2,lb-,btg-,vc=2,xftfd-,hh-,lms-,pl=8,hp-,qmx=3,vctsd=9,nt=4,pzl-,dcr-,mgv-,rpj=7,vqpn-,gr=4,pl=5,zd=9,szz-,nz=6,ln-,zvz-,kkmgj=7,nmt-,dq=4,sz-,dx-,nzr=4,xkbcj=5,ljqpsl=3,pgff=8,nzr=3,pskfz-,ksjdgv-,fsk-,lv=1,jhg-,pgdt=1,dbd=7,zmp=6,dcnr-,sk=3,rjc-,vjjr=3,qst-,pz-,rc-,fhh-,vc=4,sbk-,xtf-,qcqp-,gtb=5,qz=7,tl=1,mrqs-,cx-,zsz=2,fhm-,br-,dr=3,djpj=3,nfjh-,zrlq-,rtp=5,df=3,kmb-,cs=1,kjh=7,tbt-,pztx=9,lch-,cxhrzz=9,zfgc-,tdf=2,lfn-,zn=8,zmp=6,fbm-,ps=9,tmqh-,gl-,kn-,ttbbm=8,hbf-,pzh-,ddtp-,rkx=5,dbd-,phzv=7,bk=6,ppc-,jcdj-,mdr-,qm-,nzl-,ddx-,jr=6,ffgb=4,nzl-,zj=5,kgtl=6,br-,sjj-,ddb-,kfs=5,plbc=9,sbk-,dzfn=3,jj=6,mzd=8,vk=1,rjcj=5,nfrmqc-,nbx=9,skqr=9,vc=2,kx=5,jp=3,ltf-,gkf=6,rcs-,zqgm-,xkbcj=4,vlkgf-,zln=2,jt-,bhj-,zns-,hpb=6,pjq=4,dldzn=5,hfg=7,kq=2,kgl=9,pztx=2,pdrct=7,knb-,tg=3,cft=3,psdf-,hkk-,vh=7,df-,kd-,lb-,svnm-,xvcgnj-,lg-,pjg-,pjhdd-,flzfs-,xntm=9,kx=6,gthvfr=3,rhsctp=8,bdf=1,lfn-,nzl=6,zpcclb-,hz-,zvz-,lfn=3,dcnr-,qjlv-,ps-,td-,pn=9,nq=6,bmkb-,scr-,phhl-,mgv=5,rzcrg=3,gthvfr-,drx=5,pr=7,kzc-,gkf-,xnp-,cm=2,rrbhcr=2,zht-,gthvfr-,mxcgz-,gt=3,clm-,kfs-,gtd-,pxtqn-,vxp=8,jp=4,bz=2,stqt=1,sjj=1,dzrc=4,fqdh=9,zv-,mtskcr-,hnnkm=7,dbbd-,vlks-,zpgrqr-,kkz=9,dhmxrf=4,vr=5,hpn=2,qs-,rrbhcr=6,btg=8,vfz=2,nd-,ppc-,ng-,pl-,ksjdgv=8,sk=7,dbt=3,vlkgf-,jqg=9,dtg-,bhj-,qcqp-,tpr=6,zqgm-,sk-,hnrf=9,pxb=9,tgsq-,qmt=4,kn-,vb=1,dk-,qb=8,fk=2,djpj=7,mgv=2,lr=5,zzkn-,rjc-,rh=1,xftfd-,djpj-,lg-,rlqrc=9,hnnkm-,hnrf=6,fk-,scr=2,lfn=1,jhg=1,vp=6,pg=3,rcs=5,qjr-,dshl=9,kzz-,flzfs=3,qbbdc=5,gkg=5,cqz=7,xd-,qs-,ttbbm=2,qb-,rpj=8,fpn=1,pzhfz=9,hpb=7,fnn=8,xr-,lpj-,gl-,tdkgk-,crg=8,sm=9,kmb-,gg=1,knzhf=5,sk-,bh=7,ddtp=5,zht-,dng=2,fk=4,xtz-,fhm-,scr-,jbq=9,rhsctp-,zt=1,gpv-,pskfz-,bh-,dss-,tgjr-,vxjg-,gnl-,hrsf-,mx-,vhff=8,ljqpsl=7,nrh-,sbh-,hfg-,zsz-,vj=5,fpn-,ds=7,gkz-,gpv=3,dldzn=7,sbsp=6,zsz-,dss-,kcvf=5,pzhfz=2,sbk-,kl=9,tbt=2,dcnr=3,tcvg=6,psdf=7,szz-,bs-,hnrf=3,nd=2,dnmld=1,zmx=7,vx-,nsbxkk=9,gnl=5,fnz=9,tgzb-,xnp=4,fs=3,gbr=3,bq=2,pgff-,mpnlrx=8,pz=7,kgtl=1,lbs-,grq=4,shx-,kmb-,txp-,vnhnt=6,grv-,mhcp-,ttr=7,pzhfz=3,jpb-,cft=9,jrzs-,txp-,mdcg=9,zx-,glq-,kd-,nsbxkk=6,kzz-,ss-,sqk=2,jf-,jrzs=7,pn=5,stqt-,blnggt=5,pjg-,mm-,pzhfz-,pxk-,zj-,zv-,zl-,mzd-,tl=2,rg-,lsxt=1,nz=1,kcvf=6,mdcg=5,rs-,jrzs=8,cvl-,lb-,vvv=2,lglf-,sbj=1,rc=7,glh=1,phrk-,rk=9,vctsd-,xrr-,qsjf=5,qmt-,vqpn-,fnz-,bpk-,pzh=6,jvfpdj=9,dldzn-,cmqpn=7,jtvm=7,xntm=8,rjcj=6,bxbl-,xb-,drx=4,kgl-,sjj=2,qprbv=9,sv-,nt=3,pxk-,qqvd=2,pgff=9,hbf=6,krsl-,mhcp-,kq=4,rjcj=2,phn=5,cft=6,tqgk-,kkz-,vf=3,gkg=6,qd-,pxk=4,tg=9,qst=1,cn=7,jtvm-,pr=1,frz-,nhknz-,rrbhcr=9,mdcg=9,qhr-,cppk=8,knnx=4,thgd=9,gpm-,xcsgs=9,fq=3,qt-,rgt-,glh=6,dzlgmj=5,kkz-,dg-,qbbdc-,ztgfc-,zsz-,xk=2,bhj-,gmzd=8,skg=8,kr-,gthvfr-,nlvtt-,tq=2,jp-,drx-,nfjh-,kh=8,sbh=5,hnd=3,kgl=9,krsl=6,pz=5,zf-,kr-,pzl=4,vctsd=4,xntm-,qg=5,lpj-,dbbd-,szz-,br=9,sm=4,kjh=7,ss=8,cvl=7,ttbbm=6,ln-,lsxt-,rnd-,xcsgs-,rzdpq=2,grq=5,nlvtt-,nrl=7,xcsgs-,pgdt-,lgv=3,fdxl=1,vxp-,mppl-,zbqdr-,rg=5,plbc-,pm-,tdgls=5,zrlq=6,gr-,nsbxkk=7,rgrtpd=7,rn=3,hfg=1,mz=2,ttbbm=6,mppl-,zl-,zns-,lv-,vjvqc=5,dqt=5,hz-,nlgt-,tgsq-,pxk=1,bp-,bz=2,nz-,kcpj-,gtd-,vvv-,lfn=6,bdl=4,drx-,lr-,vmm-,rgrtpd=9,zv=5,nmt-,pm-,mgk-,hp=6,gr-,tqgk-,jxfcr=6,vx=7,kmsx-,zzkn=9,bdl=4,lch-,mg-,dx-,qjr-,xntm=1,xvk-,kgl-,hz-,tdf=1,kzc-,zj=1,phhl=7,lb-,nzl-,lglf-,bdl=9,tl=3,xl-,lch=6,phn=8,knl-,zc-,kl=5,vjjr=2,xvcgnj=6,pl-,hbjl-,cbdz-,vpt=8,rtp=7,pgff-,jqg-,xnh-,nq-,tx-,ddb=8,dx=1,qmt=7,dr=9,ffgb-,zx=6,dhmxrf=9,kkz=2,rpznm=3,phzv=2,kg-,hz=4,cm-,mx-,zrlq-,nsbxkk-,hnnkm=2,nzr=2,dzlgmj-,vjvqc=6,flzfs=2,jvfpdj-,qsjf=4,tcc-,vh=4,qvv-,zqgm-,fxdkd=6,nfjh=5,dctk-,tmqh=9,ptzd-,ltf=8,dbbd=6,ffz-,lqzvsh=5,mppl=3,pxb=9,crg=8,tgjr-,hnd-,pr=1,cz=3,hh-,ptcr=3,dcb-,hbf=4,cqz=8,jcd=7,plbc=2,gkz=6,mrqs-,dhmxrf-,pbxjx-,nb=5,zmp-,pgdt=2,zl-,sjhs=1,mx=4,fdxl-,nzr-,fqdh-,rcs=2,ltf=3,xjks-,nsg=5,rzcrg-,zm-,ddb-,nrh=1,zt=5,glh=9,pzhfz=3,kzj-,rjjk-,lhm-,dsf=7,dszd=5,cz-,th=8,jkb-,cvl=7,rkx=1,hqq-,mrqs-,db-,cbdz-,dsfln=4,cmqpn=9,qpj-,tbt=6,qx-,rzcrg=7,jt=3,shx-,kmb-,jf-,pxtqn-,krsl-,pxk=9,nrh-,tcvg=6,nfjh-,lcvqz=5,knl=1,sm-,kq=2,jptch-,hpn-,nlgt-,jxfcr-,mr=4,jz=5,ps-,gt-,jkb=5,sgq-,qxk=7,pxb-,phrk=3,vqpn-,dhmxrf-,fsbr=3,hnrf=6,qmt=3,rk-,nsbxkk-,gmzd-,jvfpdj=5,zh-,td=3,vc=4,gksbz=3,tg-,bhj=9,mvs-,pzhfz=9,vpt=3,fxdkd-,rlqrc=9,jpb-,hnnkm-,nnfd-,jr-,zbqdr=7,ng-,xtz=5,nfrmqc-,phhl-,rjcj-,rjc=7,ddx-,cppk-,dzlgmj-,cxhrzz=4,rtp-,rpznm=6,mxcgz-,zvz-,ppc-,sgq=3,jjh=2,gnl=8,ksjdgv-,nrh-,gg-,kcpj-,mgv-,cxhrzz=7,pskfz-,dcb-,xd=5,bl-,zh-,jt=4,rcs-,sbsp=3,nkh=8,gmzd-,kgl=7,xnh=5,vnhnt=2,lms-,qxk-,scr-,qg=9,jcpz-,gx=2,znkzq-,rf=4,jvfpdj-,dtg-,gthvfr-,dctk-,bd-,vlkgf=8,svnm-,jpb=6,lbs=6,hkk=9,xvcgnj-,mpnlrx-,jp=6,rfz=2,kfs=6,ddx=6,bz-,xjks=4,xv=1,jr-,jvc=6,sbnh-,nq=6,pn-,bl-,ln=2,mpnlrx-,th=4,mrqs-,zl-,kzc=1,fqdh-,ttx-,ttx=5,xqph=7,xtf=3,xftfd-,hpn-,sv-,gkf=2,zf=5,lcvqz=1,pzhfz-,pzh-,sm-,bk=2,vctsd-,lqzvsh-,jt=1,pkkbjt=1,kfs=6,gpv-,svnm=9,blxnc=8,vtc=5,gtd=5,lfn-,vb=1,dtg-,vvv-,sm=3,vp-,hrsf=6,zx-,bq-,kg=3,mb-,kcvf-,zfj-,hfg=5,mb-,tqgk-,bgv=7,ds=2,dcnr-,fbm=3,tn-,xh-,zvp-,pgff-,jd=9,knnx=8,bhj=7,vtc=7,tq=7,tkh-,gtb=3,zns-,zpgrqr-,zpgrqr=2,djq-,td-,ff=5,dzfn=9,qxk-,cz-,khq-,qd-,knzhf-,zbqdr=4,gthvfr-,kcpj-,krsl=3,hj=6,bdf-,zpcclb-,dss=7,tdgls-,lch=6,tmqh=5,rjjk-,djp-,xh-,tl-,jzv-,bk=3,qprbv-,ksjdgv-,szz-,zrlq-,qm=4,tx=2,zfgc-,zfj=1,kjh=2,vvk-,fhh=2,ks=8,dqt-,lms=2,tdkgk=6,nd=5,xz=2,jcd=8,nsbxkk=3,phrk-,mtskcr-,qpd=4,stqt=3,zmp-,tdkgk=6,tbt=9,ss-,bl-,qxk=4,vk-,zvz-,qm-,jd-,zfj=2,fx=4,zzkn-,jz=4,cft-,jv-,fnl-,khq-,jz-,qcm=4,fsbr-,pngv-,ttbbm=4,cppdg-,mr=9,xv=6,kh-,ds=6,rlqrc-,jqg-,knnx-,cn-,rmf=3,mx=4,phrk-,zl=1,mvs=1,vpj-,tg=6,nd-,nlvtt=1,lpj=5,gpm-,qmt=4,ks-,qqvd-,dg-,tdf-,zdzd=4,fnn=2,sjj-,ddb=7,ds=2,nsg-,rdm-,kgl=1,rg-,glh-,nb=4,rjjk-,dnbvds=4,xxg-,dshl=6,tg-,ch=3,qd-,qcm=5,nt=8,bdf-,mppl=5,zbqdr-,df-,lgv-,zh=7,rc=7,rn-,tdkgk=3,qz=4,mgk-,pzh=5,sm=4,ptzd-,bxbl-,nkh-,fqdh-,pgdt=1,rkx=3,xz=9,zd-,kx-,mx-,zh-,kl=8,rn=1,dcnr-,gg=9,ffz=2,lpj=9,zpcclb-,zp-,cppdg=6,gkg=9,hqq-,kx-,hnnkm=3,stqt-,cft-,bl-,xrr=7,skqr=7,skg-,kd-,jcdj=2,mg=9,jvc=2,glq=1,fhf=4,nb=2,qmt-,rf=7,knnx-,xb=2,zbqdr-,fsbr-,nfx=8,ps=7,dbd=3,rgt=7,xvk-,xtz=3,lsxt-,bk=9,tdkgk-,pkkbjt-,hnnkm-,ch-,khbsh=1,ff-,phzv-,fnn-,tmnjxl-,mgk-,tg=8,kmb-,knb=9,qxk-,kvlmn=8,zx-,qg-,hk=6,hnd=8,lqzvsh-,nrh=3,tgjr=9,kzj=5,vjvqc=6,hrsf=3,kvlmn-,hbf-,jtv-,ksjdgv=3,zx-,ddtp-,lrtd-,cxbn=6,phzv=3,qsjf=4,ll=2,zmx-,jr=1,xnh=8,bh-,ccd-,vpt=3,mb=9,pg=7,dcr=7,lch-,kr-,bz-,qc=4,grv=7,krsl=7,hnd-,dbsvh-,xsm=2,gnl-,xh=5,hnx=7,vnhnt-,cppk-,rnd=1,smn-,zmx=4,ljqpsl-,lgv-,dbt=3,nmt-,fk-,zb=1,mzd=9,pz=4,mzd-,mpnlrx-,mx-,vfz=9,blxnc=3,pjhdd-,ddtp-,sdzm-,fsk=6,cg=2,fxdkd=4,fp-,ln=5,qd-,cn=9,sf-,lg=1,mqhm-,glq=4,kmsx-,ddx-,bdgknx-,grq=1,pbxjx-,qxk=7,gkg=2,rjjk-,shx=1,flj=6,tgjr-,dhmxrf=4,dldzn=5,qst=7,gl=7,qd-,fpn-,pkkbjt-,zfgc=5,pm=9,plbc-,xsm=9,db=7,cgd=5,bdl=8,mx=7,mn-,kgl=3,jkb-,lsxt-,khq-,cn=7,jtv=1,dbt-,tdgls-,cs=1,mppl=4,bd=1,cjp=9,kcpj=5,hbqx-,zvp=4,ds=3,tbt=2,jhg-,xsm=9,rlqrc=3,blxnc-,kcvf=3,dsfln=3,vvv-,tx=3,pj-,sbk-,jptch=5,br=8,vbs-,drx=7,bl-,qr-,dtg=6,kkj=4,grv=5,lpj-,vxp-,lh=8,jpb-,xtf-,zb-,blnggt-,cs=8,bgv=6,vhff=8,pxtqn-,znkzq-,qst=4,jr=9,dzrc-,skqr-,db=8,nd-,xcsgs-,hk=5,hnrf-,zzkn=9,glh-,tgsq-,cxhrzz-,xz=6,rnd=1,grq=5,ttx=6,jpb-,mvs-,zx-,rgt-,cbdz-,phzv=7,cxbn-,glh-,xsm-,dq=6,dtg=4,jtv-,pzh-,pzl-,df=7,rg-,mrqs=6,dsf-,fnz=2,qmx-,jpb=8,gpm=6,kgtl-,pxtqn-,gx=5,dtg=5,rn=4,dtg-,lglf=3,jzv=1,jp=8,rjcj-,qc-,zh-,rkx=1,kcvf=8,hnnkm-,pgdt=5,qsp=5,txp-,nzr-,hfg-,mn-,hnrf=7,knzhf=9,jtv-,qbbdc-,knzhf=1,cqz-,fj=5,qcm=4,xvcgnj-,jc=4,cqz=5,qhf=8,zn=5,pskfz-,ll=4,pj=3,fq=5,gm-,cxhrzz=2,ks-,nbx=6,kcpj=8,kg=4,bl-,qpd-,skqr=7,xr-,vf-,fdxl-,pgdt=9,ppc-,bmkb-,tc=7,mm=3,pxtqn=6,ll-,nsg-,mqvc=8,xl=1,lv-,phzv=4,nhknz-,fn=4,pcfvv-,db=2,fhm=9,bthp=2,kgtl=5,dq-,nmt-,ljqpsl=1,cpsh-,dx-,zrlq-,cft-,cjp=4,rk=3,fsbr=5,btg-,jzv=9,zzkn-,sbh-,jz=4,rdm=2,qsp=1,gksbz-,zn-,vj-,btg=3,dcb-,fn-,jqg-,qm-,fqdh-,pjg-,rs-,gg=5,smn-,vpj=3,pv=7,cmqpn=4,xkbcj-,hpn=3,grq=6,jz=2,mdr-,dr=9,sqk-,rlqrc=2,xvk-,rkx-,nc=6,qcqp=9,vqpn=3,jk=3,cpsh-,zbqdr-,lglf-,zx=9,ps-,rlqrc=6,dk-,rjc=3,nz-,hk-,zpgrqr-,rgt-,xtf-,pskfz-,zqgm=9,ttx-,cs=8,sbsp=5,pzh=5,lcvqz-,ffz-,jvf=8,pskfz=8,ml-,cx=1,kzc-,qm=5,nq=8,ntq-,zmp-,lv=2,mzd=5,lhm-,qs-,dqt=5,dctk-,rhsctp-,zn-,hrsf-,nfjh=8,dzlgmj=9,mx=3,djpj-,rrbhcr-,rcgd-,bz=6,jhg=6,zv-,kmsx-,tbt=1,mz-,ztgfc=1,rf-,knb=2,qz=5,btg=1,ntq=4,cs=4,rpj=3,tpr=7,tcvg-,zdzd=7,pjhdd-,pj-,hnx-,zn-,xft=2,zh=1,fnn-,xnh=2,cbdz-,fhf=4,ddtp=8,kmb-,fpn-,pjhdd-,fj=6,jbq=8,rnd-,dq-,lqzvsh=6,gf=6,tgzb-,zbqdr=7,hp-,sbqr=2,fbm=3,pzhfz=9,dss=3,rdm=6,lf-,ztgfc-,tgzb=8,jtv=3,pskfz-,flzfs-,xftfd-,cg=7,bdf-,bp=7,qnc-,bhj-,rcgd=7,zfgql-,tx-,dszd=2,xpt=5,kfs=8,csph-,jbq-,flzfs-,ldb=5,dszd=2,kg=1,br=3,mdr-,pcfvv-,tfjz=9,blxnc-,tg=7,knb=5,knnx=9,nsbxkk-,mxcgz-,dss-,bl-,pdrct-,tgsq-,phn=2,bthp=5,mzd-,cxbn-,mrqc=6,jcdj-,vknrhf-,sv-,djpj=6,jcpz=3,zmx-,zdzd=9,phzv=4,ml=8,ptcr=2,hpn=4,rtp-,gbr-,vpt-,tc-,xpt-,pxtqn=5,phn-,lsgj-,lh=8,pn-,vfz=2,kvlmn-,pdh-,xdzl-,tpr=1,rjcj=1,cvl-,zh=6,blnggt-,kgl=1,fj-,xb-,sm-,cmqpn=8,mppl-,ltf=9,zf=9,mrqc=1,dss=1,gpm=5,dcr-,mqhm=9,jjh=5,jv-,jrzs=4,fs=5,zfj-,vpt-,zj-,mxsl-,lch-,rh-,fp-,zrlq=6,tq-,plbc-,zsz-,csph=2,cpsh-,kmb-,djpj-,flzfs-,pxtqn-,hz-,khbsh-,lhm-,jc-,cfzkr-,cxbn-,rlqrc-,cjp-,ppc-,vjjr=6,lcvqz=1,db=1,nbx=5,sbqr-,kkmgj-,xb=7,cxhrzz=6,lsgj=3,fs-,vc=6,gm-,ttr-,zmx-,grq=7,zh-,zbqdr-,xnh=3,lrtd-,pl=5,zht-,xl-,xh-,pzhfz=7,bq=8,szz-,dk-,jtv-,pxb=2,xtf=9,lpj=2,nnfd-,cppk-,mzd=9,knl=2,blxnc-,gbr=8,dq-,zt=7,jv-,vb-,fhm-,skg-,tdkgk=7,zmx=5,gkg=2,vc=6,mgk=2,dzlgmj-,bmkb-,pgdt=1,zsz=8,zfgql=9,flj-,mppl=9,vnhnt-,cpsh-,qcm=4,kzz=4,sdzm-,knb=9,zfgc=7,mb=3,ddtp-,nmt=7,dnbvds-,csph=5,dsf=9,gr=8,rdm=1,bdgknx-,xkbcj=2,cn-,pn-,bz=3,cppdg-,xd=6,psdf=6,nsbxkk=3,fp-,ksjdgv=1,mzd=6,zfgql=4,zpgrqr-,cmqpn=6,xk-,bs-,ddb=1,zp=4,tmqh-,xk=7,xft-,zt=3,xd-,hkk-,lrtd=6,dk=4,zc=5,nnfd=8,bs-,rjjk=3,fqdh=4,tx=3,qxs-,nsg-,tgjr=5,tcvg=6,gf-,blnggt=9,dbsvh=4,qprbv=3,kzc-,zpcclb-,bgv=7,ksjdgv-,xcsgs-,pkkbjt-,glh=7,xtf=6,rzdpq-,qx=6,lch=9,hz-,bhj=2,ntq=6,zf=2,qxk=6,zv=2,vr-,lr-,xcsgs-,mg=4,zv=9,vd-,fbm-,kkj-,vvv=1,pbxjx-,pv-,qjlv=4,dss-,mm-,ttx-,ng-,tqgk=4,fxdkd=9,rk=2,khq-,zqgm=7,drx=4,gmzd=1,qg=1,sbh=6,cvl=3,bmkb=7,dnbvds-,bxn-,zbqdr-,phn-,qjlv-,jvc-,qsp-,fdxl=4,tcc=1,zm-,nsz=5,rs=8,gf=9,lg=8,kg-,vvv=1,db-,tdgls-,kgtl=6,tg-,qmx-,flzfs-,zx=8,gr=6,nhknz-,ntq=7,lqzvsh-,xv=7,rjjk=8,cppk=1,xtz=4,dqt=5,qbbdc=5,qqvd-,xvcgnj-,rhsctp=5,zv-,np-,xv=8,mxcgz=9,xqph-,gbr-,qs=2,rs=9,ff=2,ttbbm-,jt=8,xdzl=6,sbk=3,pjhdd=9,lch-,xdzl-,lg-,tcc-,fsbr=5,tfjz-,qjr=9,sjhs-,zx-,bgv=7,rlqrc-,jz=9,ln-,xsm=6,dsf=3,qpj=3,ztgfc-,hpb=7,bhj-,pxk=9,pngv=5,zl-,lch-,vk-,pcfvv=6,lv=8,vlkgf=7,hlrdnn=9,sgq=2,vtc-,kfs-,mrqc=9,cx=1,hbf-,cvl=5,jcdj=6,lqzvsh=7,hnd=5,lfn-,qcm-,fhh-,jxfcr=4,qd=9,fhm-,ff-,tn=8,kn=8,mdr-,ffz=1,bk-,xftfd=5,jvfpdj=5,ffgb=7,ng-,jj-,gl=4,ff=3,knnx-,flzfs=1,ltf=7,bz-,fs=8,vd-,dqt=8,grv-,bl=5,zp=5,vj=4,pkkbjt-,pr-,bh-,hpb=4,djpj=2,dnbvds=8,pcfvv-,jzv-,lms-,xrr-,lsgj=7,tdkgk=2,lsgj-,bl=7,zh-,jr=3,rdm=9,vxjg-,xnh-,rmf-,kx-,jf-,jrzs=4,jd=1,mn=8,flj=2,vh-,jvc=8,csph-,gkf-,cqp-,kgtl-,cs-,ddtp-,zmp=1,dldzn=6,gksbz=2,rs-,rc-,kkz-,kjh=2,cm-,vpj-,vnhnt=5,ffgb=8,rf-,skqr-,jhg=8,xrr=5,zmc=7,ffz=4,pzhfz=6,pv=1,nnfd-,cppk-,lsxt=4,cgd=1,qcqp=7,fnz-,ptzd=4,knb=6,sqk-,vf-,bdf=6,nkh-,kl-,crg-,fn=3,flzfs=2,pbxjx=8,zmc-,fk=2,jt-,vx-,krsl=1,smn=7,qg-,ljqpsl-,xd-,cs-,ztgfc-,cm-,knnx=5,nz-,bdl=6,jr=9,lv=5,rtp=6,lch-,jkb-,sqk-,pzhfz=4,nrh=1,nnfd-,dcr-,dnmld-,mm=3,vfz=1,zht=1,xvcgnj-,cxhrzz=5,blnggt-,tqgk-,dnbvds-,zvz-,pr-,dzfn-,vlks-,mr=2,fk=2,qxk=8,cvl-,fx=7,plbc-,plbc-,qxs-,zm-,tdkgk=9,bdf=4,kq-,sjj-,zd=2,hnx=5,pzhfz-,zp-,ntq-,crg-,plbc=1,xrr=9,mqhm-,rc-,ltf=7,pgdt=1,kq-,fsbr=4,vpj-,jh=9,nsbxkk-,shx-,vxjg-,dqt-,pngv-,txp-,kq-,fq=1,jr-,ss=9,plbc=2,mxcgz-,rfz=3,zpgrqr-,zmx=4,jt=1,kr-,tgsq=5,fhm=9,nlvtt=9,hnnkm-,qhr=6,skqr=8,vvv=5,xh=2,gpv=5,rhsctp-,vx=6,xntm-,psdf=7,sbsp-,zn=2,ntq=1,zfgc-,zn-,tg=8,qxk=7,dldzn=4,cx=6,kn=8,lsgj=7,kzj-,lsgj-,np-,xh=2,mgk=3,gthvfr=3,djpj=4,zsv=7,zl-,kmsx=2,sv-,lglf=9,qd-,fq=4,ch-,xl=6,qxk-,cm=9,cbdz=2,zp=9,xb=7,kx-,ptzd=3,pztx=3,sbsp=2,dg=5,lf=6,xv=7,qb-,lglf-,ln-,bh-,mgk-,pzh-,tbt=7,xvcgnj=5,vfz=5,tl-,nzr-,dszd=3,nzl=3,qcqp=4,np-,qffgm=5,gl-,krsl=4,cqp-,fpn-,vmm=1,fnl-,jp-,nlgt-,ddtp-,xb=8,qqvd=1,jz-,cbdz-,jvfpdj=5,kkj-,zfj-,kgtl=3,psdf=7,vctsd-,dss=2,dbd=9,fnn=5,cppdg=7,rzdpq=3,dzlgmj-,nzr-,ljqpsl=3,fhh=5,mbn=9,rrbhcr-,lms=4,qqvd-,ttr-,mr-,bthp=5,jd=9,jjh=5,gg-,jvfpdj=9,jkb=7,zm-,qmx=4,xpt=9,pz=6,zj-,psdf=6,sf-,nsz-,zqgm-,xjks=9,xvk-,gg-,xpt=9,lqzvsh-,djpj=9,vjvqc=4,qsjf=7,ff=5,jjh-,lhm-,mdcg=2,grv=8,vnhnt-,cfzkr-,phhl=4,tg-,dk=9,mhcp=1,vb-,jt-,zm-,mm-,gt-,nt=1,ng-,mz-,qnc-,ntq-,mgv-,jhg=3,bxbl=7,nzl=3,ldb=7,blnggt=3,fn=5,khbsh=5,kx-,pv-,nsg-,rdm-,mgv-,pkkbjt-,glq-,lgv=9,hbjl-,qxk-,vjjr=7,qvv=7,sbnh=2,gg-,zsv-,rf-,jzv=9,nrl-,zpcclb-,qhr-,kn=2,hnnkm=3,zmc=4,qprbv=8,pgdt-,zbqdr-,bthp-,rhsctp-,djq=9,lhm=9,shx=2,qcm=3,phrk=4,hnd=6,pgdt=5,dx-,phhl-,fnl=5,dshl-,mxcgz=1,dx=9,cxhrzz=2,skg-,tfjz-,qmt-,sv-,zp-,lfn=4,mz=2,jhg=2,rc-,df-,ztgfc=5,gpv=5,kl-,lv=3,qg-,csph-,mpnlrx-,sm=2,tgzb-,bdf-,vf=8,kzz=4,ds=1,vjjr=2,kfs-,zf-,dx-,pn-,phhl=2,zsv=6,cqp-,jbq=1,ff-,pxtqn=8,nfx=5,knzhf=8,rh-,flj=5,ffz=4,dq-,ccd=9,mdcg=9,gkf=9,xdzl=9,nq=9,jp=8,gr=4,vh-,tx-,xv=6,nzr=5,pbxjx-,xz=5,tgzb=8,dzrc=9,ttbbm=2,gl=2,vc-,ttx-,qbbdc-,dtg-,ppc=1,mqhm=4,gpm-,mr-,sbsp=9,cm=3,djp-,lms-,pjq=1,mtskcr=8,qmx-,zzkn-,gkz=9,fn=9,sbnh=2,vnhnt-,bs=7,gr-,mvs=1,djpj=1,vlkgf-,rnd=4,jtvm-,cgd=8,lrtd=6,dtg=2,vb-,hk=1,xb=8,nfrmqc-,jjh=4,vctsd-,ks-,dldzn=9,fk-,vx=8,lms-,txp-,tdf-,vk=9,dvq-,mx=8,jv=6,ffz=2,qpd-,blnggt-,kn-,vk-,sbqr=3,lglf=5,rnd-,pm-,djp=9,djpj=4,dqt-,dshl=6,lbs-,jvc=5,hfg-,bthp=7,kgl=5,pskfz-,qhr-,lv=2,ttx=2,ttr=7,zmc-,skg-,kx=2,phn=1,pjg-,tq-,xqph-,fxdkd-,cmqpn-,sbj=9,dcb-,pm=3,dhmxrf-,qsp=9,cfzkr=9,bxbl-,scr-,hk-,smn=1,qc-,txp-,gksbz-,bdgknx-,jhg=5,djp=4,xftfd=9,db-,lr-,zpcclb=4,ml=2,cqz=5,hj-,ksjdgv=7,pm=3,rjcj-,crg=1,cfzkr-,kx-,cxbn=3,dtg=7,zh-,lqzvsh=2,pgdt=8,hkk=8,jd-,pjg=8,hnx-,vf-,tgjr-,cxhrzz-,scr-,xb-,ffz=7,lgv=7,jbq=9,tdgls-,br-,sjhs=8,nrh-,nd=2,pgff-,mzd-,kn-,fx-,svnm=9,kh=5,mxsl=9,cft-,nhknz-,tqgk=4,kkz-,kkmgj-,xxg=1,rrbhcr-,jpb-,ffgb=4,kg-,bmkb=2,knl=4,dsf-,jp=4,df-,gf-,kzz=6,ddb=8,pn-,qc-,pj-,xl=5,xvcgnj-,pxb-,vjjr=7,vnhnt=3,kn-,jf-,tc=2,rgrtpd=7,xvcgnj-,fpn=8,blnggt=5,cg-,lg-,xntm=7,ss-,dldzn=5,bdf-,qt=5,kkj=3,hp-,jr=2,gf-,kgtl-,fhf-,fxdkd=4,blxnc-,nlvtt=4,ff-,mzd-,kmsx=7,lsgj=2,cg=7,vqpn=9,psdf-,hlrdnn=8,np-,vlks=3,lqzvsh-,qc=4,tn-,svnm-,zf-,xqph=9,tpr-,df=4,cppk-,psdf=7,dsf-,kmsx=5,tg-,vxjg-,gmzd=6,qm-,nrl-,pkkbjt=8,fs=4,vc-,qpd=7,jhg-,pgff=7,vx-,nt=7,dcb-,vmm-,zvz=5,frz-,mvf=9,fbm-,ffz=7,tfjz-,zfj=5,pv=7,sdzm-,bdf-,dcr=6,vbs-,hkk=7,ln=7,kkj-,qcm-,fsk-,qmx-,rcgd-,jc=3,qx-,bdgknx=3,phrk-,br-,nd-,dnmld=5,ch=2,hpn=9,bthp=3,lsgj-,zvp-,hnd=9,vlkgf=9,jt=4,tl-,vmm=1,qbbdc-,tg-,zsz=5,sqk-,jc=7,kg-,pj=4,nrh-,phzv-,hrsf=3,zln-,rpj=8,xxg-,vfz=9,kkz=2,mtskcr-,qbbdc=4,pztx-,rzcrg=4,kzz-,fhh-,ptcr-,kzc=4,qch-,tq=6,pkkbjt=6,nkh-,plbc-,jj-,xpt=6,thgd-,qjlv=9,fsbr=2,bdf-,jxfcr=8,mgv-,dqt-,tq=8,fq-,xrr=3,mhcp=1,nfjh=6,bdl-,hpb=5,dctk=8,dshl=1,hnx=4,ppc=2,jz=2,ps-,zrlq=5,fs-,sbsp=6,pg-,jhg=4,sbj=7,qg-,pjq=3,dldzn=1,zd-,rpj-,ttx-,blnggt-,mgk-,xk=6,pz=2,kcpj=8,dcb-,skg-,rh-,sz-,cfzkr-,rgt=8,nz-,hbf-,vc=1,vk=5,fs-,xdzl-,btg=1,zrlq=8,jzv-,qg-,xl-,pjq-,krsl-,ttr=3,mxcgz=9,rlqrc=7,zj-,szz-,ss=6,zp=8,sbqr-,rtp=3,jj=8,lrtd-,dg=5,nlgt=6,dcr-,dzlgmj=8,pngv-,kq-,lgv-,lrtd-,zmp-,lsgj-,tc=5,vqpn-,td-,lh-,dcnr-,xd=2,shx-,ddm=8,vvv-,pxk=4,nkh=8,ljqpsl-,dbt-,ff=5,ng-,sbh=2,nq-,pdrct=7,qxk-,tcvg=6,rs=4,qm-,mrqc-,vbs-,zsz-,sbj-,cppk-,rkx=6,rs-,vpj-,ldb-,hnrf=7,qst=8,qhf-,bxbl-,jpb=5,zqgm=6,tgsq-,gpv=3,tl=8,fpn=7,blxnc=7,xvk=7,ps-,xd-,qr-,tgjr=4,pjhdd-,jv-,rkx-,vvv-,cppdg-,kzz-,xtf=2,vvv-,dng-,cxhrzz-,xxg-,tcvg-,zmx-,sbnh-,rmf-,vpj-,tgsq=4,bk=7,kkj-,vvv=8,hnx=2,xft=8,nsz=4,hbf=7,fsk=7,lgv-,xdgq=3,clm=1,phn=8,gpv=4,zm-,pzh=6,bxbl-,lqzvsh-,vlks-,vp=4,mrqs=6,nnfd=6,mxsl-,vr=7,fj-,ltf=8,kzc-,vfz-,lms-,pxtqn-,mb-,rk=7,cft-,kjh-,gmzd=6,rzdpq-,ptcr=8,dzrc=6,zn-,pm-,xvk-,cx=4,rn=3,vctsd-,qz=9,fn-,zh-,pg-,cz-,xntm-,vlks-,lcvqz-,sdzm-,cgd=6,tbt=5,ffz-,bhj=9,qjlv=5,sv-,nc=8,qr=3,lms-,khq-,ffz-,ln-,kcvf-,zrlq=6,lsgj=7,zf=8,vr=5,bxn=6,rtp=4,fdxl-,cxbn=5,mrqc-,qpd-,fk-,qqvd=2,djq=7,bk=8,qch-,jcd=7,ptcr=4,rcs-,qhf-,sgq-,bpk-,fq-,dcb-,rpj=9,tmnjxl=7,vtc=3,zrlq-,mgv=4,jkb=4,cvl-,kq-,ttbbm=1,kzc=8,dbd=3,lch-,ks-,blxnc=7,vp=3,pm=7,jpb-,tkh=8,fxdkd=8,dzrc-,qxk=3,jkb=8,cx-,dvq-,ztgfc-,sbk-,mr=8,kg-,txp-,xnh=4,jxfcr-,qm-,tl=1,vmm-,mrqs=7,mn-,lpj-,nrh-,qcm-,xz-,xft=1,kcpj-,phzv=7,kmb-,zt-,mgv=2,dcnr-,zpcclb=9,kgl=4,rhsctp=6,lrtd=5,ccd=3,dhmxrf-,qqvd=1,mppl=9,ff-,mrqs=1,ttx=6,hp=7,kgtl-,qhr=5,kjh-,qnc=2,vr=8,fx=7,rmf-,tcvg-,gtb=2,gnl=3,ddtp=9,th=1,hp-,zbqdr=6,nnfd=3,rfz-,xsm-,bpk=7,sgq-,hkk-,ttr=5,ppc-,vjvqc-,zht-,dcb-,rhsctp=7,kh-,jxfcr-,mgk-,cjp-,djpj=7,gkg=5,rjcj=5,mqvc-,zns-,lqzvsh=3,ppc=3,sm=8,jr-,jqg=5,sjhs-,ptzd-,hnd=5,pz=1,tmqh-,kmsx-,pxtqn-,phzv=6,krsl-,mrqs-,dcr-,dss=5,jt-,xh-,rcs=4,gpv-,pz-,ttbbm=8,mn=7,jjh-,qst=1,vk=6,gtb=4,pngv-,hj-,kr=6,hpb=3,dzlgmj=6,zfgc-,mx-,dctk=9,ttx-,nz=9,gkg=9,ddx=8,mpnlrx-,ntq-,vjjr=1,nfjh=2,tmqh-,hqq-,fj=6,fn-,kg-,svnm=3,gbr-,xvk=7,qz=6,zsv=1,hnd-,rzdpq=4,bhj=9,cmqpn=8,tgjr-,jd=2,djq-,glh=1,mr-,qsp=5,gt=7,vvv-,gf=4,kcvf=3,cft=7,glh-,lglf=4,lfn-,ddx-,kcvf=6,tbt-,kh-,gbr-,zt-,kcpj-,gl=1,kkj-,cbdz-,mg=2,dzrc-,hfg-,qbbdc=2,ddm=7,rrbhcr=3,tgzb=5,hpb=8,nq-,xd=9,nkh=8,nbx-,ttr=3,hk-,nhknz=2,lpj-,zd=8,jqg-,dtg-,br=1,vr-,vr=6,kh-,ddm-,sjj=4,mxcgz-,qprbv-,zb=2,pjg=7,dg-,khq=6,csph-,jbq-,cvl=9,tpr=8,fpn-,nfx-,vj=4,rkx=9,lglf=9,ttr=3,nrl-,gl-,xtz=5,znkzq-,nb-,vc-,dss-,zqgm-,pzl-,vx=7,tg=9,tq=6,mr=1,ttx=7,ds=7,gr=1,xjks-,vk=5,qpd-,ps=1,th-,rzcrg-,kkj-,kl-,vr-,ljqpsl=6,cgd=1,zbqdr=1,mr=9,pzhfz-,fhf-,sbqr=3,fp=2,mxsl=5,lfn=7,tc=8,qs-,kcpj=3,sgq=2,phzv-,mzd=2,gf=7,vfz-,vxp-,gnl=7,tgzb-,pzl-,fnz=2,qpd=6,lgv-,qc=6,lg-,cm=3,vlks=4,nfjh-,mgv-,jptch=7,mn-,dshl=4,jvc=8,dk=6,tx=5,zqgm=6,sk=9,nd=4,stqt=8,cxbn=3,dszd-,zvz-,kl-,xkbcj=7,gtb-,jc-,vh-,pjhdd=8,knnx=8,tgzb=5,xdzl-,jxfcr-,rn=4,gt=8,hqq-,pztx=3,nzr=6,mvf-,dbt=9,mrqs=3,lhm=7,pbxjx-,rgrtpd=2,bgv=1,jqg-,kkz=5,vpt=5,gkg=2,ds=4,rjc-,dng=9,qhf=8,fsbr=6,vctsd-,hpb=1,kmsx=7,jkb-,qz=8,fk=6,cppdg-,xvcgnj=2,kl-,bp=6,rjc=6,qqvd=9,rgrtpd=8,plbc-,pkkbjt=8,hpn-,jhg-,ln=2,ttr=3,zns-,sbnh-,tc=9,fdxl=6,xntm-,sm=5,vctsd=8,jf=9,vc=1,dsfln-,fq-,gx=5,qmx-,dhmxrf-,ks=8,ff=9,hfg=9,mgk=8,xkbcj-,qpj-,pzhfz-,dzrc=2,cgd=6,rcgd-,flj-,tgsq-,pn-,rkx=7,gbr-,fhf-,ln=3,vvv=1,sfg=5,pskfz=6,cx=6,mb=1,tn-,cs-,grv-,hj-,fbm-,ddm=8,cppk=8,vctsd=3,qsjf-,dzrc=6,znkzq-,ccd=3,mbn=9,hrsf=4,fn-,cfzkr-,tfjz=9,pgff=7,tcvg-,hnrf-,krsl-,jbq=3,gr-,cg=5,jjh=4,dsfln-,fj=9,vf=9,xjks=5,lch-,fp=2,vd-,gr=9,fs-,xr-,qsjf-,cz-,qjr=8,fn=2,sjhs-,gf=7,dqt=1,xsm=5,gkz=6,bs-,bhj-,zp-,nrh-,xv=8,lrtd-,ttr=2,jqg-,hpb=9,bz=9,rjjk-,mb-,zmp-,mrqc-,ccd-,jt=2,fdxl-,jd=6,jqg=7,gmzd-,pztx-,lcvqz=7,rkx-,cfzkr-,xz=4,zns-,hpb=2,ttr-,cft-,skqr-,mzd-,nrl=5,qffgm-,vp=4,gg=3,bhj-,pdh-,vhff=6,mgk=5,vvk=2,lgv=2,bhj=4,lgv=9,cjp-,pskfz-,nfjh=8,nnfd=2,glq=4,kfs=8,qt-,pzl-,rc-,sqk=7,dshl=1,ffgb-,fsk=9,stqt=4,vmm-,rzcrg=5,nfrmqc=1,bs-,pbxjx-,fk-,vlkgf-,qsp-,sqk=3,mtskcr=1,rlqrc=5,rtp-,mbn=9,rs-,fhh=1,vjjr-,rcs=9,rmf=2,mgk=8,kn=1,xvk-,xkbcj=8,dq-,zn-,mqhm=4,jj-,znkzq-,mbn-,mx-,pkkbjt-,kl-,ltf=1,cs=4,fsbr=2,mz=5,kgl=6,kgtl-,nd=3,cpsh-,rnd-,mb-,pj=1,shx=9,dsf=2,sv=9,dss=7,phrk-,vp=1,kvlmn-,zt-,lv=9,lsgj=6,hrsf-,bp-,hj=2,lms-,nzr-,rjc-,tx=2,ptzd=8,fx-,tgjr=5,cppdg=4,cppk=8,rnd=9,kd-,hpn=6,sbsp=4,rpj=4,lf-,smn-,gl=9,qhr-,zvz-,qmx-,cg-,xtf-,kg=7,rh=3,cs=4,ppc=2,bs=4,nrl=2,ff=7,jv-,pzh=4,mdr=6,ds=1,zc-,zm=2,qmt-,znkzq-,mb=2,tdgls-,ltf=8,kd=4,dk-,sjj=3,cvl-,dcnr-,mhcp-,hp=1,lr-,br-,gpm-,zp=7,fsbr-,rgrtpd=3,nrl-,tn=9,ddb-,rpznm-,th-,pl-,mvs=3,znkzq=9,vxp=2,sk=2,krsl=2,tx=1,dx-,qmt=6,dbbd-,stqt=6,vk-,kcpj=3,pz=5,cxhrzz-,qvv-,pdrct=2,ldb=7,vjvqc=6,sdzm=4,flzfs=3,djpj=5,bq=8,khq=9,rcs-,fk-,rn=2,lsxt-,phzv-,tl-,dctk=5,pm=2,fnl=7,rcs-,flj=3,mpnlrx-,xxg=6,nzr-,jkb-,nd-,gnl-,gnl=9,jcdj=3,bxn=8,tfjz=5,txp=2,pjg-,jzv-,dtg=8,ddb=9,qmt=5,vd=3,glh=9,shx-,hbjl=3,dx-,kh=7,br=1,mgv=5,tcvg=6,lrtd-,rmf-,cjp=4,cqz-,mdcg=4,ffz-,dnmld-,sfg-,dx-,bthp=7,gmzd-,tqgk=4,jr=9,dss=9,gpm-,jc=5,hz-,ffgb-,cvl=5,nfjh=8,cvl-,dnmld=5,fsk=7,qxk=4,ptcr=4,lf=9,rfz=5,mvs=1,vp-,fqdh=1,qs=7,xvk=1,sqk=6,nfrmqc=9,kkj-,pztx-,sv-,df=1,vxp=1,fq=7,mtskcr=2,nfjh=1,jk-,xdzl-,xvcgnj-,kg=8,pr-,zmc-,ksjdgv=7,lg=7,khbsh-,dbt=5,kvlmn=5,ttbbm=1,zrlq=1,cm=2,zfgc-,jcpz-,nnfd=2,kg-,bp=4,svnm=7,xtf=4,lch=2,pzl-,bz-,dqt-,ttbbm=9,nsz-,mvf=5,nbx-,jjh=1,xntm-,dcnr=8,lhm=7,blxnc-,ng-,rjjk-,qbbdc=6,rzdpq=6,znkzq=6,vh=7,kkmgj=6,ff=2,ss-,glh-,gkg-,lgv=6,lqzvsh-,sgq=5,vnhnt-,nc=5,zh=9,cg-,dq-,zf-,ksjdgv=4,xr=1,fn-,mqhm=2,zmx-,lcvqz-,ml-,rhsctp=8,jhg-,pg=3,ss=9,bq=4,gtd=1,zvp-,xvcgnj=1,fhm=7,zpgrqr-,vjjr=3,fpn=8,fbm-,ds=3,cm=2,pgff-".to_string()
+    #[test]
+    fn part2_example() {
+        assert_eq!(145, part2(&Day15::parse(&example(15)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(294_474, part2(&Day15::parse(&input(15)).unwrap()));
+    }
 }
@@ -1,7 +1,18 @@
 use std::collections::HashSet;
 
+use rayon::prelude::*;
+
+use crate::{
+    animation::{Recorder, Recording},
+    geometry::Direction,
+    grid::Grid,
+    parsing::ParseError,
+    render::{Picture, Rgb},
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum ObjectType {
+pub enum ObjectType {
     EmptySpace,
     MirrorForward,
     MirrorBackward,
@@ -9,14 +20,6 @@ enum ObjectType {
     HorizontalSplitter,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 type Point = (usize, usize);
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -25,43 +28,55 @@ struct Beam {
     direction: Direction,
 }
 
-type Contraption = Vec<Vec<ObjectType>>;
+type Contraption = Grid<ObjectType>;
 
-trait Grid {
-    fn is_point_within(&self, point: (i32, i32)) -> bool;
-}
+pub struct Day16;
 
-impl Grid for Contraption {
-    fn is_point_within(&self, (x, y): (i32, i32)) -> bool {
-        !(x < 0 || x > self.first().unwrap().len() as i32 - 1 || y < 0 || y > self.len() as i32 - 1)
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
-}
 
-fn main() {
-    assert_eq!(46, part1(&test_input()));
-    assert_eq!(8551, part1(&input()));
-    assert_eq!(51, part2(&test_input()));
-    assert_eq!(8754, part2(&input()));
-}
+    fn part1(contraption: &Self::Input<'_>) -> Answer {
+        part1(contraption).into()
+    }
 
-fn part1(input: &String) -> usize {
-    let contraption = parse(input);
+    fn part2(contraption: &Self::Input<'_>) -> Answer {
+        part2(contraption).into()
+    }
+
+    fn animate(contraption: &Self::Input<'_>, recording: &mut Recording) -> bool {
+        count_energized_tiles(contraption, INITIAL_BEAM, recording);
+
+        true
+    }
+}
 
-    let initial_beam = Beam {
-        head: (0, 0),
-        direction: Direction::Right,
-    };
+/// The beam of part 1, which enters the contraption in the top-left corner.
+const INITIAL_BEAM: Beam = Beam {
+    head: (0, 0),
+    direction: Direction::Right,
+};
 
-    count_energized_tiles(&contraption, initial_beam)
+fn part1(contraption: &Contraption) -> usize {
+    count_energized_tiles(contraption, INITIAL_BEAM, &mut ())
 }
 
-fn count_energized_tiles(contraption: &Contraption, initial_beam: Beam) -> usize {
+/// Follows the beam through the contraption. Every tile a beam moves to is a step of the
+/// recording.
+fn count_energized_tiles(
+    contraption: &Contraption,
+    initial_beam: Beam,
+    recorder: &mut impl Recorder,
+) -> usize {
     let mut energized_tiles: HashSet<(usize, usize, Direction)> = HashSet::new();
     let mut stack: Vec<Beam> = vec![initial_beam];
 
-    while !stack.is_empty() {
-        let mut beam = stack.pop().expect("The stack shouldn't be empty");
-
+    while let Some(mut beam) = stack.pop() {
         if energized_tiles.contains(&(beam.head.0, beam.head.1, beam.direction)) {
             // We've already run down this path, no need to do it again.
             continue;
@@ -69,7 +84,13 @@ fn count_energized_tiles(contraption: &Contraption, initial_beam: Beam) -> usize
 
         energized_tiles.insert((beam.head.0, beam.head.1, beam.direction));
 
-        match (beam.direction, contraption[beam.head.1][beam.head.0]) {
+        recorder.record(|| {
+            picture(contraption)
+                .highlight(energized_tiles.iter().map(|&(x, y, _)| (x, y)), Rgb::YELLOW)
+                .highlight([beam.head], Rgb::RED)
+        });
+
+        match (beam.direction, contraption[beam.head]) {
             (Direction::Up, ObjectType::MirrorForward) => {
                 beam.direction = Direction::Right;
             }
@@ -137,14 +158,12 @@ fn count_energized_tiles(contraption: &Contraption, initial_beam: Beam) -> usize
             (_, ObjectType::EmptySpace) => (),
         };
 
-        let next_point = next_point(&beam);
-
-        if !contraption.is_point_within(next_point) {
+        let Some(next_point) = contraption.step(beam.head, beam.direction) else {
             // This beam goes out of the contraption, no need to keep tracking it.
             continue;
-        }
+        };
 
-        beam.head = (next_point.0 as usize, next_point.1 as usize);
+        beam.head = next_point;
 
         stack.push(beam);
     }
@@ -152,224 +171,86 @@ fn count_energized_tiles(contraption: &Contraption, initial_beam: Beam) -> usize
     HashSet::<(usize, usize)>::from_iter(energized_tiles.iter().map(|(x, y, _)| (*x, *y))).len()
 }
 
-fn next_point(beam: &Beam) -> (i32, i32) {
-    match beam.direction {
-        Direction::Up => (beam.head.0 as i32, beam.head.1 as i32 - 1),
-        Direction::Right => (beam.head.0 as i32 + 1, beam.head.1 as i32),
-        Direction::Down => (beam.head.0 as i32, beam.head.1 as i32 + 1),
-        Direction::Left => (beam.head.0 as i32 - 1, beam.head.1 as i32),
-    }
+fn picture(contraption: &Contraption) -> Picture {
+    Picture::of_grid(contraption, |object| match object {
+        ObjectType::EmptySpace => Rgb::BLACK,
+        _ => Rgb::GREY,
+    })
 }
 
-fn part2(input: &String) -> usize {
-    let contraption = parse(input);
+/// Tries a beam from every tile on the edge, pointing into the contraption.
+fn part2(contraption: &Contraption) -> usize {
+    let width = contraption.width();
+    let height = contraption.height();
 
-    let width = contraption
-        .first()
-        .expect("The contraption should have at least one row")
-        .len();
-    let height = contraption.len();
-
-    let mut most_energized_tiles = 0;
-
-    for x in 0..width {
-        let energized_tiles = count_energized_tiles(
-            &contraption,
+    let vertical = (0..width).flat_map(|x| {
+        [
             Beam {
                 head: (x, height - 1),
                 direction: Direction::Up,
             },
-        );
-
-        if energized_tiles > most_energized_tiles {
-            most_energized_tiles = energized_tiles;
-        }
-
-        let energized_tiles = count_energized_tiles(
-            &contraption,
             Beam {
                 head: (x, 0),
                 direction: Direction::Down,
             },
-        );
+        ]
+    });
 
-        if energized_tiles > most_energized_tiles {
-            most_energized_tiles = energized_tiles;
-        }
-    }
-
-    for y in 0..height {
-        let energized_tiles = count_energized_tiles(
-            &contraption,
+    let horizontal = (0..height).flat_map(|y| {
+        [
             Beam {
                 head: (0, y),
                 direction: Direction::Right,
             },
-        );
-
-        if energized_tiles > most_energized_tiles {
-            most_energized_tiles = energized_tiles;
-        }
-
-        let energized_tiles = count_energized_tiles(
-            &contraption,
             Beam {
                 head: (width - 1, y),
                 direction: Direction::Left,
             },
-        );
+        ]
+    });
 
-        if energized_tiles > most_energized_tiles {
-            most_energized_tiles = energized_tiles;
-        }
-    }
+    let entry_beams: Vec<Beam> = vertical.chain(horizontal).collect();
 
-    most_energized_tiles
+    entry_beams
+        .into_par_iter()
+        .map(|beam| count_energized_tiles(contraption, beam, &mut ()))
+        .max()
+        .unwrap_or(0)
 }
 
-fn parse(input: &str) -> Contraption {
-    input
-        .split("\n")
-        .map(|line| {
-            line.chars()
-                .map(|ch| match ch {
-                    '.' => ObjectType::EmptySpace,
-                    '/' => ObjectType::MirrorForward,
-                    '\\' => ObjectType::MirrorBackward,
-                    '|' => ObjectType::VerticalSplitter,
-                    '-' => ObjectType::HorizontalSplitter,
-                    other => panic!("'{other}' cannot be parsed to an ObjectType"),
-                })
-                .collect()
-        })
-        .collect()
+fn parse(input: &str) -> Result<Contraption, ParseError> {
+    Grid::parse(input, |ch| match ch {
+        '.' => Some(ObjectType::EmptySpace),
+        '/' => Some(ObjectType::MirrorForward),
+        '\\' => Some(ObjectType::MirrorBackward),
+        '|' => Some(ObjectType::VerticalSplitter),
+        '-' => Some(ObjectType::HorizontalSplitter),
+        _ => None,
+    })
 }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    r".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."
-        .to_string()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(46, part1(&Day16::parse(&example(16)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> String {
-    r"\..|.....................|.-........\.............-................|./....|.....-...-.........................
-.......\.........|..............-...||..............-...........--.|...............||..........\............|.
-........../.....................\.........\..................\....|.....-.................../..-.\............
-................/..../........../..-....|\.|...-...................\....|/........|...........................
-.....|../-\.|....|................................--.-./..........|.........../..-....-................-....|.
-.../..........\................-.......\................|/..\.................\.....\...|....../............./
-..-../.../......-.....\........//.............|.........../...|.........../.......-...........................
-......\....../......./..|\............/..........................\..............\.....\.........|......-...|..
-.|/.......\.........|...../..//...................................-....-...........././-...............-......
-........-....../......./..\.............|.....................\........../-....../........-...................
-......-........./\..................../..\|..-//....|.\--................|.....|.../............-............\
-........................|...-........|..............|..............|................./........................
-...................|................|..../...-...................../...|......\..................|...|\.......
-....-......../.\...............\-...\...........................................................|.............
-.............../.|.......|.........\.\.........................-|.............\........-.../...-...||.........
-....-...-\.-............/...../...../..\........\|....\...............\.............-........................\
-........././.......//......-...|...........-...|..|....-......-.......|............................|../.......
-\.................|..../.....-..|......./.....................\....../...|./\.\...............-............./.
-.....-\............../...................................../....\.....-|...........-.\...../............\.....
-......................./..\..................|...............-.........-..../......../..|.....................
-....\...................../...../......|...........................\......||.\...-..............-...........|.
-..........................|........../...........|....-.......\........................-|..\............/..|..
--./...|..............................................|./../.........-|.\.|..........\........../.........\....
-...........|......................-............................|.........................\....-........../....
-........................|/....\......../.|.......\.........../..................\........................-....
-...../.................-............................-.\......|...............\...........\.....-.............-
-....|-.............-.../....\...........-...........|\.............-.............../-....../..................
-...............\/.............../.|............................/.|...................-............-...........
-.................\.....\........./......../.....\.....|........-..\..........................|................
-...............-..-........-........../...../...\.................\..-.-.....|..........\..............\......
-.............-....../....|.\\............|......|.........-.......|................/.......|./............\...
-.................................................\.......\-....\.........|.....-..|.........-................/
-.............../..............\..........................\.................-\............-............-.......
-/....|...../.....................................................-....\.....-................../..........-..\
-.....\.....................-...........|...\.....-...../.......-.|.................|..........................
-.............-..........-....-....-.-../....|.......-.\.........................-........../..../.....\.......
-..........\\.........................../...-........./........\............|.........../......................
-............-......|..................................................................-......./...-...........
-........\............-......|....\............................\.\........|......................../......\...-
-......../...|........|..-.-.../..../..........|........|....../....../../...........\.............\....../....
-......|.........-........//........-../....\........./..........|.-......-...-......-.............\..|........
-./......................../................-..........................................-.....--....|...........
-....../............\\..../\............|..-.|.|.-.............-........|................................./.../
-..-......../.......|.......|........................-....-..............................-.....................
-.........||............|.\................|......................./.\...............-..........|..../..-..\...
-\|.......|..\..........-.................../|.....-.............\............./....................../........
-.....................|....-.../...............\.\.............................................................
-............|.......................\...........-......|...././.-...../................|........-..........\.\
-..........|../\.|.......|\..-............\.-..............-....../...\......||..\........-.\..../...\......./|
-..|.....................................-\............//.......|..\..............\.........|....|.............
-.....\...............................-........./..........\.........-....|..........-./../...\................
-.....\./....-...\....................\|.........\.............-...\..........-...............|............./..
-/./\......\.........\........-../......../.....|/..................../.././..-......................|\........
-.|\...-.\\.......................|........-\.....|......|......./|..........-..../...................|.|......
-...........\.............\./....|./.............................|.............................................
-.../...../.|.................\...../.....-..............|.\.\../.................../.............\............
-..............\............-........-........\....../............|....|...\....\...|.....-..../...............
-/.\.\........-....\.|....-....\..../...-./...............-.......\../........./..../........./.......\........
-|..............................-..\............................\......//........\../...-../..\......../.......
-.............\.../.\............\--............|................-|............|......../......................
-...../....|................................-.....................................|./..........|.........\.\\..
-........./......-.....|............/.-.......\..-.......................................|..................\..
-.......\.\.....................-|...../.....|........|............-..............-....../\..\....../..........
-|..\.|........../|..\..|\..-...................\.................../.....-/...............|................../
-...........|..-..............|........................-..............-...............-.............\.........\
-...\./.....-...|.-.......-................/.\.....-..-...|......-.......................................--...|
-...............-.........|............-............|./.......\...../.....\............-.\.....................
-..................|......|..........-..............................-....|............|.\........./..-.........
-|......................../.....-../|.....\./..\....|......................./........................./...\....
-....|............-........\..-...............-................/\..........................|........|.......\-.
-|......./.....\.......\..................-.\..../....|............|.../..................../|.../........../..
-...-|..................||......--......\......|.............|\........./.................\......\........\....
-./........./........-...................................\......-......................................-.....-.
-.....\..-\-....\........................|........../.......|.........-.............................///........
-./.............\........./.................|.....................|..\.........../...|...|..\.|............-...
-.................................\.................................\...........................-.........-....
-...................\....|...............|...............\..../.....-.....................................\....
-.../.......-.......|...........|.....\..................../......................|../.............\...........
-......-....................../..../.....-..............\............./...........-.....\....|...............|.
-......-.......-.......|..................\................-...|./.......--............../.......|.-.|.........
--..................\........../..........................-...-.............../..............................|.
-............/.........-..../\..-.....\....\........./.........\.........../.........|/.../....................
-...................................../.....|..\.............\..........-.......\............../.............|.
-..../........./.........................-.......\..|..|.....--|...../.............../..-/./.......|..../|.....
-.........\.|...|..|-...................................\../..-.....|....\....................-................
-..../..-......|..............\../............./..........-.|......../..........|.\........|..................-
-......|.................\..........\|...................-.../.\.........../.|............../................./
-.........................\.................................../-./............................-..-.........|...
-\...........-................/....-.-....................................../...../..............\.......-\.../
--....................................\.........................../.............................|..............
-......................\............\.......\..\.............\.................-....\....-.|...................
-.|-.-...../......./............./........|.................../.........-..\........./.\|...........-..........
-.......\..............\.....-..........\.|.\...|.........\/..........\......|.\...................-...........
-../.-..................../..................|.....-...-....-............................-.-..-../..\..........
-.................................-.......|...../.\.................\..../................................-....
-.-..|.....|.........-........|....-.........../...........\......-..........................\.................
-..-.................../...|..-..|...................../...-...|........................................./|....
-.........................-\............................./....................../.......-................|.....
-........../.......\.........|...-/........./............./......................../...........-../..\.-\...-..
-..........|....\-........\...................-.|....-.........\................../.......\....................
-/...-..........\......\.........|.............|...........-....../.|..|..-............................|.......
-.........\.|........\......\....|.........................................../........-........................
-....\.....|.......\....................................-............|.....-........./..\...\.....-..-.........
-.|../...../.......|\......\............../....\..|..-...-..............|-.................................-...
-.|.\......./........../........|..\.../|......\....|.........-.|.................-.........|............./..-.
-...............-.................|...-../\..../.......-.....|..\./............................................
-....-.|.........-........................../...................|...............\........................../...
-..|/...-..../../...-........-...-.....................................\.......................................
-............../....|.....-\.....-................................../\.....................\....-........-.....
-......../.|.................|......|.|.-....|...................../....................................\......".to_string()
+    #[test]
+    fn part1_input() {
+        assert_eq!(8551, part1(&Day16::parse(&input(16)).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(51, part2(&Day16::parse(&example(16)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(8754, part2(&Day16::parse(&input(16)).unwrap()));
+    }
 }
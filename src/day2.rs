@@ -1,218 +1,386 @@
-use regex::Regex;
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
 
-#[derive(Debug)]
-struct ShowOfCubes {
-    red: i32,
-    blue: i32,
-    green: i32,
+use crate::{
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// The bag of part 1. `aoc run` keeps the puzzle's bag so that its answer can be checked; other
+/// bags are passed to `aoc explore day2 --bag`.
+const PART1_BAG: &str = "12 red, 13 green, 14 blue";
+
+/// A number of cubes of every colour, like a handful that the elf shows or the contents of a bag.
+/// Any word is a colour, and colours that aren't there have no cubes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
 }
 
-#[derive(Debug)]
-struct Game {
-    id: i32,
-    shows: Vec<ShowOfCubes>,
+impl Cubes {
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The total number of cubes.
+    pub fn len(&self) -> u32 {
+        self.counts.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether every cube of `self` could have come out of `bag`.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.counts
+            .iter()
+            .all(|(colour, &count)| count <= bag.count(colour))
+    }
+
+    /// The most cubes of every colour in either of both.
+    pub fn max(mut self, other: &Cubes) -> Cubes {
+        for (colour, &count) in &other.counts {
+            let most = self.counts.entry(colour.clone()).or_default();
+            *most = (*most).max(count);
+        }
+
+        self
+    }
+
+    /// The number of cubes of every colour multiplied together.
+    pub fn power(&self) -> u32 {
+        self.counts.values().product()
+    }
+
+    /// Adds cubes of a colour. No cubes at all leave the colour out, so cubes that only differ in
+    /// colours without any cubes are equal.
+    fn add(&mut self, colour: &str, count: u32) {
+        if count == 0 {
+            return;
+        }
+
+        *self.counts.entry(colour.to_string()).or_default() += count;
+    }
 }
 
-fn main() {
-    part1();
-    part2();
+impl Display for Cubes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.counts.is_empty() {
+            return write!(f, "no cubes");
+        }
+
+        let cubes: Vec<String> = self
+            .counts
+            .iter()
+            .map(|(colour, count)| format!("{count} {colour}"))
+            .collect();
+
+        write!(f, "{}", cubes.join(", "))
+    }
 }
 
-fn part2() {
-    let total_power = input()
-        .iter()
-        .map(|game| parse(game))
-        .map(|game| {
-            game.shows.iter().fold(
-                ShowOfCubes {
-                    red: 0,
-                    blue: 0,
-                    green: 0,
-                },
-                |mut acc, show| {
-                    if show.red > acc.red {
-                        acc.red = show.red;
-                    }
-
-                    if show.blue > acc.blue {
-                        acc.blue = show.blue;
-                    }
-
-                    if show.green > acc.green {
-                        acc.green = show.green;
-                    }
-
-                    return acc;
-                },
-            )
-        })
-        .map(|show| show.red * show.blue * show.green)
-        .fold(0, |acc, power| acc + power);
+/// Parses cubes the way they're written in the games, like `3 blue, 4 red`.
+impl FromStr for Cubes {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        parse_cubes(raw).map_err(|error| error.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub shows: Vec<Cubes>,
+}
+
+impl Game {
+    /// Whether every show of the game could have come out of the bag.
+    pub fn is_possible_with(&self, bag: &Cubes) -> bool {
+        self.shows.iter().all(|show| show.fits_in(bag))
+    }
 
-    println!("The answer to part 2 is: {}", total_power);
+    /// The fewest cubes of every colour the game could have been played with.
+    pub fn minimum_bag(&self) -> Cubes {
+        self.shows.iter().fold(Cubes::default(), Cubes::max)
+    }
 }
 
-fn part1() {
-    let max_green = 13;
-    let max_blue = 14;
-    let max_red = 12;
+pub struct Day2;
 
-    let reduced = input()
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parsing::lines(input).map(parse).collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Answer {
+        part1(games).into()
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Answer {
+        part2(games).into()
+    }
+}
+
+fn part1(games: &[Game]) -> u32 {
+    sum_of_possible_ids(games, &PART1_BAG.parse().unwrap())
+}
+
+fn part2(games: &[Game]) -> u32 {
+    games.iter().map(|game| game.minimum_bag().power()).sum()
+}
+
+/// The games that could have been played with the bag.
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Cubes) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|game| game.is_possible_with(bag))
+}
+
+/// The sum of the ids of the games that could have been played with the bag.
+pub fn sum_of_possible_ids(games: &[Game], bag: &Cubes) -> u32 {
+    possible_games(games, bag).map(|game| game.id).sum()
+}
+
+/// The bag with the fewest cubes that at least `k` of the games could have been played with, or
+/// `None` when there are fewer games than that. Of bags with as many cubes, the first one in the
+/// order of their colours wins.
+///
+/// The count of every colour but the last is tried for every count a game needs of it, and the
+/// last colour gets just enough cubes for the `k`th game that fits the others. That's quick for
+/// the three colours of the puzzle, but grows with the power of the number of colours.
+pub fn smallest_bag(games: &[Game], k: usize) -> Option<Cubes> {
+    if k > games.len() {
+        return None;
+    }
+
+    let needs: Vec<Cubes> = games.iter().map(Game::minimum_bag).collect();
+    let mut colours: Vec<&str> = needs
         .iter()
-        .map(|game| parse(game))
-        .filter(|game| {
-            game.shows
+        .flat_map(|need| need.counts.keys().map(String::as_str))
+        .collect();
+    colours.sort_unstable();
+    colours.dedup();
+
+    let Some((last, others)) = colours.split_last() else {
+        return Some(Cubes::default());
+    };
+
+    // Every combination of counts for the other colours, as the bags they make.
+    let bags = others.iter().fold(vec![Cubes::default()], |bags, &colour| {
+        let mut counts: Vec<u32> = needs.iter().map(|need| need.count(colour)).collect();
+        counts.sort_unstable();
+        counts.dedup();
+
+        bags.iter()
+            .flat_map(|bag| {
+                counts.iter().map(|&count| {
+                    let mut bag = bag.clone();
+                    bag.add(colour, count);
+                    bag
+                })
+            })
+            .collect()
+    });
+
+    bags.into_iter()
+        .filter_map(|mut bag| {
+            let mut lasts: Vec<u32> = needs
                 .iter()
-                .all(|show| show.green <= max_green && show.red <= max_red && show.blue <= max_blue)
+                .filter(|need| {
+                    others
+                        .iter()
+                        .all(|&other| need.count(other) <= bag.count(other))
+                })
+                .map(|need| need.count(last))
+                .collect();
+            lasts.sort_unstable();
+
+            let count = *lasts.get(k.checked_sub(1)?)?;
+            bag.add(last, count);
+
+            Some(bag)
         })
-        .fold(0, |acc, game| acc + game.id);
-
-    println!("The answer to part 1 is: {}", reduced);
-}
-
-fn parse(str: &str) -> Game {
-    let game_id = Regex::new(r"Game (\d+): .*")
-        .unwrap()
-        .captures(str)
-        .unwrap()
-        .get(1)
-        .map(|m| m.as_str())
-        .unwrap()
-        .parse()
-        .unwrap();
-
-    let (_, [shows_str]) = Regex::new(r"Game \d+: (.*)")
-        .unwrap()
-        .captures(str)
-        .unwrap()
-        .extract();
-
-    let shows: Vec<ShowOfCubes> = shows_str
-        .split(";")
-        .map(|unparsed_show| {
-            let green = Regex::new(r"(\d+) green")
-                .unwrap()
-                .captures(unparsed_show)
-                .map_or_else(|| 0, |capt| capt.get(1).unwrap().as_str().parse().unwrap());
-
-            let red = Regex::new(r"(\d+) red")
-                .unwrap()
-                .captures(unparsed_show)
-                .map_or_else(|| 0, |capt| capt.get(1).unwrap().as_str().parse().unwrap());
-
-            let blue = Regex::new(r"(\d+) blue")
-                .unwrap()
-                .captures(unparsed_show)
-                .map_or_else(|| 0, |capt| capt.get(1).unwrap().as_str().parse().unwrap());
-
-            ShowOfCubes { red, blue, green }
+        .min_by_key(|bag| {
+            let counts: Vec<u32> = colours.iter().map(|colour| bag.count(colour)).collect();
+            (bag.len(), counts)
         })
-        .collect();
+        .or_else(|| (k == 0).then(Cubes::default))
+}
+
+/// The answers to the queries of `aoc explore day2`: the games that are possible with `bag`, the
+/// smallest bag of the game with id `minimum_bag`, and the smallest bag that `smallest_bag_for`
+/// games are possible with.
+pub fn explore(
+    input: &str,
+    bag: Option<&Cubes>,
+    minimum_bag: Option<u32>,
+    smallest_bag_for: Option<usize>,
+) -> Result<String, String> {
+    let input = parsing::normalise(input);
+    let games = input.parse_in_day(Day2::DAY, Day2::parse)?;
+    let mut report = vec![];
+
+    if let Some(bag) = bag {
+        let possible: Vec<String> = possible_games(&games, bag)
+            .map(|game| game.id.to_string())
+            .collect();
+
+        report.push(format!(
+            "{} of {} games are possible with {bag}: {}",
+            possible.len(),
+            games.len(),
+            possible.join(", ")
+        ));
+        report.push(format!(
+            "The sum of their ids is {}",
+            sum_of_possible_ids(&games, bag)
+        ));
+    }
+
+    if let Some(id) = minimum_bag {
+        let game = games
+            .iter()
+            .find(|game| game.id == id)
+            .ok_or(format!("There is no game {id}"))?;
+
+        report.push(format!("Game {id} needs at least {}", game.minimum_bag()));
+    }
+
+    if let Some(k) = smallest_bag_for {
+        let bag = smallest_bag(&games, k).ok_or(format!("There are only {} games", games.len()))?;
+
+        report.push(format!(
+            "{k} games are possible with {bag}, {} cubes in total",
+            bag.len()
+        ));
+    }
+
+    Ok(report.join("\n"))
+}
+
+fn parse(line: &str) -> Result<Game, ParseError> {
+    let (game, shows) = parsing::split_once(line, ": ")?;
+    let id = parsing::number(parsing::strip_prefix(game, "Game ")?)?;
+    let shows = shows
+        .split(';')
+        .map(parse_cubes)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, shows })
+}
+
+/// Parses cubes like `3 blue, 4 red`, adding up the cubes of a colour that is named twice.
+fn parse_cubes(raw: &str) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::default();
+
+    for cube in raw.split(',') {
+        let (count, colour) = parsing::split_once(cube.trim(), " ")?;
+
+        if colour.trim().is_empty() {
+            return Err(ParseError::new(cube, "expected a colour after the number"));
+        }
+
+        cubes.add(colour.trim(), parsing::number(count)?);
+    }
+
+    Ok(cubes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(8, part1(&Day2::parse(&example(2)).unwrap()));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(2317, part1(&Day2::parse(&input(2)).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(2286, part2(&Day2::parse(&example(2)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(74804, part2(&Day2::parse(&input(2)).unwrap()));
+    }
+
+    fn cubes(raw: &str) -> Cubes {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_cubes_of_any_colour() {
+        let cubes = cubes("3 blue, 2 teal, 1 blue");
+
+        assert_eq!(
+            (4, 2, 0),
+            (cubes.count("blue"), cubes.count("teal"), cubes.count("red"))
+        );
+        assert_eq!("4 blue, 2 teal", cubes.to_string());
+        assert_eq!("no cubes", Cubes::default().to_string());
+        assert!("3 blue, red".parse::<Cubes>().is_err());
+        assert!("3 ".parse::<Cubes>().is_err());
+    }
+
+    #[test]
+    fn finds_the_possible_games_and_their_minimum_bags() {
+        let games = Day2::parse(&example(2)).unwrap();
+        let bag = cubes("12 red, 13 green, 14 blue");
+
+        let possible: Vec<u32> = possible_games(&games, &bag).map(|game| game.id).collect();
+        assert_eq!(vec![1, 2, 5], possible);
+        assert_eq!(8, sum_of_possible_ids(&games, &bag));
+        assert_eq!(
+            3,
+            sum_of_possible_ids(&games, &cubes("5 red, 5 green, 6 blue"))
+        );
+
+        assert_eq!(cubes("4 red, 2 green, 6 blue"), games[0].minimum_bag());
+        assert!(games[0].is_possible_with(&games[0].minimum_bag()));
+    }
+
+    #[test]
+    fn finds_the_smallest_bag_for_some_games() {
+        let games = Day2::parse(&example(2)).unwrap();
+
+        assert_eq!(Some(Cubes::default()), smallest_bag(&games, 0));
+        assert_eq!(
+            Some(cubes("1 red, 3 green, 4 blue")),
+            smallest_bag(&games, 1)
+        );
+        // Games 1 and 2 need as many cubes as games 2 and 5, which need fewer blue ones.
+        assert_eq!(
+            Some(cubes("6 red, 3 green, 4 blue")),
+            smallest_bag(&games, 2)
+        );
+        assert_eq!(
+            Some(cubes("6 red, 3 green, 6 blue")),
+            smallest_bag(&games, 3)
+        );
+        assert_eq!(
+            Some(cubes("20 red, 13 green, 15 blue")),
+            smallest_bag(&games, 5)
+        );
+        assert_eq!(None, smallest_bag(&games, 6));
+    }
+
+    #[test]
+    fn leaves_colours_a_game_does_not_need_out_of_the_smallest_bag() {
+        let games = Day2::parse("Game 1: 1 green\nGame 2: 2 red, 2 green").unwrap();
+        let bag = smallest_bag(&games, 1).unwrap();
 
-    return Game { id: game_id, shows };
-}
-
-fn input() -> Vec<&'static str> {
-    return vec!(
-        "Game 1: 1 green, 6 red, 4 blue; 2 blue, 6 green, 7 red; 3 red, 4 blue, 6 green; 3 green; 3 blue, 2 green, 1 red",
-        "Game 2: 2 blue, 4 red, 7 green; 17 red, 3 blue, 2 green; 3 green, 14 red, 1 blue",
-        "Game 3: 12 blue, 3 red, 1 green; 8 blue, 9 red; 1 blue, 1 green, 9 red; 4 blue, 1 green, 9 red",
-        "Game 4: 2 red, 10 green, 5 blue; 11 blue, 4 green; 6 green, 7 blue, 2 red; 4 blue, 9 green; 6 green, 1 red, 5 blue",
-        "Game 5: 10 green, 5 blue, 5 red; 10 blue, 13 green; 2 red, 12 blue; 9 green, 9 red",
-        "Game 6: 2 red, 3 green; 1 blue, 15 red, 2 green; 1 green, 7 red",
-        "Game 7: 16 blue, 4 green, 9 red; 6 red, 2 blue, 12 green; 2 red, 5 green, 14 blue; 11 blue, 13 red; 10 blue, 3 red, 17 green; 1 green, 12 blue",
-        "Game 8: 14 red, 12 green, 1 blue; 5 blue, 7 green, 12 red; 8 green, 1 red, 8 blue; 8 blue, 2 green, 15 red; 9 blue, 12 red, 10 green; 4 blue, 15 red, 1 green",
-        "Game 9: 2 red, 7 green, 5 blue; 1 red, 5 blue, 13 green; 5 blue",
-        "Game 10: 4 red, 1 green, 4 blue; 7 green, 8 blue, 4 red; 9 green, 3 red, 8 blue; 5 red, 2 green, 7 blue",
-        "Game 11: 4 green, 1 blue, 1 red; 3 green, 3 red, 1 blue; 3 green, 1 red, 1 blue",
-        "Game 12: 7 red, 6 green, 12 blue; 6 blue, 8 green, 3 red; 12 green, 5 blue, 4 red; 3 red, 16 blue, 8 green; 12 red, 11 green, 6 blue",
-        "Game 13: 2 green, 5 red, 12 blue; 8 green, 12 red, 4 blue; 6 blue, 7 green, 13 red",
-        "Game 14: 1 blue, 7 green, 5 red; 1 blue, 8 green, 6 red; 3 green, 1 blue, 4 red",
-        "Game 15: 11 red, 8 blue, 1 green; 11 red, 1 green; 3 green, 8 red, 2 blue; 4 blue, 11 red, 1 green; 5 blue, 5 red, 2 green",
-        "Game 16: 18 green, 4 blue, 2 red; 5 blue, 11 green, 10 red; 8 red, 2 blue, 14 green; 8 red, 7 blue, 1 green; 3 red, 5 blue, 17 green; 6 blue, 5 green, 11 red",
-        "Game 17: 3 blue, 3 red, 7 green; 4 blue, 1 red, 2 green; 5 blue, 3 green, 3 red",
-        "Game 18: 2 blue, 2 red, 1 green; 4 blue, 2 red, 7 green; 10 blue, 4 red, 3 green; 5 blue, 3 red, 2 green; 4 green, 3 red, 4 blue; 3 green, 5 red, 5 blue",
-        "Game 19: 2 red, 1 green, 1 blue; 8 red, 8 blue, 10 green; 16 green, 5 blue, 2 red; 4 red, 9 green",
-        "Game 20: 12 red, 1 blue, 1 green; 4 blue, 2 green, 2 red; 3 blue; 5 red, 8 green; 14 red, 4 blue, 6 green",
-        "Game 21: 9 red, 7 green, 1 blue; 5 green, 17 red, 11 blue; 14 red, 7 blue, 10 green; 7 green, 7 red, 10 blue; 6 blue, 6 green, 17 red; 16 red, 13 green, 7 blue",
-        "Game 22: 4 blue, 1 red; 1 green, 8 blue; 1 green; 6 blue, 1 red",
-        "Game 23: 13 red, 7 blue, 1 green; 4 green, 2 blue, 7 red; 4 green, 10 blue, 12 red",
-        "Game 24: 9 green, 10 blue; 2 blue, 4 green, 4 red; 9 green, 1 red, 9 blue; 4 green, 5 red, 12 blue",
-        "Game 25: 4 red, 1 green; 10 green, 6 red, 4 blue; 4 red, 1 blue, 7 green; 10 green, 3 red, 7 blue",
-        "Game 26: 8 red, 1 green, 2 blue; 5 green, 5 red; 6 green, 19 red; 11 red, 2 blue, 8 green; 13 red, 2 blue, 5 green; 15 red, 2 blue, 10 green",
-        "Game 27: 17 blue, 1 green; 2 red, 12 blue, 11 green; 16 green, 16 blue; 18 green, 4 blue; 10 blue, 1 red, 8 green",
-        "Game 28: 5 red, 1 green, 1 blue; 3 blue, 8 green, 4 red; 6 green, 2 red, 2 blue",
-        "Game 29: 3 green, 12 red, 11 blue; 2 green, 15 red, 8 blue; 13 red, 4 green; 17 red, 9 blue, 5 green",
-        "Game 30: 10 green; 4 blue, 1 green; 2 blue, 2 red, 7 green; 5 green, 4 blue, 1 red; 4 red, 10 green, 1 blue",
-        "Game 31: 15 blue, 2 red; 17 blue, 2 green; 19 blue, 6 red",
-        "Game 32: 1 green, 7 red; 8 red, 1 blue; 5 red, 1 blue, 11 green; 3 blue, 17 red",
-        "Game 33: 11 red, 9 green, 1 blue; 3 green, 8 blue; 10 red, 4 green, 8 blue; 6 red, 9 blue, 17 green; 15 green, 10 red, 4 blue; 1 red, 2 blue, 7 green",
-        "Game 34: 13 red, 6 green; 6 red, 14 green, 2 blue; 3 red, 19 green; 9 green, 9 red",
-        "Game 35: 7 green, 3 red; 12 green, 7 blue; 13 green, 7 red, 6 blue; 3 blue, 12 red",
-        "Game 36: 6 blue, 11 green, 14 red; 3 blue, 12 green, 4 red; 18 red, 1 blue; 7 red, 9 green, 6 blue",
-        "Game 37: 3 red, 16 blue, 6 green; 2 green, 7 blue; 8 blue, 3 red",
-        "Game 38: 16 blue, 3 green, 14 red; 8 red, 15 blue; 17 red, 15 blue, 4 green; 1 green, 11 blue, 17 red; 3 green, 10 blue, 17 red",
-        "Game 39: 1 green, 2 red, 5 blue; 12 blue, 12 green; 3 blue, 1 red",
-        "Game 40: 1 red, 2 blue, 1 green; 7 green, 1 red, 6 blue; 8 blue, 1 red, 6 green; 12 blue, 1 red, 3 green; 4 green, 8 blue",
-        "Game 41: 2 red, 2 blue, 5 green; 5 red, 8 blue; 4 green, 4 blue; 1 red, 11 blue",
-        "Game 42: 1 red, 3 green, 13 blue; 13 blue, 7 green; 13 green; 1 red, 3 blue, 4 green; 13 blue, 7 green",
-        "Game 43: 3 red, 4 green; 7 red, 11 blue, 3 green; 3 green, 12 red, 7 blue; 9 blue, 5 green",
-        "Game 44: 4 blue, 9 red, 2 green; 10 blue, 5 red, 2 green; 9 red, 9 blue, 1 green; 8 blue, 2 green, 14 red; 3 blue, 3 green, 6 red; 4 blue, 3 green, 14 red",
-        "Game 45: 1 red, 2 green, 2 blue; 2 green, 1 red; 1 green, 2 blue; 1 green, 1 red, 2 blue; 2 red, 2 blue, 1 green",
-        "Game 46: 1 green, 3 red, 3 blue; 6 green, 2 blue, 4 red; 1 green, 3 blue, 1 red; 3 green, 1 blue, 5 red; 6 green; 1 red, 1 green, 2 blue",
-        "Game 47: 18 green, 1 red, 7 blue; 6 blue, 19 green, 1 red; 5 blue, 7 green, 1 red; 1 red, 5 blue, 16 green; 15 green, 3 blue",
-        "Game 48: 4 green, 8 blue, 8 red; 13 green, 5 red, 12 blue; 9 red, 6 blue, 10 green; 18 green, 3 blue, 4 red; 2 blue, 9 red, 8 green",
-        "Game 49: 9 blue, 5 red, 9 green; 5 blue, 11 green, 5 red; 12 green, 6 blue",
-        "Game 50: 13 red, 8 green, 3 blue; 2 red, 11 green, 3 blue; 16 red, 7 green; 3 blue, 11 green, 15 red; 10 red, 2 blue, 5 green; 7 green, 2 blue, 4 red",
-        "Game 51: 2 red, 1 green, 3 blue; 2 green, 11 red, 17 blue; 2 red, 3 green, 6 blue; 4 red, 3 green, 6 blue; 13 red, 12 blue",
-        "Game 52: 1 blue, 5 green; 20 green, 6 blue; 9 blue, 6 green; 11 green, 1 red; 1 green, 1 red, 1 blue",
-        "Game 53: 8 red, 6 blue; 6 blue, 6 red, 2 green; 5 blue, 2 green, 3 red; 3 green, 3 blue; 4 green, 5 red, 1 blue",
-        "Game 54: 4 blue, 1 red, 3 green; 4 green, 10 blue, 9 red; 7 red, 3 blue, 3 green; 9 green, 9 red, 1 blue; 9 blue, 6 red, 7 green; 6 blue, 7 green, 9 red",
-        "Game 55: 15 red, 1 blue, 6 green; 11 blue, 3 red; 9 blue, 3 red, 1 green",
-        "Game 56: 8 green, 8 red, 9 blue; 8 red, 8 green, 1 blue; 7 red, 10 green, 4 blue; 10 blue, 2 green, 9 red",
-        "Game 57: 10 red, 3 green, 2 blue; 1 red, 4 green; 7 red, 1 green, 3 blue; 12 red, 4 blue; 14 red, 5 green, 4 blue",
-        "Game 58: 8 green, 3 blue, 7 red; 7 red, 14 blue, 5 green; 3 green, 7 red; 16 blue, 15 green; 1 red, 10 blue",
-        "Game 59: 3 red, 13 green, 2 blue; 10 blue, 3 green, 6 red; 3 green, 2 blue; 7 green, 2 blue, 7 red; 17 green, 6 blue, 15 red",
-        "Game 60: 2 blue, 2 red, 6 green; 11 green, 1 blue, 2 red; 1 blue, 9 green; 1 red, 4 green, 2 blue; 1 red, 2 blue, 10 green",
-        "Game 61: 3 red, 12 blue, 1 green; 3 red, 1 green, 18 blue; 5 blue, 2 red",
-        "Game 62: 4 red, 3 blue, 8 green; 2 blue, 8 red, 9 green; 8 blue, 15 green, 1 red",
-        "Game 63: 14 green, 2 red, 1 blue; 7 green, 11 blue, 1 red; 7 blue, 3 red; 4 green, 10 blue, 3 red",
-        "Game 64: 8 blue, 18 green, 2 red; 3 red, 17 green; 7 green, 1 red, 12 blue; 15 green, 2 red, 4 blue; 7 green, 8 red, 13 blue",
-        "Game 65: 6 blue, 5 green, 2 red; 1 red, 4 green; 5 green, 1 blue; 6 blue, 3 red, 2 green; 4 blue, 5 green",
-        "Game 66: 11 red, 9 blue, 4 green; 8 red, 8 blue; 9 red, 7 blue; 1 blue, 12 green, 4 red; 2 red, 11 blue, 10 green",
-        "Game 67: 1 red, 4 blue, 1 green; 7 red, 1 blue; 3 green, 4 blue, 6 red; 6 green, 3 blue, 14 red; 11 red, 1 blue, 1 green; 4 green, 8 red",
-        "Game 68: 3 red, 1 green, 2 blue; 1 red, 9 blue; 2 red, 1 green",
-        "Game 69: 3 green, 2 blue, 2 red; 1 red, 6 green; 13 red, 2 blue, 4 green; 4 blue, 13 red, 6 green; 12 red, 2 blue",
-        "Game 70: 15 blue, 2 green, 7 red; 3 red, 14 blue; 6 blue, 1 green; 1 red, 2 green, 4 blue; 2 green, 13 red; 12 blue, 3 red",
-        "Game 71: 7 red, 3 blue; 1 red, 4 blue; 2 red, 5 green, 1 blue; 6 blue, 8 red, 1 green; 3 green, 7 blue, 8 red",
-        "Game 72: 7 green; 4 green, 2 red, 8 blue; 1 blue, 5 green",
-        "Game 73: 5 red, 5 green, 2 blue; 8 red, 1 blue, 8 green; 1 red, 3 blue, 7 green",
-        "Game 74: 17 green, 9 blue, 4 red; 20 green, 2 red, 7 blue; 7 blue, 2 green, 4 red; 2 blue, 5 red, 20 green; 1 blue, 1 red, 12 green; 19 green, 9 blue, 3 red",
-        "Game 75: 1 red, 8 green, 9 blue; 7 blue, 3 green, 1 red; 2 green, 1 red, 9 blue; 5 blue, 1 red, 8 green; 2 green, 1 red, 11 blue; 5 green, 1 red",
-        "Game 76: 3 blue, 16 green, 2 red; 10 green, 3 blue, 1 red; 6 blue, 14 red, 13 green; 7 red, 2 green, 13 blue",
-        "Game 77: 7 red, 14 green; 1 blue, 1 red; 4 red, 1 green; 7 green, 11 red",
-        "Game 78: 1 red, 19 green; 10 green, 14 red, 1 blue; 3 green, 3 blue, 11 red; 7 blue, 1 green; 15 red, 3 green, 4 blue",
-        "Game 79: 7 red, 7 green, 6 blue; 3 red, 7 green, 5 blue; 7 red, 8 green, 12 blue",
-        "Game 80: 15 red, 6 blue; 1 red, 5 green, 2 blue; 1 green, 3 blue",
-        "Game 81: 3 red, 7 blue, 7 green; 7 green, 2 blue, 4 red; 3 green, 5 blue; 9 blue, 3 red, 6 green; 6 green, 1 red, 3 blue; 8 blue, 2 green, 1 red",
-        "Game 82: 5 red, 13 green; 3 blue, 13 green; 6 blue, 4 red, 10 green; 5 red, 1 green, 4 blue; 1 blue, 8 red; 4 red, 5 green",
-        "Game 83: 17 red, 1 blue, 2 green; 3 green, 3 red, 2 blue; 1 red, 5 blue, 10 green; 4 blue, 9 red, 11 green",
-        "Game 84: 13 green, 14 red, 12 blue; 14 blue, 2 red, 1 green; 4 blue, 8 red",
-        "Game 85: 3 red, 1 blue; 6 red, 3 blue, 2 green; 5 green, 3 blue, 3 red; 3 green, 5 blue, 1 red; 1 blue, 12 red, 2 green",
-        "Game 86: 16 blue, 17 green, 7 red; 14 blue, 13 green; 18 blue, 8 green",
-        "Game 87: 1 blue, 1 red; 4 blue, 1 green, 4 red; 1 green, 16 red; 1 green, 12 red, 1 blue",
-        "Game 88: 1 red, 6 green; 3 red, 2 blue, 19 green; 11 green, 2 red; 5 blue, 5 green; 5 blue, 9 green, 1 red; 2 blue, 2 red, 4 green",
-        "Game 89: 4 green, 11 red; 8 blue, 14 red; 14 blue, 8 green, 9 red; 14 green, 15 red, 10 blue",
-        "Game 90: 8 green, 2 red, 1 blue; 11 green, 4 blue, 2 red; 7 green, 2 blue; 13 green, 1 red",
-        "Game 91: 1 blue, 3 green; 1 blue; 4 green, 1 blue, 1 red; 1 blue, 2 red; 1 green, 2 red; 2 red, 5 green, 2 blue",
-        "Game 92: 16 red, 4 green, 5 blue; 9 blue, 13 green, 5 red; 13 red, 11 green, 7 blue; 11 red, 8 green, 2 blue",
-        "Game 93: 4 blue, 3 red, 3 green; 4 blue, 2 red, 1 green; 1 green, 2 red, 2 blue; 1 green, 2 red, 2 blue; 4 green, 1 blue",
-        "Game 94: 8 blue, 11 red, 7 green; 8 red, 6 green; 15 blue, 11 green, 2 red; 9 green, 6 red; 16 blue, 5 red, 7 green",
-        "Game 95: 13 blue, 1 red, 10 green; 11 green, 9 blue; 6 blue",
-        "Game 96: 1 green, 6 red; 1 red; 12 red, 1 green; 6 red, 1 blue",
-        "Game 97: 1 red, 9 blue, 8 green; 2 green, 6 blue, 1 red; 6 green, 1 blue",
-        "Game 98: 9 blue, 7 green, 8 red; 6 red, 11 blue, 4 green; 11 green, 9 blue, 15 red; 11 red, 6 blue, 16 green",
-        "Game 99: 2 blue, 1 red, 9 green; 8 red, 1 blue, 1 green; 2 red, 7 green, 8 blue; 1 red, 5 green, 7 blue; 7 blue, 10 green, 9 red; 1 green, 1 blue, 1 red",
-        "Game 100: 3 blue, 6 red, 9 green; 4 red, 3 green; 4 green, 16 red, 1 blue; 14 blue, 1 green",
-    );
+        assert_eq!(cubes("1 green"), bag);
+        assert_eq!("1 green", bag.to_string());
+        assert_eq!(cubes("1 green"), cubes("1 green, 0 red"));
+    }
 }
@@ -1,7 +1,16 @@
 use std::{collections::HashMap, fmt::Display, hash::Hash};
 
+use log::trace;
+
 use regex::Regex;
 
+use crate::{
+    cancel::Token,
+    parsing::{self, ParseError},
+    progress::Progress,
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Pulse {
     Low,
@@ -26,7 +35,7 @@ impl Hash for ModuleType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Module {
+pub struct Module {
     name: String,
     module_type: ModuleType,
     outputs_to: Vec<String>,
@@ -64,9 +73,9 @@ impl Module {
                     .collect()
             }
             ModuleType::Conjunction(ref mut memory) => {
-                memory
-                    .get_mut(&signal.from)
-                    .and_then(|pulse| Some(*pulse = signal.pulse));
+                if let Some(pulse) = memory.get_mut(&signal.from) {
+                    *pulse = signal.pulse;
+                }
 
                 let pulse = match memory.iter().all(|(_, pulse)| *pulse == Pulse::High) {
                     true => Pulse::Low,
@@ -99,16 +108,28 @@ impl Display for Signal {
     }
 }
 
-fn main() {
-    assert_eq!(32_000_000, part1(&test_input()));
-    assert_eq!(11_687_500, part1(&test_input_2()));
-    assert_eq!(898_557_000, part1(&input()));
-    assert_eq!(0, part2(&test_input()));
-    assert_eq!(0, part2(&input()));
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = Vec<Module>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(modules: &Self::Input<'_>) -> Answer {
+        part1(modules).into()
+    }
+
+    fn part2(modules: &Self::Input<'_>) -> Answer {
+        part2(modules).into()
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let mut modules = parse(input);
+fn part1(modules: &[Module]) -> usize {
+    let mut modules = modules.to_vec();
 
     let (mut low_pulse_count, mut high_pulse_count) = (0, 0);
 
@@ -120,9 +141,9 @@ fn part1(input: &str) -> usize {
         }];
 
         while !signals.is_empty() {
-            // for signal in &signals {
-            //     println!("{}", signal);
-            // }
+            for signal in &signals {
+                trace!("{signal}");
+            }
 
             low_pulse_count += signals
                 .iter()
@@ -153,8 +174,8 @@ fn part1(input: &str) -> usize {
     low_pulse_count * high_pulse_count
 }
 
-fn part2(input: &str) -> usize {
-    let mut modules: HashMap<String, Module> = parse(input)
+fn part2(modules: &[Module]) -> usize {
+    let mut modules: HashMap<String, Module> = modules
         .iter()
         .map(|module| (module.name.to_owned(), module.to_owned()))
         .collect();
@@ -162,8 +183,14 @@ fn part2(input: &str) -> usize {
     let mut cache: HashMap<(Signal, Module), Vec<Signal>> = HashMap::new();
 
     let mut number_of_button_presses = 0;
+    let token = Token::current();
+    let progress = Progress::unbounded("button presses");
 
     loop {
+        if token.is_cancelled() {
+            return number_of_button_presses;
+        }
+
         // This is a single button press
         let mut signals: Vec<Signal> = vec![Signal {
             from: "button".to_string(),
@@ -172,15 +199,12 @@ fn part2(input: &str) -> usize {
         }];
 
         number_of_button_presses += 1;
-
-        if number_of_button_presses % 1_000_000 == 0 {
-            println!("We have performed {number_of_button_presses} button presses");
-        }
+        progress.advance(1);
 
         while !signals.is_empty() {
-            // for signal in &signals {
-            //     println!("{}", signal);
-            // }
+            for signal in &signals {
+                trace!("{signal}");
+            }
 
             let mut next_signals: Vec<Signal> = vec![];
 
@@ -208,155 +232,94 @@ fn part2(input: &str) -> usize {
     }
 }
 
-fn parse(input: &str) -> Vec<Module> {
-    let re = Regex::new(r"(?:(&|%)(\w+)) -> (.*)").unwrap();
-    let broadcaster_re = Regex::new(r"broadcaster -> (.*)").unwrap();
-
-    let conjunction_mapping = input
-        .split("\n")
-        .map(|line| -> (String, Vec<String>) {
-            if line.contains("broadcaster") {
-                let (_, [raw_outputs_to]) = broadcaster_re.captures(line).unwrap().extract();
-
-                return (
-                    "broadcaster".to_string(),
-                    raw_outputs_to
-                        .split(", ")
-                        .map(|name| name.to_string())
-                        .collect(),
-                );
-            } else {
-                let (_, [_, name, raw_outputs_to]) = re.captures(line).unwrap().extract();
-                return (
-                    name.to_string(),
-                    raw_outputs_to
-                        .split(", ")
-                        .map(|name| name.to_string())
-                        .collect(),
-                );
-            }
-        })
-        .collect::<Vec<(String, Vec<String>)>>();
+fn parse(input: &str) -> Result<Vec<Module>, ParseError> {
+    let re = Regex::new(r"^(broadcaster|[%&]\w+) -> (\w+(?:, \w+)*)$").unwrap();
 
-    input
-        .split("\n")
+    let lines = parsing::lines(input)
         .map(|line| {
-            if line.contains("broadcaster") {
-                let (_, [raw_outputs_to]) = broadcaster_re.captures(line).unwrap().extract();
-
-                Module {
-                    name: "broadcaster".to_string(),
-                    module_type: ModuleType::Broadcast,
-                    outputs_to: raw_outputs_to
-                        .split(", ")
-                        .map(|name| name.to_string())
-                        .collect::<Vec<String>>(),
-                }
-            } else {
-                let (_, [raw_type, name, raw_outputs_to]) = re.captures(line).unwrap().extract();
-
-                Module {
-                    name: name.to_string(),
-                    module_type: match raw_type {
-                        "%" => ModuleType::FlipFlop(false),
-                        "&" => ModuleType::Conjunction(
-                            conjunction_mapping
-                                .iter()
-                                .filter(|(_, to)| to.contains(&name.to_string()))
-                                .map(|(from, _)| (from.to_owned(), Pulse::Low))
-                                .collect(),
-                        ),
-                        other => panic!("Cannot parse {other} into ModuleType"),
-                    },
-                    outputs_to: raw_outputs_to
-                        .split(", ")
-                        .map(|name| name.to_string())
-                        .collect::<Vec<String>>(),
-                }
+            let (_, [label, raw_outputs_to]) = parsing::captures(&re, line)?.extract();
+
+            let outputs_to = raw_outputs_to
+                .split(", ")
+                .map(|name| name.to_string())
+                .collect::<Vec<String>>();
+
+            Ok((label, outputs_to))
+        })
+        .collect::<Result<Vec<(&str, Vec<String>)>, ParseError>>()?;
+
+    let modules = lines
+        .iter()
+        .map(|(label, outputs_to)| {
+            let (module_type, name) = match label.split_at(1) {
+                ("%", name) => (ModuleType::FlipFlop(false), name),
+                ("&", name) => (
+                    ModuleType::Conjunction(
+                        lines
+                            .iter()
+                            .filter(|(_, to)| to.iter().any(|to| to == name))
+                            .map(|(from, _)| {
+                                (from.trim_start_matches(['%', '&']).to_owned(), Pulse::Low)
+                            })
+                            .collect(),
+                    ),
+                    name,
+                ),
+                _ => (ModuleType::Broadcast, *label),
+            };
+
+            Module {
+                name: name.to_string(),
+                module_type,
+                outputs_to: outputs_to.clone(),
             }
         })
-        .collect::<Vec<Module>>()
-}
+        .collect();
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a"
-        .to_string()
+    Ok(modules)
 }
 
-#[allow(dead_code)]
-fn test_input_2() -> String {
-    "broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output"
-        .to_string()
-}
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        solution::{Failure, Part, Puzzle},
+        testing::{example, input, second_example, unknown},
+    };
 
-#[allow(dead_code)]
-fn input() -> String {
-    "&kv -> qb
-%px -> qz, tk
-%xk -> sv, zv
-%rj -> lx, qz
-%ks -> fc
-%dx -> gt, dr
-%lz -> qz, df
-%dz -> fr
-broadcaster -> cn, xk, rj, gf
-%ct -> ks
-%hq -> bz
-%qv -> cx
-&qz -> vk, qm, rj, kv, hq, tk
-&jg -> qb
-%cf -> sv, tz
-&dr -> cn, jz, tq, ks, mr, ct
-%mx -> bn
-%bv -> sk, kf
-%cn -> dr, mq
-%vk -> lz
-%jd -> qz
-&qb -> rx
-%tp -> sv, lm
-%jz -> ct
-%tq -> tj
-%bn -> sv, cf
-%br -> sk, hc
-%gt -> dr, nd
-%nd -> dr, nk
-&rz -> qb
-%lx -> qm, qz
-&sk -> qv, kf, rd, qh, jg, gf
-%mq -> jz, dr
-%rl -> bv, sk
-%tz -> sv, ng
-%df -> qz, jd
-%tk -> hq
-&mr -> qb
-%gf -> rl, sk
-%qm -> nt
-&sv -> xk, rz, zv, dz, mx
-%hc -> sk, nf
-%xp -> br, sk
-%bc -> sv, tp
-%fc -> dr, tq
-%nf -> sk
-%cx -> sk, qh
-%bz -> vk, qz
-%zv -> dz
-%kf -> rd
-%tj -> dr, dx
-%fr -> mx, sv
-%ng -> bc, sv
-%lm -> sv
-%nk -> dr
-%nt -> qz, px
-%qh -> xp
-%rd -> qv"
-        .to_string()
+    #[test]
+    fn part1_example() {
+        assert_eq!(32_000_000, part1(&Day20::parse(&example(20)).unwrap()));
+    }
+
+    #[test]
+    fn part1_second_example() {
+        assert_eq!(
+            11_687_500,
+            part1(&Day20::parse(&second_example(20)).unwrap())
+        );
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(898_557_000, part1(&Day20::parse(&input(20)).unwrap()));
+    }
+
+    #[test]
+    #[ignore = "answer unknown, never terminates"]
+    fn part2_input() {
+        unknown(part2(&Day20::parse(&input(20)).unwrap()));
+    }
+
+    #[test]
+    fn part2_times_out_without_an_rx_module() {
+        let budget = Duration::from_millis(50);
+        let outcomes = Day20
+            .solve(&example(20), &[Part::Two], Some(budget))
+            .unwrap();
+
+        assert_eq!(Err(Failure::TimedOut(budget)), outcomes[0].result);
+    }
 }
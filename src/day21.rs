@@ -1,5 +1,15 @@
 use std::collections::HashSet;
 
+use crate::{
+    animation::{Recorder, Recording},
+    cancel::Token,
+    geometry::Point2,
+    grid::Grid,
+    parsing::ParseError,
+    render::{Picture, Rgb},
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum SquareType {
     Start,
@@ -7,146 +17,107 @@ enum SquareType {
     Rocks,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-}
+type Point = Point2<usize>;
 
-impl Into<VirtualPoint> for Point {
-    fn into(self) -> VirtualPoint {
-        VirtualPoint {
-            x: self.x as i64,
-            y: self.y as i64,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct VirtualPoint {
-    x: i64,
-    y: i64,
-}
+/// A point on the infinite map that repeats the garden in every direction.
+type VirtualPoint = Point2<i64>;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Garden {
-    squares: Vec<Vec<SquareType>>,
-    width: usize,
-    height: usize,
+pub struct Garden {
+    squares: Grid<SquareType>,
     start: Point,
 }
 
 impl Garden {
     fn get_bounded_neighbors(&self, point: Point) -> Vec<Point> {
-        let mut neighbors = vec![];
-
-        if point.x > 0 {
-            neighbors.push(Point {
-                x: point.x - 1,
-                y: point.y,
-            });
-        }
-
-        if point.x < self.width - 1 {
-            neighbors.push(Point {
-                x: point.x + 1,
-                y: point.y,
-            });
-        }
-
-        if point.y > 0 {
-            neighbors.push(Point {
-                x: point.x,
-                y: point.y - 1,
-            });
-        }
-
-        if point.y < self.height - 1 {
-            neighbors.push(Point {
-                x: point.x,
-                y: point.y + 1,
-            });
-        }
-
-        neighbors
+        self.squares
+            .neighbours4(point.into())
+            .map(Point::from)
+            .collect()
     }
 
     fn get_square_type(&self, point: VirtualPoint) -> SquareType {
-        let normalized = Point {
-            x: point.x.rem_euclid(self.width as i64) as usize,
-            y: point.y.rem_euclid(self.height as i64) as usize,
-        };
+        let normalized = (
+            point.x.rem_euclid(self.squares.width() as i64) as usize,
+            point.y.rem_euclid(self.squares.height() as i64) as usize,
+        );
 
-        self.squares[normalized.y][normalized.x]
+        self.squares[normalized]
     }
 
-    fn get_virtual_neighbors(&self, point: VirtualPoint) -> Vec<VirtualPoint> {
-        let mut neighbors = vec![];
+    fn picture(&self, destinations: &HashSet<Point>) -> Picture {
+        Picture::of_grid(&self.squares, |square| match square {
+            SquareType::Rocks => Rgb::GREY,
+            _ => Rgb::BLACK,
+        })
+        .highlight(destinations.iter().map(|&point| point.into()), Rgb::GREEN)
+    }
+}
 
-        neighbors.push(VirtualPoint {
-            x: point.x - 1,
-            y: point.y,
-        });
+pub struct Day21;
 
-        neighbors.push(VirtualPoint {
-            x: point.x + 1,
-            y: point.y,
-        });
+impl Solution for Day21 {
+    const DAY: u8 = 21;
 
-        neighbors.push(VirtualPoint {
-            x: point.x,
-            y: point.y - 1,
-        });
+    type Input<'a> = Garden;
 
-        neighbors.push(VirtualPoint {
-            x: point.x,
-            y: point.y + 1,
-        });
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
 
-        neighbors
+    fn part1(garden: &Self::Input<'_>) -> Answer {
+        part1(garden, 64, &mut ()).into()
     }
-}
 
-fn main() {
-    assert_eq!(16, part1(&test_input(), 6));
-    assert_eq!(3697, part1(&input(), 64));
-    assert_eq!(0, part2(&input(), 26_501_365));
-}
+    fn part2(garden: &Self::Input<'_>) -> Answer {
+        part2(garden, 26_501_365).into()
+    }
 
-fn part1(input: &str, steps: usize) -> usize {
-    let garden = parse(input);
+    fn animate(garden: &Self::Input<'_>, recording: &mut Recording) -> bool {
+        part1(garden, 64, recording);
+
+        true
+    }
+}
 
+/// Every step the elf takes is a step of the recording.
+fn part1(garden: &Garden, steps: usize, recorder: &mut impl Recorder) -> usize {
     let mut destinations: HashSet<Point> = HashSet::new();
     destinations.insert(garden.start);
+    recorder.record(|| garden.picture(&destinations));
 
     for _ in 0..steps {
         let mut next_destinations: HashSet<Point> = HashSet::new();
 
         for destination in destinations.iter() {
             for neighbor in garden.get_bounded_neighbors(*destination) {
-                if garden.get_square_type(neighbor.into()) != SquareType::Rocks {
+                if garden.get_square_type(virtual_point(neighbor)) != SquareType::Rocks {
                     next_destinations.insert(neighbor);
                 }
             }
         }
 
         destinations = next_destinations;
+        recorder.record(|| garden.picture(&destinations));
     }
 
     destinations.len()
 }
 
-fn part2(input: &str, steps: usize) -> usize {
-    let garden = parse(input);
-
+fn part2(garden: &Garden, steps: usize) -> usize {
     let mut destinations: HashSet<VirtualPoint> = HashSet::new();
-    destinations.insert(garden.start.into());
+    destinations.insert(virtual_point(garden.start));
+    let token = Token::current();
+
+    for _ in 0..steps {
+        if token.is_cancelled() {
+            break;
+        }
 
-    for i in 0..steps {
         let mut next_destinations: HashSet<VirtualPoint> = HashSet::new();
 
         for destination in destinations.iter() {
-            for neighbor in garden.get_virtual_neighbors(*destination) {
+            for neighbor in destination.neighbours() {
                 if garden.get_square_type(neighbor) != SquareType::Rocks {
                     next_destinations.insert(neighbor);
                 }
@@ -159,185 +130,59 @@ fn part2(input: &str, steps: usize) -> usize {
     destinations.len()
 }
 
-fn parse(input: &str) -> Garden {
-    let squares: Vec<Vec<SquareType>> = input
-        .split("\n")
-        .map(|line| {
-            line.chars()
-                .map(|char| match char {
-                    'S' => SquareType::Start,
-                    '.' => SquareType::Garden,
-                    '#' => SquareType::Rocks,
-                    other => panic!("'{other}' cannot be parsed into a SquareType"),
-                })
-                .collect::<Vec<SquareType>>()
-        })
-        .collect();
-
-    let width = squares[0].len();
-    let height = squares.len();
-
-    let start = (0..height)
-        .flat_map(|y| (0..width).map(move |x| (x, y)))
-        .find(|&(x, y)| squares[y][x] == SquareType::Start)
-        .map(|(x, y)| Point { x, y })
-        .unwrap();
-
-    Garden {
-        squares,
-        width,
-        height,
-        start,
-    }
+fn virtual_point(point: Point) -> VirtualPoint {
+    point.cast().expect("The garden fits in an i64")
 }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "...........
-.....###.#.
-.###.##..#.
-..#.#...#..
-....#.#....
-.##..S####.
-.##..#...#.
-.......##..
-.##.#.####.
-.##..##.##.
-..........."
-        .to_string()
+fn parse(input: &str) -> Result<Garden, ParseError> {
+    let squares = Grid::parse(input, |char| match char {
+        'S' => Some(SquareType::Start),
+        '.' => Some(SquareType::Garden),
+        '#' => Some(SquareType::Rocks),
+        _ => None,
+    })?;
+
+    let start = squares
+        .position(|&square| square == SquareType::Start)
+        .map(Point::from)
+        .ok_or_else(|| {
+            ParseError::new(
+                input.lines().next().unwrap_or(input),
+                "there is no starting position 'S'",
+            )
+        })?;
+
+    Ok(Garden { squares, start })
 }
 
-#[allow(dead_code)]
-fn input() -> String {
-    "...................................................................................................................................
-....#..#...##..##........#...#.#.........#.....#.#....................#..###.#.#...#........##......#.#.......#............#.......
-.#...#........................#.....#.#..............#....................#..#....................#...............##..#...##.#.....
-....#......#..#.#.....#...............#........#........#.#...............#....#..#..#..##........................#.#...#..........
-......................................#...#......#....#..................#.#......................#.....#....#....#.#....#....#....
-..#.....#....#....#.#....#...#...#..............##..#...........#..................#.......#.......#...#....##...#.........#.......
-....##..........#.........#.....#...#.#.#.......#.#...#............................#..#.............#.#............................
-............#.#...............................#.....#............................#......#.......#........#.......#.....#...#.#.....
-....#.......#........#......................#....#............#....#..........................#....#......##.....#...........##....
-...#.#.#..##...........................#........................#...#.........##.....#...#.#..............#.#.....#.#...##..#......
-..#.....#..#.................................#...#...........##.....#...........#........#.#..#..........##....#..#.#..............
-....##..#.#...#..#...................#...#...#...............#.....#......................................#............#.......#...
-............#..#...##....#.#..#............#...#.........##.......#........................#..............###.#...........#......#.
-.......#..#......#...#.##..#...#....#.#.......................#.#.####....#...................#...#.........##.....##...........#..
-.....#..#..............#....##..#....#.................#.#....#.....#...............#....#...................................#.....
-..#.......#..#....##......#.....##........#...........#.#...........................#.#.....#...................###...........#..#.
-.#....###.....#......#......#.........#......................#.#..........................##...#..#.............##...#...#.#.......
-...#..##..#...................#.....#.#..#..#.........##....#..............#.#...........##.....#.......##..............#....#...#.
-........#..............#...................................##........#....#...........................#...............#.......#.##.
-.......#......#.............#..........#.................#.....##....##...##............##....................#.#.#........#....##.
-...............#...........#..#.....#...............#......#.......#...#..#.##.............#.#..............##....#.........###....
-....#.....#..##.................................#.....##........#...................................#....#...#.........#..#......#.
-............#.........#....#...#.......................#.##.....#...................................#....#.....#..#.......#.....#..
-...##..........#.#.....#..#........##....................#...............#..#..#........................#....#............#..#.....
-...................#.....#......................#.#............#...........##......................#..#............#.....##........
-...#.......##......#......#....#.............#...#............#...............#...................#.#.##........................#..
-..#..#........##..#.#...#..#..#..##........#.#...............#........................#............#...#.......#.........#..#......
-..................#..#..#.....#..................#.#.#..#...............#...#..###..#........................#.....#...#.......#...
-............#.......##..........#..............#.....#.#...........#........##...##..##.#..........#.............#..#.#............
-..........................#....#...........#.............#...........#....#......##...............#....#.....#....#.##.....#.#...#.
-.#........#.............#........................#.#........##.#......#...........#.....##............###.....#.....#.....#........
-......#..........#......#..#.......................#......................##.....#..#.......#........#....#.#..#..#.......#......#.
-.....#........#..#.....#..#.............#.................#.........#..................#...#..........##....#.#.#..#.##........###.
-...#......#.#.............#..............#.............#...#..#...##.#..#..........##......##..............##..#.#...#.........#...
-......##..#......#.#....#.##.......#......#.#...................#.......#.....#..........................#........#...........#....
-...#...............##.............#.............................#.......#.............#....#....................................#..
-..#.#....#.#.....#.#...#..........#..#................#.....#........#.#...............#........#........................#....#....
-....#....#.......#...............#..........##.#..................#...#...............#.#....#..............#......#.......#....##.
-..#..................#......................#...#..##.....##.###......#.###...........#..#.............................##.......##.
-..#..##...#..#....................#....#.#.#..#.#.#..#.#...##......#.#.#....#..........##.#..##.#..#..............##...............
-.....#..............#.........................###.....##...............#.....#.....#..#......###.....#.............................
-.......##....................................#.........#..#...#...##......#......#.#.#...#......##...#.......................#.#...
-.#....#....#.......#............##.....................#.......#...#..#.......#...#..#...#.....................................#...
-.#...#.......#..#...............##...........#....#.....##...#..........#.....#...#.........##....#..#.....................#..#.##.
-......##..................#.##...#....#.................#...#.#........##.......#.....#........#.......#...............#..#....#...
-.#........##.#..............#.....#...........#...#...#.#..#.#...........#....#.......#.....#...##.....................#.#...##..#.
-......#..#..#.#........#...#......#........#.#.........##...........#............#..........#......................#..#..#.....#...
-.#.......#..#.........#.#....#.#.##...........##.....#........#.#..........#......#..............#...#..#..............#..#........
-.....#....#............#.#...........#..#.#..#............#...........#......#.#...........#...#................................#..
-....##.................#.....##.....#.....................#..........#.#.#...#.....#...............#.....#.#.............#.........
-......#...............#.#.#......#...##..........#...........##....#.............#.........#......#......#...................#.##..
-.....#............#.......#.#.............#....#.......#..........#.##.#...#........##......#.....#.#...#..........................
-....#....................#................#..#....#....##..#...#.....#........#..#.........#....#.......#......#.............#...#.
-.#...#................#...#....................#.....#.....#........##........#....#.#...#...#..#..#.#...#..#......................
-....#.........................#.....#..#..............##......#.#.....#............................#.#.....#.....#.................
-......................#...##.........##........#..#.........#.....#...##...#..#.#...#..........................#.................#.
-..#..#........#...#.......#....................#....#.......#.#.......#....#.......#.#.#.................#.....................##..
-.#................#....#...............#.#..........#.......#...........#.##.#...#.....#..............#...#....#..#................
-.#....................#................##.......#####......#..........#........#.............#.#.........#.#......#..#.............
-..#.......#....#...#.#.................#.......#................#..#..#.....##..#.....................#..#....#....................
-.........#.......##...#.#.........#.....#.........#.....#.#.#..##...#...#.......#....................#..#...#..#....#.#............
-..............#.........#.....#.###....##...#.....#........##...#......#..........#...........#..##...#.....#.#........#...........
-.........#.#..........#..........#.........#........##....#..##.........##......#..#..#........#.....#.....#.......................
-.......#.#...........#........##.......#............#........#.#...............#..#.......#..#.........#.....#..#..#..#..#.........
-.......#............#.#............#...........##..#.........#.....#......#..#...#.............................#......#..#.........
-.................................................................S.................................................................
-.......##....#...#......#...........#.........#.....#..#...........#.##....#.##..#........................................###......
-.....................#...............#....#.........#..........#.....#..#.......#.......#...............#.#.....#..#...............
-.........##..........#.....#.............#...#.........##..........##........##......#............#.#...##...#...#..#....#.#.......
-............#..##........#..........#.##..............#................#..#..#..#...#.##........#......#.......#.......#.##........
-.......................#.....#..#..#.....#....................#.............###.....#..#....#.......#...#.......#......#...........
-.#........#.#.....#...#.#......#..#.#.#..#......##..........##..#.....#...............#............................................
-.....................##..........#.....#.........#....#.........................#..........#...#.#.....#.....#...#.................
-.##................#.......##...........#...#.#.........##..#.......#.......##................#........#...##.......#............#.
-....................#.#.....#...#...#..#..##...##...#..................#......................#....##........................#.....
-..#.............#...#.......#...#....#..#............#..#.#......................#.................#.#...#..#.....#.........#.#....
-...................#.#......##.............#...............#..#.#......................#...............###..###..#..........#......
-........#............#...#...#.#....#..#..........#..................#.........#.......#.........#..##......#..............#...#...
-..##.............##.......##.#.#.#....#....#.#...#....................#..........#...#.......#..#..........#.#...............#.....
-...#..............#.....#..............###......#........#..#.....#.......#...#..#.................#.#........#..........#.....#...
-....#....#............#.#....#..........#............#.........##...............#.#................#...#...................#..#.#..
-........#.#.........#....#.....#.....#.#..#.........................#..#.#.......##.#.........#......#..##....#........##.#........
-......................#......#...........#......#......#.#................#...##.#....#......#.....#.................#...#.#...#...
-.....#..#...................#...............###..........#.............#..#.#.....#....#....................................#.#.#..
-.#.#.........#.........#......#.#...............#.....#..#......#.....#.#..#...##.........#...#....#............................#..
-..#.#......#..............#.##.#..##....#.............#.....#....................###.....#...#..#.....................#.......#.#..
-.#.#.........#.................#.#........#..##.........#..#.......#...##....#...#..........#.#...#..#.#...........#......#.###.##.
-.........##..................#...#......##...##...#.............#..........#.....###......#...#...#.#.#.................##.#...#...
-..........#...#..#...................#..#....##........#..........##............#......#........#.#.................#............#.
-..#.....#......#..............#............................##..##.............##.......#.#..#.........#..........##...##.....#..#..
-.............#..............................#.#........................#......##...............#..#................#.##...#........
-...#..#.....#..##...#..................................#.....................#...#.....#.........#..........#........#...#.#.#.....
-....#......#.##.....#..........#.....##.........#.#..#.........................................................#......#..##.#....#.
-...................#............................#........#..#.................#.....#..#.......#..............##.#.....#...........
-......#......#..#................#........#...#..#..............#......#....#.......#.....................#...........#....#...##..
-..#..#.....#...#..#...............##.............#.#........#...........#.............##....#.#...........#.#.........###....##....
-..#......#.#...#.........#...................................#........#...................................................#..#.....
-.............#......#.......#........#.........#...........#....#..#...................##..............#.#...#.#...........#..#..#.
-....#............#.....#.............#...#..........#.......#.....#......................#..#..........#.#....#...........#........
-..........#.......##.....................##....##......#.............##.##...#..........#.#...................#..............##.#..
-....#....#.........#.#..#..##.#....................#..............#.......#............................#.......#..........##....#..
-..##.#.........#.......#...#..........................#.#.....#....#.....##......#....#...#...................................#....
-..........#.....#..#....#...................#.....#.#...##..#.#................#.#...................##.........#........#....##...
-.............#.........#.....#.............#.............##.............#.....#....#.................#...#.......#.#..#..#...#.#...
-........##...#....#..........#......................#..#.#...........#........#..#.#..............#............#......#........#...
-...#.#..#.#...............#..##..#..#.........#........#.#..........#.......#.........#........#.......#.......#..#....##.......#..
-..#.......###.........#.....##.......#....................#..#......##.......#....##..........##........#.....................#....
-.........#..#.....#.....#......#.....#........#...............#...#...#......#.................................#.#.#............##.
-.....#.........#..#.........#..##......#...................##.....##.....#.....#............#.......#.....#..#..#..#..........#.#..
-..........#.#.........#.......#..#...##......................................#..............#....##.#..#....#....#.##..............
-...........###.#..........##...........................................#.........#.......#.....................#......##...........
-...#........#.....#...................#.......................##.......#......#...........#..###.....#..#.......#........#.........
-..##.....#..#..#.....#.................#.#.............#...##.........#................#.......................##.......#.....###..
-..............##.#...#............#......#...........#..#.........#..#......#...........##....#....#.............##.#..............
-.#..##.#.............#.....#.............................#.....#.........#.................##.#...#.....#.....#..#..#............#.
-.#.#...#.##..........#..........#.......#.....................#..........##..............#.............#........#.#......#....#....
-....#...................#.......#.....#................#.....#.....#.....#.............#.#.#..#.................#........#..#......
-.............#...#.......#.#..#...##....#..#..#.........##.............#.............#.#.#.........#..................#............
-............#..#...#..##....##..#.....#..........#.......##.#.......................................#.#..........................#.
-..#................#...##.....#..........#...#...............#........................#....##.....#..............#...##.##.#..#.#..
-....#.#...#.#.#.....#..#.##.#.##....#.#.##..#..................................##.......................##........#.#......##...#..
-...#.....#.#..#..#.#.......#.......#..##.#.....#............#.#......##..............#.#...#.....#...#.#....#.........#.....#....#.
-....###.#..............#.#.....##..........#......................#.#...............#.##.....#........#.#...#..#.#............#..#.
-.......#.....#.#..............#..#..#........##.#.#...............#.....................#...#..#.##...........#.....#....#.#..#....
-.......##..........#.#....#..#..#.......##.......#..................................#..#..........#............#....#......#.##....
-.###.##.##........#.....#......#....#...................#.........#..................#...#..............#..#......#..........#..#..
-.....##.........................#.....#.......#...#.....#.................##..#........#...................###.#......#..#....#....
-..#........#........##......#..#.#..............#.........................#...###.#...#....##.#........#.#......#........#....##...
-..........#.....#........#................##...........#...............###.....##...........#..#...#...#..##......#........#.#.....
-.....#....#......................#.##.............#..#...#.#.............#............#..#..#.....#.......................#...###..
-...................................................................................................................................".to_string()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input, unknown};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(16, part1(&Day21::parse(&example(21)).unwrap(), 6, &mut ()));
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(3697, part1(&Day21::parse(&input(21)).unwrap(), 64, &mut ()));
+    }
+
+    #[test]
+    fn part2_example() {
+        let garden = Day21::parse(&example(21)).unwrap();
+
+        assert_eq!(16, part2(&garden, 6));
+        assert_eq!(50, part2(&garden, 10));
+        assert_eq!(1594, part2(&garden, 50));
+        assert_eq!(6536, part2(&garden, 100));
+    }
+
+    #[test]
+    #[ignore = "answer unknown, too slow to finish"]
+    fn part2_input() {
+        unknown(part2(&Day21::parse(&input(21)).unwrap(), 26_501_365));
+    }
 }
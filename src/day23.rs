@@ -1,21 +1,20 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Point2D {
-    x: usize,
-    y: usize,
-}
+use crate::{
+    geometry::{Direction, Point2},
+    graph::{bfs, longest_path},
+    grid::Grid,
+    parsing::ParseError,
+    progress::Progress,
+    solution::{Answer, Solution},
+};
 
-impl Display for Point2D {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
+type Point = Point2<usize>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Path {
-    from: Point2D,
-    to: Point2D,
+    from: Point,
+    to: Point,
     length: usize,
 }
 
@@ -29,14 +28,6 @@ impl Display for Path {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum Terrain {
     Path,
@@ -45,236 +36,136 @@ enum Terrain {
 }
 
 #[derive(Debug, Clone)]
-struct HikingTrail {
-    start: Point2D,
-    end: Point2D,
-    terrain: Vec<Vec<Terrain>>,
+pub struct HikingTrail {
+    start: Point,
+    end: Point,
+    terrain: Grid<Terrain>,
     paths: Vec<Path>,
-    width: usize,
-    height: usize,
 }
 
 impl HikingTrail {
     fn valid_neighbors_while_taking_slopes_into_consideration(
         &self,
-        path: &Vec<Point2D>,
-        current_position: &Point2D,
-    ) -> Vec<Point2D> {
+        current_position: &Point,
+    ) -> Vec<Point> {
         // If we're currently on a slope, the slope dictates our only valid next step.
-        if let Terrain::Slope(direction) = &self.terrain[current_position.y][current_position.x] {
-            let next_position = match direction {
-                Direction::Up => Point2D {
-                    x: current_position.x,
-                    y: current_position.y - 1,
-                },
-                Direction::Right => Point2D {
-                    x: current_position.x + 1,
-                    y: current_position.y,
-                },
-                Direction::Down => Point2D {
-                    x: current_position.x,
-                    y: current_position.y + 1,
-                },
-                Direction::Left => Point2D {
-                    x: current_position.x - 1,
-                    y: current_position.y,
-                },
-            };
-
-            // The steps is only valid if we haven't been there before.
-            if path.contains(&next_position) {
-                return vec![];
-            } else {
-                return vec![next_position];
-            }
+        if let Terrain::Slope(direction) = &self.terrain[(*current_position).into()] {
+            let next_position = current_position
+                .checked_step(*direction)
+                .expect("Slopes never lead off the map");
+
+            return vec![next_position];
         };
 
-        // If we're not on a slope, we can move in any direction where there isn't a forest and we
-        // haven't been there before.
-        self.valid_neighbors(path, current_position)
+        // If we're not on a slope, we can move in any direction where there isn't a forest.
+        self.neighbor_paths(*current_position)
     }
 
-    fn valid_neighbors(&self, path: &Vec<Point2D>, current_position: &Point2D) -> Vec<Point2D> {
-        let mut neighbor_paths = self.neighbor_paths(*current_position);
-        neighbor_paths.retain(|neighbor| !path.contains(neighbor));
-        neighbor_paths
+    fn neighbor_paths(&self, point: Point) -> Vec<Point> {
+        self.terrain
+            .neighbours4(point.into())
+            .filter(|&neighbor| self.terrain[neighbor] != Terrain::Forest)
+            .map(Point::from)
+            .collect()
     }
+}
 
-    fn neighbor_paths(&self, point: Point2D) -> Vec<Point2D> {
-        let mut valid_neighbors = vec![];
-
-        if point.y > 0 && self.terrain[point.y - 1][point.x] != Terrain::Forest {
-            valid_neighbors.push(Point2D {
-                x: point.x,
-                y: point.y - 1,
-            });
-        };
+pub struct Day23;
 
-        if point.x < self.width - 1 && self.terrain[point.y][point.x + 1] != Terrain::Forest {
-            valid_neighbors.push(Point2D {
-                x: point.x + 1,
-                y: point.y,
-            });
-        };
+impl Solution for Day23 {
+    const DAY: u8 = 23;
 
-        if point.y < self.height - 1 && self.terrain[point.y + 1][point.x] != Terrain::Forest {
-            valid_neighbors.push(Point2D {
-                x: point.x,
-                y: point.y + 1,
-            });
-        };
+    type Input<'a> = HikingTrail;
 
-        if point.x > 0 && self.terrain[point.y][point.x - 1] != Terrain::Forest {
-            valid_neighbors.push(Point2D {
-                x: point.x - 1,
-                y: point.y,
-            });
-        };
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
 
-        valid_neighbors
+    fn part1(trail: &Self::Input<'_>) -> Answer {
+        part1(trail).into()
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct PathInfo {
-    current_position: Point2D,
-    path: Vec<Path>,
-    length: usize,
+    fn part2(trail: &Self::Input<'_>) -> Answer {
+        part2(trail).into()
+    }
 }
 
-impl PathInfo {
-    fn have_we_visited_this_location(&self, location: Point2D) -> bool {
-        if self.path.is_empty() {
-            return false;
-        }
-
-        let mut legs = vec![self.path.first().unwrap().from];
-
-        for i in 0..self.path.len() {
-            legs.push(self.path[i].to);
-        }
+fn part1(trail: &HikingTrail) -> usize {
+    let (length, _) = longest_path(
+        trail.start,
+        |position| {
+            trail
+                .valid_neighbors_while_taking_slopes_into_consideration(position)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+        },
+        |&position| position == trail.end,
+    )
+    .expect("There should be a route to the end");
 
-        legs.contains(&location)
-    }
+    length
 }
 
-fn main() {
-    assert_eq!(94, part1(&test_input()));
-    assert_eq!(2310, part1(&input()));
-    assert_eq!(154, part2(&test_input()));
-    assert_eq!(6738, part2(&input()));
-}
+fn part2(trail: &HikingTrail) -> usize {
+    let mut paths_from: HashMap<Point, Vec<(Point, usize)>> = HashMap::new();
 
-fn part1(input: &str) -> usize {
-    let trail = parse(input);
-
-    let mut paths_to_end: Vec<Vec<Point2D>> = vec![];
-
-    let mut stack: Vec<Vec<Point2D>> = vec![];
-    stack.push(vec![trail.start]);
-
-    while let Some(path) = stack.pop() {
-        for neighbor in trail
-            .valid_neighbors_while_taking_slopes_into_consideration(&path, path.last().unwrap())
-        {
-            let mut new_path = path.clone();
-            new_path.push(neighbor);
-            if neighbor == trail.end {
-                paths_to_end.push(new_path);
-            } else {
-                stack.push(new_path);
-            }
-        }
+    for path in &trail.paths {
+        paths_from
+            .entry(path.from)
+            .or_default()
+            .push((path.to, path.length));
     }
 
-    paths_to_end.iter().map(|path| path.len()).max().unwrap() - 1
-}
+    let progress = Progress::unbounded("crossroads visited");
 
-fn part2(input: &str) -> usize {
-    let trail = parse(input);
-
-    let mut longest_hiking_route: Option<PathInfo> = None;
-
-    let mut stack: Vec<PathInfo> = vec![];
-    stack.push(PathInfo {
-        current_position: trail.start,
-        path: vec![],
-        length: 0,
-    });
-
-    while let Some(path_info) = stack.pop() {
-        for path in trail
-            .paths
-            .iter()
-            .filter(|path| path.from == path_info.current_position)
-            .filter(|path| !path_info.have_we_visited_this_location(path.to))
-        {
-            let mut new_path_info = path_info.clone();
-            new_path_info.length += path.length;
-            new_path_info.path.push(*path);
-            new_path_info.current_position = path.to;
-            if path.to == trail.end {
-                if let Some(ref route) = longest_hiking_route {
-                    if new_path_info.length > route.length {
-                        longest_hiking_route = Some(new_path_info);
-                    }
-                } else {
-                    longest_hiking_route = Some(new_path_info);
-                }
-            } else {
-                stack.push(new_path_info);
-            }
-        }
+    let (length, _) = longest_path(
+        trail.start,
+        |crossroad| {
+            progress.advance(1);
+            paths_from.get(crossroad).into_iter().flatten().copied()
+        },
+        |&crossroad| crossroad == trail.end,
+    )
+    .expect("There should be a route to the end");
+
+    length
+}
+
+fn parse(input: &str) -> Result<HikingTrail, ParseError> {
+    let terrain = Grid::parse(input, |char| match char {
+        '.' => Some(Terrain::Path),
+        '#' => Some(Terrain::Forest),
+        '^' => Some(Terrain::Slope(Direction::Up)),
+        '>' => Some(Terrain::Slope(Direction::Right)),
+        'v' => Some(Terrain::Slope(Direction::Down)),
+        '<' => Some(Terrain::Slope(Direction::Left)),
+        _ => None,
+    })?;
+
+    if terrain.width() < 3 || terrain.height() < 2 {
+        return Err(ParseError::new(
+            input,
+            "the map is too small to have a start and an end",
+        ));
     }
 
-    longest_hiking_route.unwrap().length
-}
-
-fn parse(input: &str) -> HikingTrail {
-    let terrain = input
-        .split("\n")
-        .map(|line| {
-            line.chars()
-                .map(|char| match char {
-                    '.' => Terrain::Path,
-                    '#' => Terrain::Forest,
-                    '^' => Terrain::Slope(Direction::Up),
-                    '>' => Terrain::Slope(Direction::Right),
-                    'v' => Terrain::Slope(Direction::Down),
-                    '<' => Terrain::Slope(Direction::Left),
-                    other => panic!("Cannot parse {other} as a valid terrain"),
-                })
-                .collect::<Vec<Terrain>>()
-        })
-        .collect::<Vec<Vec<Terrain>>>();
-
-    let width = terrain[0].len();
-    let height = terrain.len();
-
     let mut trail = HikingTrail {
-        start: Point2D { x: 1, y: 0 },
-        end: Point2D {
-            x: terrain[0].len() - 2,
-            y: terrain.len() - 1,
-        },
+        start: Point::new(1, 0),
+        end: Point::new(terrain.width() - 2, terrain.height() - 1),
         terrain,
         paths: vec![],
-        width,
-        height,
     };
 
     let mut crossroads = vec![trail.start, trail.end];
 
-    for y in 0..height {
-        for x in 0..width {
-            if trail.terrain[y][x] == Terrain::Forest {
-                continue;
-            }
+    for (position, terrain) in trail.terrain.iter() {
+        if *terrain == Terrain::Forest {
+            continue;
+        }
 
-            let point = Point2D { x, y };
-            if trail.neighbor_paths(point).len() > 2 {
-                crossroads.push(point);
-            }
+        let point = Point::from(position);
+        if trail.neighbor_paths(point).len() > 2 {
+            crossroads.push(point);
         }
     }
 
@@ -288,208 +179,57 @@ fn parse(input: &str) -> HikingTrail {
 
     trail.paths = paths;
 
-    trail
+    Ok(trail)
 }
 
 fn find_accesible_neighboring_crossroads(
     trail: &HikingTrail,
-    all_crossroads: &Vec<Point2D>,
-    current: &Point2D,
+    all_crossroads: &[Point],
+    current: &Point,
 ) -> Vec<Path> {
-    let mut paths_to_neighboring_crossroads: Vec<Path> = vec![];
-
-    let mut stack: Vec<Vec<Point2D>> = vec![];
-    stack.push(vec![*current]);
-
-    while let Some(path) = stack.pop() {
-        for neighbor in trail.valid_neighbors(&path, path.last().unwrap()) {
-            let mut new_path = path.clone();
-            new_path.push(neighbor);
-            if all_crossroads.contains(&neighbor) {
-                paths_to_neighboring_crossroads.push(Path {
-                    from: *current,
-                    to: neighbor,
-                    length: new_path.len() - 1,
-                });
-            } else {
-                stack.push(new_path);
-            }
-        }
+    // Walk every trail leaving this crossroad, up until the next crossroad.
+    let paths = bfs(
+        *current,
+        |&position| match position != *current && all_crossroads.contains(&position) {
+            true => vec![],
+            false => trail.neighbor_paths(position),
+        },
+        |_| false,
+    );
+
+    paths
+        .reached()
+        .filter(|&(position, _)| position != current && all_crossroads.contains(position))
+        .map(|(&to, length)| Path {
+            from: *current,
+            to,
+            length,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(94, part1(&Day23::parse(&example(23)).unwrap()));
     }
 
-    paths_to_neighboring_crossroads.retain(|path| path.from != path.to);
-    paths_to_neighboring_crossroads
-}
+    #[test]
+    fn part1_input() {
+        assert_eq!(2310, part1(&Day23::parse(&input(23)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn test_input() -> String {
-    "#.#####################
-#.......#########...###
-#######.#########.#.###
-###.....#.>.>.###.#.###
-###v#####.#v#.###.#.###
-###.>...#.#.#.....#...#
-###v###.#.#.#########.#
-###...#.#.#.......#...#
-#####.#.#.#######.#.###
-#.....#.#.#.......#...#
-#.#####.#.#.#########v#
-#.#...#...#...###...>.#
-#.#.#v#######v###.###v#
-#...#.>.#...>.>.#.###.#
-#####v#.#.###v#.#.###.#
-#.....#...#...#.#.#...#
-#.#########.###.#.#.###
-#...###...#...#...#.###
-###.###.#.###v#####v###
-#...#...#.#.>.>.#.>.###
-#.###.###.#.###.#.#v###
-#.....###...###...#...#
-#####################.#"
-        .to_string()
-}
+    #[test]
+    fn part2_example() {
+        assert_eq!(154, part2(&Day23::parse(&example(23)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> String {
-    "#.###########################################################################################################################################
-#.......#...#...###...#.....#...#.....#...#####...#...............#...#...#...#.......#.......#...###...#####.......###.........###.......###
-#######.#.#.#.#.###.#.#.###.#.#.#.###.#.#.#####.#.#.#############.#.#.#.#.#.#.#.#####.#.#####.#.#.###.#.#####.#####.###.#######.###.#####.###
-#.......#.#.#.#.#...#.#...#.#.#.#...#.#.#.....#.#.#...........#...#.#.#.#.#.#.#.#.....#.....#...#.....#...#...#...#.....#.......#...#...#...#
-#.#######.#.#.#.#.###.###.#.#.#.###.#.#.#####.#.#.###########.#.###.#.#.#.#.#.#.#.#########.#############.#.###.#.#######.#######.###.#.###.#
-#...#...#.#.#.#.#...#.....#...#...#.#...#.....#.#.#...###...#.#...#.#...#...#.#.#...#.>.>.#...#.........#.#...#.#.........###.....#...#.....#
-###.#.#.#.#.#.#.###.#############.#.#####.#####.#.#.#.###.#.#.###.#.#########.#.###.#.#v#.###.#.#######.#.###.#.#############.#####.#########
-###...#.#.#.#.#.###.......#...#...#.....#.....#.#.#.#.#...#...#...#...###.....#...#.#.#.#...#.#.#...###...#...#.......###...#...#...#.......#
-#######.#.#.#.#.#########.#.#.#.#######.#####.#.#.#.#.#.#######.#####.###.#######.#.#.#.###.#.#.#.#.#######.#########.###.#.###.#.###.#####.#
-#.......#.#.#.#.#...###...#.#...#...#...#.....#.#.#.#.#.......#.....#...#.....#...#...#...#.#.#.#.#...#.>.>.#...#.....#...#...#.#...#.#.....#
-#.#######.#.#.#.#.#.###.###.#####.#.#.###.#####.#.#.#.#######.#####.###.#####.#.#########.#.#.#.#.###.#.#v###.#.#.#####.#####.#.###.#.#.#####
-#.#.....#.#.#.#.#.#...#...#.>.>.#.#.#.#...#...#.#.#.#...###...#.....#...#.....#.#...###...#.#.#.#.#...#.#.#...#...#...#.#.....#.#...#.#...###
-#.#.###.#.#.#.#.#.###.###.###v#.#.#.#.#.###.#.#.#.#.###.###.###.#####.###.#####.#.#.###.###.#.#.#.#.###.#.#.#######.#.#.#.#####.#.###.###.###
-#.#.#...#.#.#.#.#...#.#...#...#.#.#.#.#.###.#.#.#.#.#...#...#...#...#.###...#...#.#.#...###...#...#.....#.#.#.....#.#.#.#.###...#...#...#.###
-#.#.#.###.#.#.#v###.#.#.###.###.#.#.#.#.###.#.#.#.#.#.###.###.###.#.#.#####.#.###.#.#.###################.#.#.###.#.#.#.#.###.#####.###.#.###
-#.#.#.#...#...#.>.#.#.#.###...#.#.#.#.#.#...#...#...#.>.>.###.#...#.#...#...#.#...#...#...#.......#.......#.#.#...#.#...#.###.#.....#...#...#
-#.#.#.#.#######v#.#.#.#.#####.#.#.#.#.#.#.#############v#####.#.###.###.#.###.#.#######.#.#.#####.#.#######.#.#.###.#####.###.#.#####.#####.#
-#.#.#...#.......#...#.#.#####.#.#.#.#.#.#.......###...#.....#.#...#.#...#...#.#.........#.#.#...#.#.......#.#.#...#.....#.###.#.#...#...#...#
-#.#.#####.###########.#.#####.#.#.#.#.#.#######.###.#.#####.#.###.#.#.#####.#.###########.#.#.#.#.#######.#.#.###.#####.#.###.#.#.#.###.#.###
-#...#...#...........#.#.#.....#...#...#.#.....#.#...#.......#...#.#.#...###...#.........#.#...#.#.#...#...#.#...#.#...#.#.#...#...#...#.#.###
-#####.#.###########.#.#.#.#############.#.###.#.#.#############.#.#.###.#######.#######.#.#####.#.#.#.#.###.###.#.#.#.#.#.#.#########.#.#.###
-#.....#.............#...#.............#.#...#...#...#.......###...#.....#.....#.......#.#.#.....#...#...###.#...#.#.#...#...#...#...#...#...#
-#.###################################.#.###.#######.#.#####.#############.###.#######.#.#.#.###############.#.###.#.#########.#.#.#.#######.#
-#.#...............#...#...............#.....###...#...#.....#...........#...#.....#...#...#...............#...###...###...#...#.#.#.......#.#
-#.#.#############.#.#.#.#######################.#.#####.#####.#########.###.#####.#.#####################.#############.#.#.###.#.#######.#.#
-#.#.#...#...#.....#.#.#.......###...#.....#...#.#.#...#.....#...#.......#...#...#...#...#...#.............#...#.....#...#.#...#.#.......#.#.#
-#.#.#.#.#.#.#.#####.#.#######.###.#.#.###.#.#.#.#.#.#.#####.###.#.#######.###.#.#####.#.#.#.#.#############.#.#.###.#.###.###.#.#######.#.#.#
-#.#.#.#...#...#...#.#.#...###...#.#.#.#...#.#.#.#.#.#.###...###.#...#...#.....#.......#...#.#.............#.#.#.#...#.#...#...#.........#.#.#
-#.#.#.#########.#.#.#.#.#.#####.#.#.#.#.###.#.#.#.#.#.###v#####.###.#.#.###################.#############.#.#.#.#.###.#.###v#############.#.#
-#.#.#.#...#...#.#.#.#.#.#.#...#...#...#...#.#.#.#...#.#.>.>.###.#...#.#.#...###...#.........#...#.........#.#...#...#.#.#.>.#...#...#####...#
-#.#.#.#.#v#.#.#.#.#.#.#.#.#.#.###########v#.#.#.#####.#.#v#.###.#.###.#.#.#.###.#.#.#########.#.#.#########.#######.#.#.#.#v#.#.#.#.#########
-#.#.#.#.#.>.#.#.#.#.#.#.#.#.#.#...#.....>.>.#.#.#...#...#.#.#...#...#.#.#.#...#.#.#...#.....#.#.#.........#.......#.#.#.#.#.#.#.#.#.........#
-#.#.#.#.#v###.#.#.#.#.#.#.#.#.#.#.#.#####v###.#.#.#.#####.#.#.#####.#.#.#.###.#.#.###.#.###.#.#.#########.#######.#.#.#.#.#.#.#.#.#########.#
-#...#...#...#.#.#.#.#.#.#.#.#.#.#.#.#.....#...#.#.#...#...#...###...#.#.#.#...#.#.#...#.#...#.#.#...###...#...#...#.#.#.#.#.#.#.#.#...#.....#
-###########.#.#.#.#.#.#.#.#.#.#.#.#.#.#####.###.#.###.#.#########.###.#.#.#.###.#.#v###.#.###.#.#.#.###v###.#.#.###.#.#.#.#.#.#.#.#.#.#.#####
-#...........#...#...#.#.#.#.#.#.#...#.....#.....#...#.#.....#.....#...#.#.#...#.#.>.>...#.#...#.#.#...>.>.#.#.#...#...#.#.#.#.#.#.#.#...#####
-#.###################.#.#.#.#.#.#########.#########.#.#####.#.#####.###.#.###.#.###v#####.#.###.#.#####v#.#.#.###.#####.#.#.#.#.#.#.#########
-#.#.............#...#...#.#.#...#...#...#.......#...#.......#...#...#...#...#...###...###.#...#.#.#.....#...#...#.....#...#...#.#.#...#...###
-#.#.###########.#.#.#####.#.#####.#.#.#.#######.#.#############.#.###.#####.#########.###.###.#.#.#.###########.#####.#########.#.###.#.#.###
-#.#.#...........#.#.#...#.#.#.....#...#...#...#.#...........###.#...#...#...#.......#...#.#...#.#.#...........#.#.....###.......#...#...#...#
-#.#.#.###########.#.#.#.#.#.#.###########.#.#.#.###########.###.###.###.#.###.#####.###.#.#.###.#.###########.#.#.#######.#########.#######.#
-#...#.....#...#...#...#.#...#.....#...###.#.#...#...###...#.#...#...#...#.###.#...#.....#...###.#.#...........#.#.#.....#.......#...#.......#
-#########.#.#.#.#######.#########.#.#.###.#.#####.#.###.#.#.#.###.###.###.###.#.#.#############.#.#.###########.#.#.###.#######.#.###.#######
-#...#...#...#...#.....#.#...#...#...#...#.#...#...#.....#...#...#...#...#.#...#.#.#...#.......#.#.#.#.......#...#...#...#.......#.#...#######
-#.#.#.#.#########.###.#.#.#.#.#.#######.#.###.#.###############.###.###.#.#.###.#.#.#.#.#####.#.#.#.#.#####.#.#######.###.#######.#.#########
-#.#...#...........###...#.#.#.#.###.....#...#.#.............###.....###...#.....#...#.#.#.....#...#...#.....#.....#...###...#.....#.........#
-#.#######################.#.#.#.###.#######.#.#############.#########################.#.#.#############.#########.#.#######.#.#############.#
-#.................#.......#...#...#...#...#...#.............#...#.............#...#...#.#.###...#.......###...###...#...###...###...#.......#
-#################.#.#############.###.#.#.#####.#############.#.#.###########.#.#.#.###.#.###.#.#.#########.#.#######.#.#########.#.#.#######
-#.....#...........#.............#.....#.#...###...............#.#.........#...#.#.#.....#.....#.#.#.........#.....###.#.#...#...#.#.#.......#
-#.###.#.#######################.#######.###.###################.#########.#.###.#.#############.#.#.#############.###.#.#.#.#.#.#.#.#######.#
-#...#.#.......#...###...#...###.....###.#...#...#...............###...#...#...#.#.#.............#...###.....#...#...#.#...#.#.#...#.........#
-###.#.#######v#.#.###.#.#.#.#######.###.#.###.#.#.#################.#.#.#####.#.#.#.###################.###.#.#.###.#.#####.#.###############
-#...#...#...#.>.#...#.#.#.#.###...#.#...#.....#.#.#.....#...#...#...#.#.....#.#.#.#.............#.......###...#.#...#.....#.#...............#
-#.#####.#.#.#v#####.#.#.#.#.###.#.#v#.#########.#.#.###.#.#.#.#.#.###.#####.#.#.#.#############.#.#############.#.#######.#.###############.#
-#.#...#...#...###...#.#.#.#.#...#.>.>.#.....#...#...#...#.#.#.#.#.#...#...#.#.#.#.###...........#.............#...#...#...#...#.........#...#
-#.#.#.###########.###.#.#.#.#.#####v###.###.#.#######.###.#.#.#.#.#.###.#.#.#.#.#.###v#######################.#####.#.#.#####.#.#######.#.###
-#.#.#...#...#...#...#.#.#.#.#.#.....###...#...###...#...#.#.#.#...#...#.#.#.#...#...>.>.###...###.........#...###...#.#.....#.#.......#...###
-#.#.###.#.#.#.#.###.#.#.#.#.#.#.#########.#######.#.###.#.#.#.#######.#.#.#.#########v#.###.#.###.#######.#.#####.###.#####.#.#######v#######
-#.#.###...#.#.#...#...#...#...#.......###...###...#.....#.#...#...#...#.#...###...#...#...#.#...#.......#...#...#...#.#...#.#.#...#.>.#...###
-#.#.#######.#.###.###################.#####.###.#########.#####.#.#.###.#######.#.#.#####.#.###.#######.#####.#.###.#.#.#.#.#.#.#.#.#v#.#.###
-#...#.......#.###...#...#...#...#...#.....#...#.......#...#...#.#.#.....#.......#...#...#.#.#...#.....#...#...#.#...#...#...#...#...#...#...#
-#####.#######.#####.#.#.#.#.#.#.#.#.#####.###.#######v#.###.#.#.#.#######.###########.#.#.#.#.###.###.###.#.###.#.#########################.#
-#...#.........#...#...#.#.#...#...#.....#.###...#...>.>.###.#...#.......#...........#.#.#.#.#...#.#...#...#...#.#.....#.....................#
-#.#.###########.#.#####.#.#############.#.#####.#.###v#####.###########.###########.#.#.#.#.###.#.#.###v#####.#.#####.#.#####################
-#.#.#...###.....#.......#.............#...#.....#...#.#...#.#...........#.........#...#.#...#...#.#.#.>.>...#.#...#...#.....................#
-#.#.#.#.###.#########################.#####.#######.#.#.#.#.#.###########.#######.#####.#####.###.#.#.#v###.#.###.#.#######################.#
-#.#...#...#...#.......#...#...........#...#.......#.#.#.#.#.#.....#...###.......#.#...#...###.....#...#.#...#...#.#...#...#...###.....#.....#
-#.#######.###.#.#####.#.#.#.###########.#.#######.#.#.#.#.#.#####.#.#.#########.#.#.#.###.#############.#.#####.#.###.#.#.#.#.###.###.#.#####
-#...#...#...#...#.....#.#.#.........###.#...#...#.#.#.#.#...#.....#.#...#.......#...#.#...#.............#.......#.#...#.#...#...#...#.#.....#
-###.#.#.###.#####.#####.#.#########.###.###.#.#.#.#.#.#.#####.#####.###.#.###########.#.###.#####################.#.###.#######.###.#.#####.#
-###...#.#...#...#.....#.#.........#.#...#...#.#.#...#...#...#.......#...#...#...#...#.#...#.....#...#...###...###...###.......#.....#.#...#.#
-#######.#.###.#.#####.#.#########.#.#.###.###.#.#########.#.#########.#####.#.#.#.#.#.###.#####.#.#.#.#.###.#.###############.#######.#.#.#.#
-#.......#.....#.......#.........#...#...#.....#...#...#...#...........#####...#...#.#.#...###...#.#...#.....#.....###.........#...###.#.#...#
-#.#############################.#######.#########.#.#.#.###########################.#.#.#####.###.###############.###.#########.#.###.#.#####
-#.#.....#.................#.....#...#...#...#.....#.#.#.................#...#...#...#...#...#.#...#.............#...#.#.........#...#.#.....#
-#.#.###.#.###############.#.#####.#.#.###.#.#.#####.#.#################.#.#.#.#.#.#######.#.#.#.###.###########.###.#.#.###########.#.#####.#
-#.#.#...#.#...........#...#...###.#.#.....#.#.#...#.#.#...#...#.........#.#.#.#.#...#...#.#.#...###.......#...#...#.#.#.#...........#...#...#
-#.#.#.###.#.#########.#.#####.###.#.#######.#.#.#.#.#.#.#.#.#.#v#########.#.#.#.###.#.#.#.#.#############.#.#.###.#.#.#.#.#############.#.###
-#...#...#.#.....#...#...#...#.#...#.#...#...#.#.#.#.#.#.#...#.>.>.....#...#.#.#.#...#.#...#.#...#...#...#...#...#...#...#.............#.#...#
-#######.#.#####.#.#v#####.#.#v#.###.#.#.#.###.#.#.#.#.#.#######v#####.#.###.#.#.#.###.#####.#.#.#.#.#.#.#######.#####################.#.###.#
-#.....#...#...#...#.>...#.#.>.>.###...#.#.#...#.#.#.#.#...#.....#...#.#.#...#.#.#...#.#.....#.#.#.#.#.#.#.....#...#...#...###.....#...#.....#
-#.###.#####.#.#####v###.#.###v#########.#.#.###.#.#.#.###.#.#####.#.#.#.#.###.#.###v#.#.#####.#.#.#.#.#.#v###.###.#.#.#.#.###.###.#.#########
-#...#.#...#.#.......###...#...###...#...#.#.#...#.#.#...#.#.....#.#.#.#.#...#.#.#.>.>.#...#...#...#.#.#.>.>.#.....#.#.#.#.#...#...#.........#
-###.#.#.#.#.###############.#####.#.#.###.#.#.###.#.###.#.#####.#.#.#.#.###.#.#.#.#v#####.#.#######.#.###v#.#######.#.#.#.#.###.###########.#
-#...#.#.#.#...............#.....#.#.#.....#.#.#...#...#.#.#...#...#.#.#.###.#.#...#.....#.#.###.....#.###.#.......#.#.#.#.#...#.........#...#
-#.###.#.#.###############.#####.#.#.#######.#.#.#####.#.#.#.#.#####.#.#.###.#.#########.#.#.###.#####.###.#######.#.#.#.#.###v#########.#.###
-#...#...#.....#...#.......#.....#.#.#...###...#...#...#.#.#.#.......#.#...#...###.......#.#...#.#...#.#...#.....#...#...#...>.#.......#.#...#
-###.#########.#.#.#.#######.#####.#.#.#.#########.#.###.#.#.#########.###.#######.#######.###.#.#.#.#.#.###.###.#############v#.#####.#.###.#
-###.........#.#.#...#.....#.......#...#.......###...###...#.#...#...#.#...###...#.......#.....#.#.#.#.#...#.#...###...#.......#.#.....#.....#
-###########.#.#.#####.###.###################.#############.#.#.#.#.#.#.#####.#.#######.#######.#.#.#.###.#.#.#####.#.#.#######.#.###########
-#...........#...#...#.#...#.............#.....#.............#.#...#.#...#.....#.........#...###...#.#.###...#.......#.#.#.......#...#...#...#
-#.###############.#.#.#.###.###########.#.#####.#############.#####.#####.###############.#.#######.#.###############.#.#.#########.#.#.#.#.#
-#...........#.....#...#...#...........#...#...#.....#.........#...#.....#.#...#...........#.....###...#...#.....#.....#...###.....#...#...#.#
-###########.#.###########.###########.#####.#.#####.#.#########.#.#####.#.#.#.#.###############.#######.#.#.###.#.###########.###.#########.#
-#...........#.#...........###...#...#.....#.#.#...#...#.........#.#...#.#.#.#...#.........#.....#.......#.#...#...#...#...#...###...#...#...#
-#.###########.#.#############.#.#.#.#####.#.#.#.#.#####.#########.#.#.#.#.#.#####.#######.#.#####.#######.###.#####.#.#.#.#.#######.#.#.#.###
-#.............#.....###.......#...#.......#.#.#.#.#...#.........#...#...#...#...#.......#.#.#...#.....#...###.#...#.#.#.#.#.....###...#.#.###
-###################.###.###################.#.#.#.#.#.#########.#############.#.#######.#.#.#.#.#####.#.#####.#.#.#.#.#.#.#####.#######.#.###
-###...#...#...#...#...#.......#.....#...###.#.#.#.#.#.....#...#.........###...#...#.....#...#.#...###.#.#...#.#.#.#.#.#.#.#...#...#.....#...#
-###.#.#.#.#.#.#.#.###.#######.#.###.#.#.###.#.#.#.#.#####.#.#.#########.###.#####.#.#########.###.###.#.#.#.#v#.#.#.#.#.#.#.#.###v#.#######.#
-#...#...#...#...#.....#...###.#...#...#.....#.#.#.#.....#...#.........#.#...#...#...###.....#...#.#...#.#.#.>.>.#.#.#.#.#.#.#.#.>.#.......#.#
-#.#####################.#.###v###.###########.#.#.#####.#############.#.#.###.#.#######.###.###.#.#.###.#.###v###.#.#.#.#.#.#.#.#v#######.#.#
-#.#.....#...#.........#.#.#.>.>...#.......#...#.#.#...#...#.....#...#...#.....#.......#.#...#...#...###...###...#.#.#...#...#...#.#...###...#
-#.#.###.#.#.#.#######.#.#.#.#v#####.#####.#.###.#.#.#.###.#.###.#.#.#################.#.#.###.#################.#.#.#############.#.#.#######
-#.#.###.#.#.#.#.......#.#.#.#...#...#####...###.#.#.#.###...#...#.#.#.....#...........#.#.###.......#...........#.#.....###.....#...#.#.....#
-#.#.###.#.#.#.#.#######.#.#.###.#.#############.#.#.#.#######.###.#.#.###.#.###########.#.#########.#.###########.#####.###.###.#####.#.###.#
-#.#.#...#.#.#.#.#.....#.#...###.#.....#...#...#.#...#...#...#.....#...#...#.............#.###...#...#...........#.#...#.#...#...#...#...#...#
-#.#.#.###.#.#.#.#.###.#.#######.#####.#.#.#.#.#.#######.#.#.###########.#################.###.#.#.#############.#.#.#.#.#.###.###.#.#####.###
-#...#.....#...#...#...#.....#...#...#.#.#.#.#.#.#.......#.#.###.......#.#...#.....#.......#...#.#.#.........#...#.#.#.#.#...#.#...#...#...###
-###################v#######.#.###.#.#.#.#.#.#.#.#.#######.#.###v#####.#.#.#.#.###.#.#######.###.#.#.#######.#.###.#.#.#.###.#.#.#####.#.#####
-#...#...#...#.....#.>.#.....#.#...#.#...#...#.#.#.#.....#.#.#.>.>...#...#.#.#.#...#.###...#.###...#.#.....#...###.#.#.#.#...#.#...#...#.....#
-#.#.#.#.#.#.#.###.#v#.#.#####.#.###.#########.#.#.#.###.#.#.#.#v###.#####.#.#.#.###v###.#.#.#######.#.###.#######.#.#.#.#.###.###.#.#######.#
-#.#.#.#.#.#.#...#.#.#...#...#.#.#...#.......#.#.#.#.#...#.#...#...#...#...#.#.#...>.>.#.#.#.......#...###.......#...#...#...#.....#.......#.#
-#.#.#.#.#.#.###.#.#.#####.#.#.#.#.###.#####.#.#.#.#.#.###.#######.###.#.###.#.#####v#.#.#.#######.#############.###########.#############.#.#
-#.#...#...#.....#.#.....#.#...#.#...#.#.....#...#.#.#.....#.......###...###.#.###...#...#.#.......###...........###.......#...#.........#.#.#
-#.###############.#####.#.#####.###.#.#.#########.#.#######.###############.#.###.#######.#.#########.#############.#####.###.#.#######.#.#.#
-#.......#.......#.......#.....#.###...#.........#...#.....#...............#.#.#...#...###...###.....#.....#...#...#.....#.....#.#.......#...#
-#######.#.#####.#############.#.###############.#####.###.###############.#.#.#.###.#.#########.###.#####.#.#.#.#.#####.#######.#.###########
-#...###...#...#.#...........#...#...#...........#.....#...#.......#.......#...#.....#.....#...#...#.#.....#.#.#.#...###.....#...#.#...#.....#
-#.#.#######.#.#.#.#########.#####.#.#.###########.#####.###.#####.#.#####################.#.#.###.#.#.#####.#.#.###.#######.#.###.#.#.#.###.#
-#.#.#...#...#.#...#.....#...#.....#...#.........#.....#...#.....#...#.....###...#...#...#...#.#...#...#.....#.#...#.....#...#.#...#.#.#.#...#
-#.#.#.#.#.###.#####.###.#.###.#########.#######.#####.###.#####.#####.###.###.#.#.#.#.#.#####.#.#######.#####.###.#####.#.###.#.###.#.#.#.###
-#.#.#.#.#...#.#.....###...###.........#.#.......#...#...#.#...#.#...#.#...#...#...#...#...#...#.........#...#.#...#.....#.....#.....#...#...#
-#.#.#.#.###.#.#.#####################.#.#.#######.#.###.#.#.#.#.#.#.#.#.###.#############.#.#############.#.#.#.###.#######################.#
-#.#...#.....#...#.......#...#...#...#...#.......#.#.....#.#.#.#.#.#.#.#...#.........#...#...#.....#...###.#...#...#.#...###.................#
-#.###############.#####.#.#.#.#.#.#.###########.#.#######.#.#.#v#.#.#.###.#########.#.#.#####.###.#.#.###v#######.#.#.#.###v#################
-#.#...#...........#.....#.#.#.#.#.#.###.........#.......#.#.#.>.>.#.#.#...###...#...#.#.#...#...#.#.#.#.>.>.#...#.#.#.#.#.>.#...#...#...#...#
-#.#.#.#.###########.#####.#.#.#.#.#.###.###############.#.#.#######.#.#.#####.#.#v###.#.#.#.###.#.#.#.#.###.#.#.#.#.#.#.#.#v#.#.#.#.#.#.#.#.#
-#.#.#.#.#...........###...#.#.#.#.#...#...........#.....#.#.....#...#.#...#...#.>.>.#.#.#.#...#.#.#.#.#.#...#.#.#.#.#.#.#.#.#.#...#...#.#.#.#
-#.#.#.#.#.#############.###.#.#.#.###.###########.#.#####.#####.#.###.###.#.#######.#.#.#.###.#.#.#.#.#.#.###.#.#.#.#.#.#.#.#.#########.#.#.#
-#.#.#.#.#.#...#.........#...#.#...#...#...........#.....#.#...#.#.....#...#...###...#.#.#.###.#.#...#.#.#...#.#.#.#.#.#.#.#.#.#.........#.#.#
-#.#.#.#.#.#.#.#.#########.###.#####.###.###############.#.#.#.#.#######.#####.###.###.#.#.###.#.#####.#.###.#.#.#.#.#.#.#.#.#.#.#########.#.#
-#.#.#.#.#.#.#...#.......#...#.....#...#.....#...#####...#.#.#.#.#.......#...#...#...#.#.#...#.#...#...#.#...#.#.#.#...#.#.#.#.#.....#...#.#.#
-#.#.#.#.#.#.#####.#####.###.#####.###.#####.#.#.#####.###.#.#.#.#.#######.#.###.###.#.#.###.#.###.#.###.#.###.#.#.#####.#.#.#.#####.#.#.#.#.#
-#...#...#...#.....#...#.....#...#.#...###...#.#...#...###.#.#.#.#.#.....#.#.#...###.#.#...#.#...#.#.#...#.#...#...#...#.#.#.#.#.....#.#...#.#
-#############.#####.#.#######.#.#.#.#####v###.###.#.#####.#.#.#.#.#.###.#.#.#.#####.#.###.#.###.#.#.#.###.#.#######.#.#.#.#.#.#.#####.#####.#
-#.............#...#.#.#.....#.#.#.#...#.>.>.#...#.#.....#.#.#.#.#.#.#...#.#.#.....#.#.#...#...#.#.#.#...#.#...#.....#...#.#.#.#...#...#.....#
-#.#############.#.#.#.#.###.#.#.#.###.#.###.###.#.#####.#.#.#.#.#.#.#.###.#.#####.#.#.#.#####.#.#.#.###.#.###.#.#########.#.#.###.#.###.#####
-#...............#...#...###...#...###...###.....#.......#...#...#...#.....#.......#...#.......#...#.....#.....#...........#...###...###.....#
-###########################################################################################################################################.#".to_string()
+    #[test]
+    fn part2_input() {
+        assert_eq!(6738, part2(&Day23::parse(&input(23)).unwrap()));
+    }
 }
@@ -1,512 +1,605 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    str::FromStr,
+};
+
+use log::trace;
 use regex::Regex;
-use std::str;
+use serde::Serialize;
+
+use crate::{
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// Which characters of the schematic are symbols. Digits and `.` never are, and every other
+/// character that isn't a symbol is empty space.
+#[derive(Debug, Clone, Default)]
+pub enum Classifier {
+    /// Every character that isn't a digit or a `.`.
+    #[default]
+    NotDigitOrDot,
+    /// Only these characters.
+    Set(BTreeSet<char>),
+    /// The characters of a Unicode general category, like `P` for punctuation or `Sm` for math
+    /// symbols.
+    Category(Regex),
+}
+
+/// The abbreviation and long name of every Unicode general category, groups like `P` included.
+/// Surrogates are left out, as no `char` is one.
+const GENERAL_CATEGORIES: [(&str, &str); 37] = [
+    ("L", "Letter"),
+    ("LC", "Cased_Letter"),
+    ("Lu", "Uppercase_Letter"),
+    ("Ll", "Lowercase_Letter"),
+    ("Lt", "Titlecase_Letter"),
+    ("Lm", "Modifier_Letter"),
+    ("Lo", "Other_Letter"),
+    ("M", "Mark"),
+    ("Mn", "Nonspacing_Mark"),
+    ("Mc", "Spacing_Mark"),
+    ("Me", "Enclosing_Mark"),
+    ("N", "Number"),
+    ("Nd", "Decimal_Number"),
+    ("Nl", "Letter_Number"),
+    ("No", "Other_Number"),
+    ("P", "Punctuation"),
+    ("Pc", "Connector_Punctuation"),
+    ("Pd", "Dash_Punctuation"),
+    ("Ps", "Open_Punctuation"),
+    ("Pe", "Close_Punctuation"),
+    ("Pi", "Initial_Punctuation"),
+    ("Pf", "Final_Punctuation"),
+    ("Po", "Other_Punctuation"),
+    ("S", "Symbol"),
+    ("Sm", "Math_Symbol"),
+    ("Sc", "Currency_Symbol"),
+    ("Sk", "Modifier_Symbol"),
+    ("So", "Other_Symbol"),
+    ("Z", "Separator"),
+    ("Zs", "Space_Separator"),
+    ("Zl", "Line_Separator"),
+    ("Zp", "Paragraph_Separator"),
+    ("C", "Other"),
+    ("Cc", "Control"),
+    ("Cf", "Format"),
+    ("Co", "Private_Use"),
+    ("Cn", "Unassigned"),
+];
+
+impl Classifier {
+    /// Only the characters of the Unicode general category with the given name or abbreviation.
+    /// Long names can be written in any case, with spaces instead of underscores.
+    pub fn category(name: &str) -> Result<Classifier, String> {
+        let loose = |name: &str| name.replace([' ', '_'], "").to_lowercase();
+
+        let (abbreviation, _) = GENERAL_CATEGORIES
+            .iter()
+            .find(|&&(abbreviation, long)| name == abbreviation || loose(name) == loose(long))
+            .ok_or(format!("'{name}' is not a Unicode general category"))?;
+
+        let category = Regex::new(&format!(r"^\p{{{abbreviation}}}$"))
+            .expect("Every general category should be a valid class");
 
-trait ContainsAny {
-    fn contains_any(&self, substrings: &Vec<&str>) -> bool;
+        Ok(Classifier::Category(category))
+    }
+
+    pub fn is_symbol(&self, char: char) -> bool {
+        match self {
+            _ if char.is_ascii_digit() || char == '.' => false,
+            Classifier::NotDigitOrDot => true,
+            Classifier::Set(symbols) => symbols.contains(&char),
+            Classifier::Category(category) => category.is_match(char.encode_utf8(&mut [0; 4])),
+        }
+    }
 }
 
-impl ContainsAny for String {
-    fn contains_any(&self, substrings: &Vec<&str>) -> bool {
-        substrings.iter().any(|substring| self.contains(substring))
+/// Which cells around a number are next to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Neighbours {
+    /// All 8 cells around every digit, diagonals included.
+    #[default]
+    Eight,
+    /// Only the cells left, right, above and below every digit.
+    Four,
+}
+
+impl Neighbours {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbours::Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+            Neighbours::Four => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+        }
     }
 }
 
-#[derive(Debug)]
-struct PotentialPartNumber<'a> {
-    number: i32,
-    line_number: i32,
-    current_line: &'a str,
-    start_index_in_line: i32,
-    end_index_in_line: i32,
-    line_above: Option<&'a str>,
-    line_below: Option<&'a str>,
+impl FromStr for Neighbours {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw {
+            "8" => Ok(Neighbours::Eight),
+            "4" => Ok(Neighbours::Four),
+            _ => Err(format!("Expected 4 or 8 neighbours, found '{raw}'")),
+        }
+    }
 }
 
-impl PotentialPartNumber<'_> {
-    fn touches_symbol(&self) -> bool {
-        let symbols: Vec<&'static str> = vec!["@", "#", "$", "%", "&", "*", "-", "=", "+", "/"];
-
-        let touches_start = match self.start_index_in_line {
-            0 => false,
-            _ => String::from_utf8(
-                [self.current_line.as_bytes()[(self.start_index_in_line - 1) as usize]].to_vec(),
-            )
-            .unwrap()
-            .contains_any(&symbols),
-        };
+/// How to read a schematic. The puzzle uses the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub classifier: Classifier,
+    pub neighbours: Neighbours,
+}
 
-        let touches_end = match self.end_index_in_line {
-            x if x == self.current_line.len() as i32 - 1 => false,
-            _ => String::from_utf8(
-                [self.current_line.as_bytes()[self.end_index_in_line as usize + 1]].to_vec(),
-            )
-            .unwrap()
-            .contains_any(&symbols),
-        };
+/// A number in the schematic, with the position of its first digit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Number {
+    pub value: u32,
+    pub x: usize,
+    pub y: usize,
+    /// The number of digits.
+    pub length: usize,
+}
 
-        let touches_above: bool = match self.line_above {
-            None => false,
-            Some(line) => {
-                let start_index: i32 = {
-                    if self.start_index_in_line == 0 {
-                        0
-                    } else {
-                        self.start_index_in_line - 1
-                    }
-                };
-
-                let end_index: i32 = {
-                    if self.end_index_in_line == self.current_line.len() as i32 - 1 {
-                        self.end_index_in_line
-                    } else {
-                        self.end_index_in_line + 1
-                    }
-                };
-
-                let substring_above: String = {
-                    line.chars()
-                        .skip(start_index as usize)
-                        .take(end_index as usize - start_index as usize + 1)
-                        .collect::<String>()
-                };
-
-                substring_above.contains_any(&symbols)
-            }
-        };
+impl Number {
+    fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.x..self.x + self.length).map(|x| (x, self.y))
+    }
+}
 
-        let touches_below: bool = match self.line_below {
-            None => false,
-            Some(line) => {
-                let start_index: i32 = {
-                    if self.start_index_in_line == 0 {
-                        0
-                    } else {
-                        self.start_index_in_line - 1
-                    }
-                };
-
-                let end_index: i32 = {
-                    if self.end_index_in_line == self.current_line.len() as i32 - 1 {
-                        self.end_index_in_line
-                    } else {
-                        self.end_index_in_line + 1
-                    }
-                };
-
-                let substring_below: String = {
-                    line.chars()
-                        .skip(start_index as usize)
-                        .take(end_index as usize - start_index as usize + 1)
-                        .collect::<String>()
-                };
-
-                substring_below.contains_any(&symbols)
-            }
-        };
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Symbol {
+    pub symbol: char,
+    pub x: usize,
+    pub y: usize,
+}
+
+/// The engine schematic as a bipartite graph, with an edge between every number and every symbol
+/// next to it. Positions count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// Every edge as the index of the number and the index of the symbol.
+    pub edges: Vec<(usize, usize)>,
+    /// The indices of the numbers that are next to more than one symbol, so they could belong to
+    /// either.
+    pub ambiguous: Vec<usize>,
+    #[serde(skip)]
+    symbols_of_number: Vec<Vec<usize>>,
+    #[serde(skip)]
+    numbers_of_symbol: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    fn new(numbers: Vec<Number>, symbols: Vec<Symbol>, neighbours: Neighbours) -> Schematic {
+        let positions: HashMap<(usize, usize), usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| ((symbol.x, symbol.y), index))
+            .collect();
+
+        let mut edges = vec![];
+
+        for (number_index, number) in numbers.iter().enumerate() {
+            let mut touching: Vec<usize> = number
+                .positions()
+                .flat_map(|position| around(position, neighbours))
+                .filter_map(|position| positions.get(&position).copied())
+                .collect();
+            touching.sort_unstable();
+            touching.dedup();
+
+            trace!("{} touches {} symbols", number.value, touching.len());
 
-        // if touches_start {
-        //     println!("{} touches at the start", self.number);
-        // } else if touches_end {
-        //     println!("{} touches at the end", self.number);
-        // } else if touches_above {
-        //     println!("{} touches above", self.number);
-        // } else if touches_below {
-        //     println!("{} touches below", self.number);
-        // } else {
-        //     println!("{} doesn't touch anywhere", self.number);
-        // }
-
-        return touches_start || touches_end || touches_above || touches_below;
+            edges.extend(touching.into_iter().map(|symbol| (number_index, symbol)));
+        }
+
+        let mut symbols_of_number = vec![vec![]; numbers.len()];
+        let mut numbers_of_symbol = vec![vec![]; symbols.len()];
+
+        for &(number, symbol) in &edges {
+            symbols_of_number[number].push(symbol);
+            numbers_of_symbol[symbol].push(number);
+        }
+
+        let ambiguous = (0..numbers.len())
+            .filter(|&number| symbols_of_number[number].len() > 1)
+            .collect();
+
+        Schematic {
+            numbers,
+            symbols,
+            edges,
+            ambiguous,
+            symbols_of_number,
+            numbers_of_symbol,
+        }
     }
 
-    fn get_touching_gears(&self) -> Vec<Gear> {
-        let mut gears: Vec<Gear> = vec![];
-
-        match self.start_index_in_line {
-            0 => (),
-            start_index => {
-                let symbol = String::from_utf8(
-                    [self.current_line.as_bytes()[start_index as usize - 1]].to_vec(),
-                );
-                if symbol.eq(&Ok("*".to_string())) {
-                    gears.push(Gear {
-                        line_number: self.line_number,
-                        index_in_line: start_index - 1,
-                        adjacent_part_numbers: vec![self],
-                    });
-                }
-            }
-        };
+    /// The numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.symbols_of_number)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+    }
 
-        match self.end_index_in_line {
-            end_index if end_index == self.current_line.len() as i32 - 1 => (),
-            end_index => {
-                let symbol = String::from_utf8(
-                    [self.current_line.as_bytes()[end_index as usize + 1]].to_vec(),
-                );
-                if symbol.eq(&Ok("*".to_string())) {
-                    gears.push(Gear {
-                        line_number: self.line_number,
-                        index_in_line: end_index + 1,
-                        adjacent_part_numbers: vec![self],
-                    });
-                }
-            }
-        };
+    /// Every symbol with the numbers next to it.
+    pub fn symbols_with_numbers(&self) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .zip(&self.numbers_of_symbol)
+            .map(|(symbol, numbers)| {
+                let numbers = numbers
+                    .iter()
+                    .map(|&number| &self.numbers[number])
+                    .collect();
+
+                (symbol, numbers)
+            })
+    }
 
-        match self.line_above {
-            None => (),
-            Some(line) => {
-                let start_index: i32 = {
-                    if self.start_index_in_line == 0 {
-                        0
-                    } else {
-                        self.start_index_in_line - 1
-                    }
-                };
-
-                let end_index: i32 = {
-                    if self.end_index_in_line == self.current_line.len() as i32 - 1 {
-                        self.end_index_in_line
-                    } else {
-                        self.end_index_in_line + 1
-                    }
-                };
-
-                let substring_above: String = {
-                    line.chars()
-                        .skip(start_index as usize)
-                        .take(end_index as usize - start_index as usize + 1)
-                        .collect::<String>()
-                };
-
-                for (index_in_substring, char) in substring_above.chars().enumerate() {
-                    if char == '*' {
-                        let to_subtract = match self.start_index_in_line {
-                            0 => 0,
-                            _ => 1,
-                        };
-
-                        gears.push(Gear {
-                            line_number: self.line_number - 1,
-                            index_in_line: self.start_index_in_line + index_in_substring as i32
-                                - to_subtract,
-                            adjacent_part_numbers: vec![self],
-                        });
-                    }
-                }
-            }
-        };
+    /// The numbers that are next to more than one symbol, with those symbols.
+    pub fn ambiguous_numbers(&self) -> impl Iterator<Item = (&Number, Vec<&Symbol>)> {
+        self.ambiguous.iter().map(|&number| {
+            let symbols = self.symbols_of_number[number]
+                .iter()
+                .map(|&symbol| &self.symbols[symbol])
+                .collect();
 
-        match self.line_below {
-            None => (),
-            Some(line) => {
-                let start_index: i32 = {
-                    if self.start_index_in_line == 0 {
-                        0
-                    } else {
-                        self.start_index_in_line - 1
-                    }
-                };
-
-                let end_index: i32 = {
-                    if self.end_index_in_line == self.current_line.len() as i32 - 1 {
-                        self.end_index_in_line
-                    } else {
-                        self.end_index_in_line + 1
-                    }
-                };
-
-                let substring_below: String = {
-                    line.chars()
-                        .skip(start_index as usize)
-                        .take(end_index as usize - start_index as usize + 1)
-                        .collect::<String>()
-                };
-
-                for (index_in_substring, char) in substring_below.chars().enumerate() {
-                    if char == '*' {
-                        let to_subtract = match self.start_index_in_line {
-                            0 => 0,
-                            _ => 1,
-                        };
-
-                        gears.push(Gear {
-                            line_number: self.line_number + 1,
-                            index_in_line: self.start_index_in_line + index_in_substring as i32
-                                - to_subtract,
-                            adjacent_part_numbers: vec![self],
-                        });
-                    }
-                }
-            }
-        };
+            (&self.numbers[number], symbols)
+        })
+    }
 
-        gears
+    /// The symbols that are next to exactly `n` numbers, like the gears of part 2 with 2, with
+    /// those numbers.
+    pub fn symbols_with_exactly(&self, n: usize) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols_with_numbers()
+            .filter(move |(_, numbers)| numbers.len() == n)
     }
-}
 
-#[derive(Debug)]
-struct Gear<'a> {
-    line_number: i32,
-    index_in_line: i32,
-    adjacent_part_numbers: Vec<&'a PotentialPartNumber<'a>>,
-}
+    /// Every symbol with the numbers next to it, grouped by the character of the symbol.
+    pub fn adjacency(&self) -> BTreeMap<char, Vec<(&Symbol, Vec<&Number>)>> {
+        let mut adjacency: BTreeMap<char, Vec<_>> = BTreeMap::new();
+
+        for (symbol, numbers) in self.symbols_with_numbers() {
+            adjacency
+                .entry(symbol.symbol)
+                .or_default()
+                .push((symbol, numbers));
+        }
 
-impl Gear<'_> {
-    fn is_adjacent_to_exactly_two_part_number(&self) -> bool {
-        self.adjacent_part_numbers.len() == 2
+        adjacency
     }
 
-    fn get_ratio(&self) -> i32 {
-        self.adjacent_part_numbers
-            .iter()
-            .fold(1, |acc, cur| acc * cur.number)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
     }
 }
 
-impl PartialEq for Gear<'_> {
-    fn eq(&self, other: &Self) -> bool {
-        self.line_number == other.line_number && self.index_in_line == other.index_in_line
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input, &Options::default())
     }
-}
 
-impl Eq for Gear<'_> {}
+    fn part1(schematic: &Self::Input<'_>) -> Answer {
+        part1(schematic).into()
+    }
 
-fn main() {
-    part1();
-    part2();
+    fn part2(schematic: &Self::Input<'_>) -> Answer {
+        part2(schematic).into()
+    }
 }
 
-fn part1() {
-    let total = parse(input())
-        .iter()
-        .filter(|num| num.touches_symbol())
-        .fold(0, |acc, num| acc + num.number);
+fn part1(schematic: &Schematic) -> u32 {
+    schematic.part_numbers().map(|number| number.value).sum()
+}
 
-    println!("The sum of the numbers that touch a symbol is: {}", total);
+fn part2(schematic: &Schematic) -> u32 {
+    schematic
+        .symbols_with_exactly(2)
+        .filter(|(symbol, _)| symbol.symbol == '*')
+        .map(|(_, numbers)| numbers.iter().map(|number| number.value).product::<u32>())
+        .sum()
 }
 
-fn part2() {
-    let parsed_input: Vec<PotentialPartNumber> = parse(input());
+/// What `aoc explore day3` prints: the symbols with the numbers next to them by type of symbol,
+/// only the ones next to `exactly` numbers when it's set, followed by the ambiguous numbers. Or
+/// the whole graph as JSON, when `json` is set.
+pub fn explore(
+    input: &str,
+    options: &Options,
+    exactly: Option<usize>,
+    json: bool,
+) -> Result<String, String> {
+    let input = parsing::normalise(input);
+    let schematic = input.parse_in_day(Day3::DAY, |input| parse(input, options))?;
+
+    if json {
+        return Ok(schematic.to_json());
+    }
 
-    let all_gears: Vec<Gear> = parsed_input
-        .iter()
-        .flat_map(|no| no.get_touching_gears())
-        .collect();
-
-    let mut normalized_gears: Vec<Gear> = vec![];
-
-    let _ = all_gears.iter().for_each(|gear| {
-        match normalized_gears
-            .iter_mut()
-            .find(|norm_gear| norm_gear.eq(&gear))
-        {
-            Some(found_gear) => gear
-                .adjacent_part_numbers
+    let mut report = vec![];
+
+    for (symbol, adjacency) in schematic.adjacency() {
+        let adjacency: Vec<_> = adjacency
+            .into_iter()
+            .filter(|(_, numbers)| exactly.is_none_or(|n| numbers.len() == n))
+            .collect();
+
+        if adjacency.is_empty() {
+            continue;
+        }
+
+        match adjacency.len() {
+            1 => report.push(format!("{symbol} (1 symbol)")),
+            count => report.push(format!("{symbol} ({count} symbols)")),
+        }
+
+        for (position, numbers) in adjacency {
+            let numbers: Vec<String> = numbers
                 .iter()
-                .for_each(|part_nr| found_gear.adjacent_part_numbers.push(part_nr)),
-            None => normalized_gears.push(Gear {
-                line_number: gear.line_number,
-                index_in_line: gear.index_in_line,
-                adjacent_part_numbers: gear.adjacent_part_numbers.clone(),
-            }),
+                .map(|number| number.value.to_string())
+                .collect();
+
+            report.push(format!(
+                "  ({}, {}): {}",
+                position.x,
+                position.y,
+                numbers.join(", ")
+            ));
         }
-    });
+    }
 
-    let total_gear_ratio: i32 = normalized_gears
-        .iter()
-        .filter(|gear| gear.is_adjacent_to_exactly_two_part_number())
-        .map(|gear| gear.get_ratio())
-        .sum();
+    let ambiguous: Vec<_> = schematic.ambiguous_numbers().collect();
+
+    match ambiguous.len() {
+        0 => report.push("No numbers are next to more than one symbol".to_string()),
+        1 => report.push("1 number is next to more than one symbol".to_string()),
+        count => report.push(format!("{count} numbers are next to more than one symbol")),
+    }
+
+    for (number, symbols) in ambiguous {
+        let symbols: Vec<String> = symbols
+            .iter()
+            .map(|symbol| format!("{} ({}, {})", symbol.symbol, symbol.x, symbol.y))
+            .collect();
+
+        report.push(format!(
+            "  {} at ({}, {}): {}",
+            number.value,
+            number.x,
+            number.y,
+            symbols.join(", ")
+        ));
+    }
 
-    println!("The sum of gear ratios is: {}", total_gear_ratio);
+    Ok(report.join("\n"))
 }
 
-fn parse(input: Vec<&'static str>) -> Vec<PotentialPartNumber> {
-    let mut vec = vec![];
-    let re = Regex::new(r"(\d+)").unwrap();
-
-    for (i, line) in input.iter().enumerate() {
-        for capture in re.captures_iter(line) {
-            let (_, [number]) = capture.extract();
-            let m = capture.get(0).unwrap();
-
-            vec.push(PotentialPartNumber {
-                number: number.parse().unwrap(),
-                line_number: i as i32 + 1,
-                current_line: line,
-                start_index_in_line: m.start() as i32,
-                end_index_in_line: m.end() as i32 - 1,
-                line_above: match i {
-                    0 => None,
-                    _ => Some(input.get(i - 1).copied().unwrap()),
-                },
-                line_below: input.get(i + 1).copied(),
+/// The neighbours of a position. Positions past the right and bottom edge are harmless, as there
+/// is nothing there.
+fn around((x, y): (usize, usize), neighbours: Neighbours) -> impl Iterator<Item = (usize, usize)> {
+    neighbours
+        .offsets()
+        .iter()
+        .filter_map(move |&(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+}
+
+/// Reads the schematic with the given options.
+pub fn parse(input: &str, options: &Options) -> Result<Schematic, ParseError> {
+    let mut numbers = vec![];
+    let mut symbols = vec![];
+
+    for (y, line) in parsing::lines(input).enumerate() {
+        // The byte and character index of the first digit of the number being read.
+        let mut start: Option<(usize, usize)> = None;
+
+        for (x, (index, char)) in line.char_indices().enumerate() {
+            if char.is_ascii_digit() {
+                start.get_or_insert((index, x));
+                continue;
+            }
+
+            if let Some((start_index, start_x)) = start.take() {
+                numbers.push(Number {
+                    value: parsing::number(&line[start_index..index])?,
+                    x: start_x,
+                    y,
+                    length: x - start_x,
+                });
+            }
+
+            if options.classifier.is_symbol(char) {
+                symbols.push(Symbol { symbol: char, x, y });
+            }
+        }
+
+        if let Some((start_index, start_x)) = start {
+            numbers.push(Number {
+                value: parsing::number(&line[start_index..])?,
+                x: start_x,
+                y,
+                length: line[start_index..].len(),
             });
         }
     }
 
-    return vec;
+    Ok(Schematic::new(numbers, symbols, options.neighbours))
 }
 
-#[allow(dead_code)]
-fn test_input() -> Vec<&'static str> {
-    vec![
-        "467..114..",
-        "...*......",
-        "..35..633.",
-        "......#...",
-        "617*......",
-        ".....+.58.",
-        "..592.....",
-        "......755.",
-        "...$.*....",
-        ".664.598..",
-    ]
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(4361, part1(&Day3::parse(&example(3)).unwrap()));
+    }
 
-fn input() -> Vec<&'static str> {
-    vec![
-        "......124..................418.......587......770...........672.................564............................438..........512......653....",
-        "665/...*......................*599.....*.983......794*..140..*...........@..963*....................445........*......*.........709.....*...",
-        ".......246.....581......701..........108....%.532........../.73..699...927............................*....579.354.464..............298..86.",
-        "........................*.....@...............%........$............+.........167..................408............................$..*......",
-        "....914......335.......513..245....106=...............974................749.....*.702.......589........803........*176..386.....631..340...",
-        "....*.......*...........................48...203@.............767......*..../.362...$......................*159.381.........................",
-        "..620.....430.....612.507.........365.....................335../........938.................154.........@....................682............",
-        ".....................*...........*..................470.........................889...........*.....@.489.....453$.329..334............86...",
-        "..........324...............431..58..533-../..-...../......*405.................$.............47..474..............*......*.......930.*.....",
-        "............/.....*350....................400.502...............$...........168.......855.635....................258.......794...+.....846..",
-        "........................560...72.945..............866..........783..328....*....116......*...........179..904........682$..........333......",
-        ".....674...........152....*....*....*815.........*........$609.............737../................583*........*.84..............767*.........",
-        "..../......55@....+........645.914................987..................*..........972.........#.......80..750........588................=260",
-        ".....................349&...................../.................754.407..203*720./.......207...14...............=88...+...767...............",
-        ".........*824.............890.......269....893..271*139..645....*...................233...%................428...........*.........79.......",
-        "..........................#............*.................@.../...316...844.............*........@439...287*......*974.....182...............",
-        "....*.....50.......671+.................267........634*....417............-.598.....531....891................331................358.....341",
-        ".883.561..*....428.........../14...742...........@.....654.....809../716.......*456.....=....*........$..............................607....",
-        "...........835..*..796*............*..............321......612*.......................299..203....962..431..........277.......40......$.....",
-        "......+591.....916.....294.........446..111......................237*77.....&........................-................*...150*....*......873",
-        "....%..................................*.....................819............522.................922................738.........214.595..&...",
-        "...552.........*...............+545.....627...........601......*..801..............867.....954....*.984.....752..........*830...............",
-        "............276...939.............................979....*.........*........866*.....*.......*..963.-.................172...................",
-        "278................*......@..........52........51*....851..766@..515.949=.......736...456...107............796.@668..................#......",
-        "...*...431.................616..............................................79..................651.806.....%.............554.........740...",
-        ".969...-............................-..........721.......555.657....+.........*....#....704........*.................556...*....196.........",
-        "................*228..........312.201.....490...%.........*..#...815........896..417.....=....890.....274....884.683*.......327...*.........",
-        "......@......742.............*........393*....=........715..............535...................*.........................529.....741.....#...",
-        ".......386........244......196...............815...........869....+580...*...................71........654...454..346=.$.............909....",
-        "....................................................265...$.............738..401...984...........265.....*......*...............-...........",
-        ".......=.....184....148....14.........685...990..................80..=....../........$.......511....%.....424..400......184..551............",
-        ".....71......*.......@............812.........&..$.....573*613.....*.677.......#.......299..&.......................933.....................",
-        "...=.......142...917...-989....76*....230...*....105.............920.....+......371.......*............660..692.........553*........%.......",
-        "341................&.....................=.29.............643.82......*...714............222....934......*....@.............178..581..+.....",
-        "...............874...129.......................739*971.......*.......176.............3.@...........*..219..40..........#.............168....",
-        ".....179..............*...741.......524...................................757.=633..*...136......63........*..........399...................",
-        ".......*....315....307....*.....%..*.............718......371....=....654*..........89...................194........................+.23....",
-        ".....737.........&........540.253...80...273......*.........&...773............492.................722........113....970..=195....702.......",
-        "..............869..........................*.539...434...............393*933......*........679.874..%......=....*...........................",
-        "............................$....148......43...*............@....................67.876........=......./..388..920......423.........-.......",
-        "..118*773.142....%565.......397....*.........352..#..........217......................*....865......257..................*........421.415...",
-        "............&.........898.............607..........897...631.................787...840.......*..............684.........34.359.........*....",
-        "........995....235.....@.........#.......*.932*.............*73...940..997..#..........&.&...942...$.727..........115.........*122....380...",
-        "................-................131...........265...827...........*....*............797.490.....845..*..........#....+823..................",
-        "............96.......................383.......................53.292....19...536......................42..668..................579......666",
-        "...-..487............680..&...*45...&........801.............*.*.................-........374.....................128..109.......%....*.....",
-        ".611.+..............*.....151................=......739....622..572................103...........683.....245..748*....*............298.67...",
-        "........*735......911...................562........@.........................458.....*...753........................275.362*................",
-        ".....683......702.....736.230.....457.........................13................*..126...........458........................890.........992.",
-        "...............*......*..........*.......499........10.........+...227.227...542..........................167....661....................*...",
-        ".............691.7....135.62..157..570..*....304......*...........*......*................$589....#946....*.........*............132....190.",
-        "........687.......*.........*............768..-...453..643.........844....706...%......................509........767......*................",
-        "...........*....485......859...........*.........................................92...268.........193.....................385.....991*722...",
-        "...-.....18..................217......853................................28..............*........@.......+......302........................",
-        "...103......60=..*352...........*916........351.....347..=..452.810....................304..........539.346.......*....................*....",
-        "......................610..........................=....990.....=...819....*496..797.................*........946..44..................261..",
-        ".......630.............*........882.........................173....*...............*....903....44.....318.212.@.......-.....................",
-        ".......-......-...........@..=.....*841.....812.......515......*....713....+.....566....*...................*......344..297....356.430..%...",
-        ".........*482..453.......279.554./............#..320..*.....................671......873........&...637$.....413........@.........*.....906.",
-        "....263...........................861......*....*.....908......365....123.......494.............134.................808.......*.....*.......",
-        "..........465.....520%.....................432.76..........160........*......................26.......218....14.......*......598.874.844....",
-        "...417......%.............138.....................$..............84..............272...573.....*.296.*................585...................",
-        "...*................596...&......................783.....992..........*....982.....*.........857..*...314...797..265........*....*..547.#...",
-        "....260........75....*...........389....616=.........5.....*.....695..427..*....780....-425......872..........*...*.......49....599.....19..",
-        "..............+.......389...........*33.........596.......600......*......67......................................567.......................",
-        "...802............................$.....302....*.....-93........434............$........554../339..............................277..........",
-        ".....*....................$.....822.....*.....89...........233..........602.....911.....#..........958............475......773*.........%989",
-        "..849.............228..868..217......679.......................99*...../............................*.......................................",
-        ".........253.........*......*....................643..............796......-200...355..469.........174......=........174....279.638.........",
-        "295*22..*............664...462.-238...................&765.........................*........................241..............$.......%......",
-        ".......937.....25....................422.264.................244...........628...340..................106.........................551.....82",
-        "897*.........../..60.......361.......*......#.....164.........*.......804*....%..........670*194........#.......#..........83...............",
-        "....754............*.................51..47.......*.........487...585.....202........838..................28...734...*457..............427..",
-        "............155...705......................*....912.....887..........................*....*875...........*........................&.........",
-        "..952.621......*.............+983...........832.....783....@.......432#...+........530.223.............456......462...257*100......763......",
-        "..........=..905........................892...............................262..85..........#..................-../.....................671..",
-        ".........994...............476.............%.665...524...53*........939*........*..........703..497.........186............=.567........*...",
-        "154...............$412......&..........-.....&.....-........41.109......282......676...........%....&80.@...............439...*.....@...997.",
-        "........+130..................296.....308................@.....*...902..................................77....833.....%.....932.102.48......",
-        "................407%.%685......*..........927.=222........426.450...*........12.....82...../..570................*...798..........*.........",
-        "..825.....................923.429......#..*........#630...........409..314*..........*...569.....*....273..648...961.............279........",
-        "...=..827=..........293.....#.........875.401.............457.433..........690.....600............929......*..............*245.......93..94.",
-        ".............557.......*.......................52........@..........284.......................450......................986..........*.......",
-        "........................463.....583..708...........................+........101....834.445.......*.....336...................694...333......",
-        "....556....923................./......*..433.....182....181.........................*..*......492..598.*............260.....*...............",
-        "......*.....*....430...............960....*...+..&.......*..=96.....628.282...@355.833.883...........*..468...420...........288.............",
-        "...140...682......*..945...150............7.654....+83.941...........*......@................#....7.780.......*......922.........334........",
-        ".............365..49.......$...590............................608.........503......./......36.....*.......74...340.....*....................",
-        "..........@..*.......870.-.....*...284*556................288..*...@.............$.526............587....*...........387....................",
-        ".......188....626.$......372.733.............................*..97.466.....776.541......................413.950..........696.162............",
-        "...../.............755...........62......99...............224...............-.........333......................*..337.....*....#......%233..",
-        "442..7.......=...................#...........875*705.548..............963...............*......180.....581...350.....*....513...............",
-        "..............377....................................*.........#......*.........291......981..*...........*..........74..............&......",
-        "......895.........644...................613.......540...........756.............%............390.263....754........#.....713-.....450.......",
-        "...85*....$.............297......%.........*..........156............974./870....................*...........12....426.................483..",
-        ".......774............#..*.....872.............361......-...196..................849...........419............*.........221....667..........",
-        "...................961....604...........644....*...............*........../927......*......503................124........-......../...238...",
-        "....370.............................531*......174.693.........349..................495.......*....925.......................................",
-        ".....+...104....582...602*604...123..................*..896..........71@.....................767...............573@....656......%...........",
-        "140........*...@...............*.........-........592..*....................806..692.....511...............755..........*......917...636....",
-        "..........905.......462.....+...655......860..150.....800..903.......8*920..........*867.@...................=..........269.................",
-        "....582..................217........842.........*...........*..................912...............551...615.......-..........343*129....+....",
-        "....*........................./.....................752..347...275...127.@........*.....804.....#....+.#.........252...845............671...",
-        "..862..........38..........293....429...@............*.......*...#.....*..245....330.......*18.....182.....$538..........&..725.............",
-        ".................=.............46*....696.............581.664.........608...........................................%.......=...............",
-        "...*724.....977.............................../................565...............#15...............................782.........359....$.....",
-        "529.....373.*............198.983.....980..559..592...100.579......*889..145..839..............790.....496....193......................545...",
-        ".........*..560..769.......*.....775...*.............*....@..104................*...%..820.....*......*.....*.............245...160.........",
-        ".....338............@......28.......*...725.687....761......%...............%..423.773..*.....315....765..69.................*...*..........",
-        ".851*.....619...333..............907..........*.........512....536..........98.........434....................48.613.973..941...554..751....",
-        "....................*564....122.............608........$.........-.........................48.........435.......*.......*............&......",
-        "...........165*967...........-..799.186.938......@.615...317........................630...........551*................345...................",
-        "....968............281.546.......*.....*.......225.......*...162....372.........&85...+...177@...........719..55................360*........",
-        ".......*.......%.......#.......226....................341.....*......*.............................341.........*...........989&.....321..618",
-        "....884..554.163...%...............*423.233.................653...557....$....910...................*...........109....772..................",
-        "..................854......167..560......@.....311...958..............492.....*.....*877.........@...911..797%...........*..................",
-        "....852.815.....9.................................*....#...../..941.........960..494........56.375.....................50..+......=...106...",
-        "....*......*461.&.......739....$..........=.$562.276.......408.....=.....................=...%.................769.........893..463..*......",
-        ".133..................-........797.....313...........................................20.214.....357..776.471......*687.@.............527....",
-        ".......14........899...845..........*..........+.....46........634........914.....84../...............*.....*780.......878..%36.435.........",
-        "542.....*...........$........*833...257..329-.147...........+........150..*......*.......907...........429.........................*....#...",
-        ".....517..799.44@.........230..........................477.579...........836......839.....-.....................964.704.............194..310",
-        "298.......*....................307..800......346.65.....*..........414........@.......619........&442.............*..*......................",
-        "...........510...+.....837.237*.........../.....*.....270.....818$..+..........27....*.................163.....140....647....764.163........",
-        "................181...*..........536#....335......................................610..170...............*.-....................*.......&...",
-        ".......................832........................#........&........611.........................&55...428..472....586......111........768...",
-        ".......763.....................................461.........381......-............566..814.....*.....................*........./............8",
-        ".............+.......................318............695............................=..%....323.756............711..663............827.......",
-        "...........526....=....675...353&.....@..982#......*..................+.62...880+..................631.......$......................-.118...",
-        "..................655....*....................795..30...922*.......978...+.&.........539...........-....719.................599.............",
-        "......2...574%..................#.698...475.....*...........652./...........464.163$...*..338*966.........................../.....534..386..",
-        "......*.......................404..#............747...703........231...-...............................................................*....",
-        ".......906.................................&575.........&....457.......633...395..761...355.#780....3+......799+...............496...264....",
-        "311............967.682............%.838...........253..........@..551.......@....*.....*.............................487.........*..........",
-        "..........@.......*......925....376....&...419......=.............*..............20..952.111/....648.&........748................834..706...",
-        "..443....940.............*....................*..........................820.684...................@.755........*........106.283............",
-        "..............397.........803...84............627..........704.983..........*................522............................*....541........",
-        ".....32....$.....#...643*..............116........./905......*..../...........311......811$.*........*890..........924..670........=....882.",
-        "......*.....81.....*.....636.......317...*...................899.............*....*698............626....................-..+..@.......*....",
-        ".......877......256.714...................825.........458....................869..............................54............28.823..110.....",
-    ]
+    #[test]
+    fn part1_input() {
+        assert_eq!(549908, part1(&Day3::parse(&input(3)).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(467835, part2(&Day3::parse(&example(3)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(81166799, part2(&Day3::parse(&input(3)).unwrap()));
+    }
+
+    #[test]
+    fn finds_symbols_by_their_number_of_numbers() {
+        let schematic = Day3::parse(&example(3)).unwrap();
+
+        let positions = |n| -> Vec<(char, usize, usize)> {
+            schematic
+                .symbols_with_exactly(n)
+                .map(|(symbol, _)| (symbol.symbol, symbol.x, symbol.y))
+                .collect()
+        };
+
+        assert_eq!(vec![('*', 3, 1), ('*', 5, 8)], positions(2));
+        assert_eq!(4, positions(1).len());
+        assert!(positions(0).is_empty());
+    }
+
+    #[test]
+    fn lists_the_numbers_of_every_symbol_type() {
+        let schematic = Day3::parse(&example(3)).unwrap();
+        let adjacency = schematic.adjacency();
+
+        let values: Vec<Vec<u32>> = adjacency[&'*']
+            .iter()
+            .map(|(_, numbers)| numbers.iter().map(|number| number.value).collect())
+            .collect();
+
+        assert_eq!(
+            vec!['#', '$', '*', '+'],
+            adjacency.keys().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![vec![467, 35], vec![617], vec![755, 598]], values);
+    }
+
+    #[test]
+    fn exports_the_graph_as_json() {
+        let schematic = Day3::parse("12*\n..3").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&schematic.to_json()).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "numbers": [
+                    {"value": 12, "x": 0, "y": 0, "length": 2},
+                    {"value": 3, "x": 2, "y": 1, "length": 1},
+                ],
+                "symbols": [{"symbol": "*", "x": 2, "y": 0}],
+                "edges": [[0, 0], [1, 0]],
+                "ambiguous": [],
+            }),
+            json
+        );
+    }
+
+    #[test]
+    fn classifies_symbols() {
+        let symbols = |classifier: Classifier| -> String {
+            let options = Options {
+                classifier,
+                ..Options::default()
+            };
+
+            parse("1a.§\n×*_2", &options)
+                .unwrap()
+                .symbols
+                .iter()
+                .map(|symbol| symbol.symbol)
+                .collect()
+        };
+
+        assert_eq!("a§×*_", symbols(Classifier::NotDigitOrDot));
+        assert_eq!("×*", symbols(Classifier::Set(['*', '×', '1'].into())));
+        assert_eq!("§*_", symbols(Classifier::category("P").unwrap()));
+        assert_eq!("×", symbols(Classifier::category("Sm").unwrap()));
+        assert_eq!("×", symbols(Classifier::category("math symbol").unwrap()));
+        assert!(Classifier::category("Nope").is_err());
+    }
+
+    #[test]
+    fn only_accepts_general_categories() {
+        for (abbreviation, long) in GENERAL_CATEGORIES {
+            assert!(Classifier::category(abbreviation).is_ok());
+            assert!(Classifier::category(long).is_ok());
+        }
+
+        for name in ["Greek", "Alphabetic", "P}|.", "p", ""] {
+            assert_eq!(
+                Err(format!("'{name}' is not a Unicode general category")),
+                Classifier::category(name).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn only_counts_orthogonal_neighbours_when_asked() {
+        let input = "1.2\n.#.\n.3.";
+        let values = |neighbours| -> Vec<u32> {
+            let options = Options {
+                neighbours,
+                ..Options::default()
+            };
+
+            parse(input, &options)
+                .unwrap()
+                .part_numbers()
+                .map(|number| number.value)
+                .collect()
+        };
+
+        assert_eq!(vec![1, 2, 3], values(Neighbours::Eight));
+        assert_eq!(vec![3], values(Neighbours::Four));
+        assert_eq!(Ok(Neighbours::Four), "4".parse());
+        assert!("6".parse::<Neighbours>().is_err());
+    }
+
+    #[test]
+    fn flags_numbers_next_to_several_symbols() {
+        let schematic = Day3::parse("#12*\n..5.\n...+").unwrap();
+
+        let ambiguous: Vec<(u32, Vec<char>)> = schematic
+            .ambiguous_numbers()
+            .map(|(number, symbols)| {
+                (
+                    number.value,
+                    symbols.iter().map(|symbol| symbol.symbol).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(vec![(12, vec!['#', '*']), (5, vec!['*', '+'])], ambiguous);
+        assert_eq!(vec![0, 1], schematic.ambiguous);
+    }
 }
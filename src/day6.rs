@@ -1,7 +1,19 @@
-use regex::Regex;
+use log::debug;
+
+use crate::{
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Races {
+    records: Vec<RaceRecord>,
+    /// The one long race you get by ignoring the spaces between the numbers.
+    single: RaceRecord,
+}
 
 #[derive(Debug)]
-struct RaceRecord {
+pub struct RaceRecord {
     record_time: i64,
     record_distance: i64,
 }
@@ -20,110 +32,115 @@ impl RaceRecord {
     }
 }
 
-fn main() {
-    part1();
-    part2();
-}
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_races(input)
+    }
+
+    fn part1(races: &Self::Input<'_>) -> Answer {
+        part1(races).into()
+    }
 
-fn part1() {
-    let race_records = parse_records(input());
+    fn part2(races: &Self::Input<'_>) -> Answer {
+        part2(races).into()
+    }
+}
 
-    let margins: Vec<i64> = race_records
+fn part1(races: &Races) -> i64 {
+    let margins: Vec<i64> = races
+        .records
         .iter()
         .map(|race| race.margin_of_error())
         .collect();
 
-    println!("Ways to win each race: {margins:?}");
-    println!(
-        "Multiplied together, yields: {}",
-        margins.iter().fold(1, |acc, margin| acc * margin)
-    );
+    debug!("Ways to win each race: {margins:?}");
+
+    margins.iter().product()
 }
 
-fn part2() {
-    let race_record = parse_single_record(input());
+fn part2(races: &Races) -> i64 {
+    races.single.margin_of_error()
+}
 
-    let margin = race_record.margin_of_error();
+fn parse_races(input: &str) -> Result<Races, ParseError> {
+    let mut lines = parsing::lines(input);
 
-    println!("Ways to beat this record: {margin}");
-}
+    let times = lines
+        .next()
+        .ok_or_else(|| parsing::truncated(input, "the times"))?;
+    let distances = lines
+        .next()
+        .ok_or_else(|| parsing::truncated(input, "the distances"))?;
 
-fn parse_records(input: &'static str) -> Vec<RaceRecord> {
-    let times: Vec<i64> = Regex::new(r"Time:\s+(\d+\s*)+")
-        .unwrap()
-        .captures_iter(input)
-        .flat_map(|capt| {
-            let (m, [_]) = capt.extract::<1>();
-
-            m.split_whitespace()
-                .skip(1)
-                .map(|num| num.parse().unwrap())
-                .collect::<Vec<i64>>()
-        })
-        .collect();
+    let times = parsing::strip_prefix(times, "Time:")?;
+    let distances = parsing::strip_prefix(distances, "Distance:")?;
 
-    let distances: Vec<i64> = Regex::new(r"Distance:\s+(\d+\s*)+")
-        .unwrap()
-        .captures_iter(input)
-        .flat_map(|capt| {
-            let (m, [_]) = capt.extract::<1>();
+    let time_count = times.split_whitespace().count();
+    let distance_count = distances.split_whitespace().count();
 
-            m.split_whitespace()
-                .skip(1)
-                .map(|num| num.parse().unwrap())
-                .collect::<Vec<i64>>()
-        })
-        .collect();
+    if time_count != distance_count {
+        return Err(ParseError::new(
+            distances,
+            format!("expected {time_count} distances, one for every time, found {distance_count}"),
+        ));
+    }
 
-    times
-        .iter()
-        .zip(distances.iter())
-        .map(
-            |(&record_time, &record_distance): (&i64, &i64)| RaceRecord {
-                record_time,
-                record_distance,
-            },
-        )
-        .collect()
-}
+    let records = times
+        .split_whitespace()
+        .zip(distances.split_whitespace())
+        .map(|(time, distance)| {
+            Ok(RaceRecord {
+                record_time: parsing::number(time)?,
+                record_distance: parsing::number(distance)?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
 
-fn parse_single_record(input: &'static str) -> RaceRecord {
-    let (_, [time]) = Regex::new(r"Time:\s+([\d+\s*]+)")
-        .unwrap()
-        .captures(input)
-        .unwrap()
-        .extract();
+    let single = RaceRecord {
+        record_time: parse_joined(times)?,
+        record_distance: parse_joined(distances)?,
+    };
 
-    let mut time = String::from(time);
-    time.retain(|c| !c.is_whitespace());
+    Ok(Races { records, single })
+}
 
-    let (_, [distance]) = Regex::new(r"Distance:\s+([\d+\s*]+)")
-        .unwrap()
-        .captures(input)
-        .unwrap()
-        .extract();
+/// Parses a list of numbers as if the spaces between them weren't there.
+fn parse_joined(numbers: &str) -> Result<i64, ParseError> {
+    let digits: String = numbers.split_whitespace().collect();
 
-    let mut distance = String::from(distance);
-    distance.retain(|c| !c.is_whitespace());
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(numbers.trim(), format!("'{digits}' is not a valid number")))
+}
 
-    println!("Time: {time}, distance: {distance}");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
 
-    let record_time = time.parse().unwrap();
-    let record_distance = distance.parse().unwrap();
+    #[test]
+    fn part1_example() {
+        assert_eq!(288, part1(&Day6::parse(&example(6)).unwrap()));
+    }
 
-    RaceRecord {
-        record_time,
-        record_distance,
+    #[test]
+    fn part1_input() {
+        assert_eq!(2269432, part1(&Day6::parse(&input(6)).unwrap()));
     }
-}
 
-#[allow(dead_code)]
-fn test_input() -> &'static str {
-    "Time:      7  15   30
-    Distance:  9  40  200"
-}
+    #[test]
+    fn part2_example() {
+        assert_eq!(71503, part2(&Day6::parse(&example(6)).unwrap()));
+    }
 
-fn input() -> &'static str {
-    "Time:        49     78     79     80
-    Distance:   298   1185   1066   1181"
+    #[test]
+    fn part2_input() {
+        assert_eq!(35865985, part2(&Day6::parse(&input(6)).unwrap()));
+    }
 }
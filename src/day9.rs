@@ -1,12 +1,17 @@
 use std::fmt::Display;
 
+use crate::{
+    parsing::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 enum Direction {
     Normal,
     Reverse,
 }
 
 #[derive(Debug, Clone)]
-struct Report {
+pub struct Report {
     original_history: Vec<i32>,
     history_tree: Option<Vec<Vec<i32>>>,
     extrapolated_prediction: Option<i32>,
@@ -32,51 +37,56 @@ impl Display for Report {
     }
 }
 
-fn main() {
-    assert_eq!(1955513104, part1());
-    assert_eq!(1131, part2());
-}
+pub struct Day9;
 
-fn part1() -> i32 {
-    let mut reports = parse_into_reports(input());
+impl Solution for Day9 {
+    const DAY: u8 = 9;
 
-    let sum = reports
-        .iter_mut()
-        .map(|report| fill_out_history_tree(report))
-        .map(|report| extrapolate_history_tree(report, Direction::Normal))
-        .map(|report| fill_out_extrapolated_prediction(report))
-        .fold(0, |acc, report| {
-            acc + report.extrapolated_prediction.unwrap()
-        });
+    type Input<'a> = Vec<Report>;
 
-    println!("The sum of the extrapolated values is: {sum}");
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_into_reports(input)
+    }
 
-    sum
+    fn part1(reports: &Self::Input<'_>) -> Answer {
+        part1(reports).into()
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> Answer {
+        part2(reports).into()
+    }
 }
 
-fn part2() -> i32 {
-    let mut reports = parse_into_reports(input());
+fn part1(reports: &[Report]) -> i32 {
+    let mut reports = reports.to_vec();
 
-    let sum = reports
+    reports
         .iter_mut()
-        .map(|report| fill_out_history_tree(report))
-        .map(|report| reverse_history_tree(report))
-        .map(|report| extrapolate_history_tree(report, Direction::Reverse))
-        .map(|report| fill_out_extrapolated_prediction(report))
-        .fold(0, |acc, report| {
-            acc + report.extrapolated_prediction.unwrap()
-        });
+        .map(fill_out_history_tree)
+        .map(|report| extrapolate_history_tree(report, Direction::Normal))
+        .map(fill_out_extrapolated_prediction)
+        .map(|report| report.extrapolated_prediction.unwrap())
+        .sum()
+}
 
-    println!("The sum of the, now backwards, extrapolated values is: {sum}");
+fn part2(reports: &[Report]) -> i32 {
+    let mut reports = reports.to_vec();
 
-    sum
+    reports
+        .iter_mut()
+        .map(fill_out_history_tree)
+        .map(reverse_history_tree)
+        .map(|report| extrapolate_history_tree(report, Direction::Reverse))
+        .map(fill_out_extrapolated_prediction)
+        .map(|report| report.extrapolated_prediction.unwrap())
+        .sum()
 }
 
 fn fill_out_history_tree(report: &mut Report) -> &mut Report {
     report.history_tree = Some(vec![report.original_history.to_owned()]);
 
     loop {
-        if last_is_all_zeroes(&report) {
+        if last_is_all_zeroes(report) {
             return report;
         }
 
@@ -142,227 +152,43 @@ fn last_is_all_zeroes(report: &Report) -> bool {
     }
 }
 
-fn parse_into_reports(input: &'static str) -> Vec<Report> {
-    String::from(input)
-        .split('\n')
-        .map(|line| Report {
-            original_history: line
-                .split_whitespace()
-                .map(|num| num.parse().unwrap())
-                .collect(),
-            history_tree: None,
-            extrapolated_prediction: None,
+fn parse_into_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    parsing::lines(input)
+        .map(|line| {
+            Ok(Report {
+                original_history: line
+                    .split_whitespace()
+                    .map(parsing::number)
+                    .collect::<Result<_, _>>()?,
+                history_tree: None,
+                extrapolated_prediction: None,
+            })
         })
         .collect()
 }
 
-#[allow(dead_code)]
-fn test_input() -> &'static str {
-    "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45"
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example, input};
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(114, part1(&Day9::parse(&example(9)).unwrap()));
+    }
 
-#[allow(dead_code)]
-fn input() -> &'static str {
-    "14 28 56 121 263 554 1131 2270 4549 9201 18855 39034 81081 167714 343319 692592 1373532 2674454 5110126 9579947 17622015
--3 1 11 21 24 22 39 132 395 951 1927 3407 5358 7524 9283 9462 6105 -3811 -24717 -62855 -126748
-19 33 58 112 229 464 911 1744 3295 6199 11669 22016 41599 78474 147102 272564 496803 887453 1549804 2642368 4396329
-9 14 20 26 31 40 83 250 745 1962 4586 9722 19055 35044 61153 102122 164281 255910 387648 572954 828623
-3 -2 -10 -22 -42 -79 -137 -181 -56 698 3260 10419 28423 70739 165664 371788 809539 1725541 3623323 7526796 15506848
--1 -7 -15 -19 5 111 398 1033 2286 4580 8558 15168 25766 42236 67125 103790 156553 230859 333431 472415 657507
-14 23 56 138 310 639 1231 2241 3872 6352 9875 14499 20039 26137 33053 44522 75675 172337 454665 1211606 3100405
-12 21 39 74 141 262 466 789 1274 1971 2937 4236 5939 8124 10876 14287 18456 23489 29499 36606 44937
-19 29 48 92 202 465 1054 2295 4779 9575 18693 36148 70395 139749 284055 589010 1235351 2595681 5420598 11189176 22757108
-12 24 49 105 234 529 1186 2594 5491 11242 22336 43253 81919 152047 276755 493958 864150 1481324 2487923 4094873 6607920
-8 17 32 60 131 322 790 1822 3922 7967 15476 29048 53037 94544 164818 281170 469516 767677 1229576 1930484 2973479
-21 39 80 160 312 605 1168 2216 4067 7127 11804 18292 26142 33509 35932 24468 -17039 -115817 -315464 -683048 -1318428
-12 32 56 77 81 45 -58 -246 -501 -706 -490 1140 6616 21501 57778 139897 315366 672532 1369348 2678423 5055571
-2 0 4 23 72 182 414 874 1730 3257 5990 11154 21677 44312 93775 200496 424830 882778 1792004 3553021 6889970
-27 56 106 186 321 579 1126 2320 4859 10010 19966 38406 71369 128597 225554 386388 648171 1066828 1725250 2744178 4296545
-3 21 58 118 205 323 476 668 903 1185 1518 1906 2353 2863 3440 4088 4811 5613 6498 7470 8533
-18 33 50 63 69 73 95 194 538 1563 4278 10787 25113 54423 110767 213458 392234 691357 1174818 1932831 3089813
-7 -1 -11 -22 -33 -43 -51 -56 -57 -53 -43 -26 -1 33 77 132 199 279 373 482 607
-13 20 39 92 216 463 900 1609 2687 4246 6413 9330 13154 18057 24226 31863 41185 52424 65827 81656 100188
-23 46 90 174 325 593 1077 1967 3613 6643 12187 22368 41474 78745 154662 314266 653811 1371804 2863764 5894602 11908011
-20 33 51 78 119 185 304 540 1022 1995 3941 7903 16309 34873 76590 169493 370760 791013 1634311 3261484 6287171
-29 46 72 118 201 341 565 933 1616 3082 6483 14384 32034 69449 144658 288555 551903 1015150 1801842 3096554 5168407
--3 -8 -13 -18 -23 -28 -33 -38 -43 -48 -53 -58 -63 -68 -73 -78 -83 -88 -93 -98 -103
-24 49 94 175 315 555 990 1859 3731 7848 16715 35063 71341 139891 263884 478884 836478 1406655 2276400 3540123 5274867
-10 23 39 67 134 294 650 1398 2901 5797 11150 20707 37510 67571 124264 238823 483264 1016695 2172997 4618055 9619058
-9 29 57 98 163 272 465 822 1498 2804 5421 10932 23008 49799 108371 232406 484855 977815 1902601 3573814 6492177
-7 -2 -5 17 99 309 790 1855 4176 9121 19306 39442 77570 146790 267603 470998 802429 1326840 2134909 3350695 5140885
-5 25 54 97 161 251 366 495 613 677 622 357 -239 -1321 -3082 -5757 -9627 -15023 -22330 -31991 -44511
-2 7 17 45 131 360 880 1920 3818 7093 12650 22317 40111 74970 146279 294605 602193 1232162 2504430 5044920 10081198
-25 49 87 140 209 295 399 522 665 829 1015 1224 1457 1715 1999 2310 2649 3017 3415 3844 4305
-14 24 43 86 181 368 711 1346 2613 5375 11735 26556 60512 135901 297189 629280 1287864 2547906 4878395 9053818 16315343
-8 16 17 7 -18 -62 -129 -223 -348 -508 -707 -949 -1238 -1578 -1973 -2427 -2944 -3528 -4183 -4913 -5722
-16 34 58 101 186 346 624 1073 1756 2746 4126 5989 8438 11586 15556 20481 26504 33778 42466 52741 64786
-27 43 67 103 162 275 521 1087 2388 5301 11626 25012 52845 110125 227416 466980 954921 1943671 3930069 7871917 15573412
-8 11 23 53 103 180 320 632 1392 3245 7597 17284 37571 77436 150902 277859 483328 793415 1225235 1766799 2341191
--8 -8 0 32 122 333 779 1674 3438 6911 13758 27204 53351 103562 198851 378040 712850 1335454 2488122 4612280 8509902
-0 -1 -5 -5 23 130 408 1017 2253 4729 9824 20691 44318 95420 203319 423457 855798 1673123 3163121 5789243 10276529
-11 15 19 23 27 31 35 39 43 47 51 55 59 63 67 71 75 79 83 87 91
-15 14 20 54 147 336 660 1162 1909 3055 5012 8898 17671 38877 91013 217640 517478 1206335 2740499 6055505 13016002
-2 -4 -8 3 44 127 256 422 598 734 752 541 -48 -1207 -3176 -6248 -10774 -17168 -25912 -37561 -52748
-23 31 41 74 173 408 878 1710 3055 5081 7963 11870 16949 23306 30984 39938 50007 60883 72077 82882 92333
-7 8 11 18 34 77 197 499 1159 2416 4517 7581 11317 14447 13488 142 -44205 -159640 -429293 -1019020 -2247008
-4 10 16 38 118 343 879 2042 4436 9186 18277 34973 64228 112909 189524 302981 459692 658074 879182 1071832 1130130
-1 13 39 99 237 533 1112 2142 3812 6282 9604 13640 18084 22910 30111 48933 110020 301296 854730 2347394 6148964
-0 -9 -24 -33 4 170 612 1583 3531 7284 14428 28063 54267 104819 202050 387129 733674 1369333 2508936 4504007 7914878
-9 18 33 70 151 311 620 1239 2544 5367 11418 23967 48880 96118 181823 331130 581859 989256 1631967 2619444 4100997
-9 14 23 36 53 74 99 128 161 198 239 284 333 386 443 504 569 638 711 788 869
-13 25 40 66 121 246 529 1140 2377 4723 8914 16018 27525 45448 72435 111892 168117 246445 353404 496882 686305
-14 29 44 61 89 158 351 870 2163 5158 11698 25389 53327 109659 222816 449832 904062 1808185 3595410 7103715 13950703
-14 26 54 98 153 206 227 152 -137 -808 -1966 -3221 -2494 6774 41457 139973 382532 925766 2059406 4295648 8505623
-2 11 23 38 64 123 266 607 1400 3212 7304 16450 36645 80537 174042 368560 762618 1538757 3024209 5787554 10788306
-6 7 25 87 235 526 1032 1840 3052 4785 7171 10357 14505 19792 26410 34566 44482 56395 70557 87235 106711
-10 25 53 116 259 559 1148 2260 4314 8054 14783 26751 47787 84302 146834 252357 427634 713959 1173705 1899174 3024331
-24 36 50 77 148 333 777 1758 3773 7666 14837 27646 50332 91269 168480 322548 645294 1340369 2845850 6073450 12861288
-12 15 30 80 197 426 832 1522 2710 4871 9065 17592 35299 72139 148031 301779 606960 1199651 2326312 4426252 8273793
--2 9 40 101 205 381 700 1323 2596 5241 10727 21960 44523 88856 174043 334351 630474 1168765 2133866 3843451 6838793
-6 10 10 11 36 141 433 1107 2530 5410 11103 22140 43105 82062 152799 278197 494986 859934 1457012 2404133 3856478
-8 15 23 35 51 73 113 211 494 1357 3950 11353 31179 80964 198740 462887 1028108 2188775 4487913 8901223 17143107
-11 15 34 94 233 497 938 1623 2673 4380 7520 14120 29183 64270 144432 320835 692591 1442869 2896390 5607994 10495197
-14 21 31 44 60 79 101 126 154 185 219 256 296 339 385 434 486 541 599 660 724
-26 45 80 156 313 614 1171 2201 4138 7862 15171 29726 58851 116779 230210 447397 852410 1586755 2881154 5101032 8810117
-5 22 64 142 265 438 651 851 889 437 -1121 -4798 -12040 -24526 -43411 -67432 -88895 -85964 -8818 243946 863895
-6 1 -4 -9 -14 -19 -24 -29 -34 -39 -44 -49 -54 -59 -64 -69 -74 -79 -84 -89 -94
-20 26 33 46 69 114 217 462 1032 2346 5427 12820 30700 73352 172059 391723 860467 1820397 3712384 7315609 13976426
-4 15 39 76 126 189 265 354 456 571 699 840 994 1161 1341 1534 1740 1959 2191 2436 2694
--6 1 16 35 54 69 76 71 50 9 -56 -149 -274 -435 -636 -881 -1174 -1519 -1920 -2381 -2906
-14 24 37 64 131 291 650 1405 2899 5716 10865 20131 36696 66145 117960 207556 358812 606880 1000797 1605058 2498809
-0 12 45 125 301 652 1308 2513 4769 9115 17633 34380 67219 131660 259205 515543 1039634 2125851 4392779 9122926 18933417
-11 28 56 98 159 238 330 466 833 2030 5545 14617 35841 82291 179753 379184 781311 1584428 3175934 6305560 12409707
-12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
-0 12 46 115 241 475 938 1891 3842 7698 14970 28039 50491 87529 146470 237335 373540 572696 857526 1256907 1807045
-21 32 62 133 287 597 1173 2168 3798 6414 10727 18424 33672 66451 139366 300661 647715 1367484 2803332 5561663 10676949
-30 45 67 118 231 460 908 1785 3524 7003 13944 27585 53747 102447 190251 343647 603906 1034293 1731257 2842620 4598159
-14 14 16 25 44 69 84 56 -70 -376 -976 -2021 -3704 -6265 -9996 -15246 -22426 -32014 -44560 -60691 -81116
-22 32 43 50 43 7 -78 -237 -500 -902 -1483 -2288 -3367 -4775 -6572 -8823 -11598 -14972 -19025 -23842 -29513
-16 19 21 30 66 171 421 940 1916 3619 6421 10818 17454 27147 40917 60016 85960 120563 165973 224710 299706
-19 37 74 139 242 405 685 1208 2222 4192 7972 15095 28244 52077 94928 172751 318390 602318 1175903 2355506 4775763
--2 4 30 96 231 485 964 1894 3716 7208 13636 24979 44385 77231 133514 232821 413834 751228 1383906 2559754 4703435
-6 24 54 101 184 348 678 1318 2519 4778 9199 18334 37993 80936 174177 373301 790724 1652238 3411282 6979903 14190706
-18 30 50 78 114 158 210 270 338 414 498 590 690 798 914 1038 1170 1310 1458 1614 1778
--1 9 37 102 230 464 893 1714 3360 6761 13869 28692 59269 121305 244607 484069 937821 1776397 3288557 5951958 10540544
-19 42 74 123 209 379 747 1586 3507 7778 16875 35424 71791 140705 267452 494347 890363 1564954 2687232 4511721 7411885
-24 35 48 67 108 223 534 1271 2819 5804 11284 21161 39028 71919 134086 255471 500884 1012731 2103598 4450869 9494278
-19 32 58 110 217 431 847 1654 3240 6379 12533 24307 46100 85000 151976 263425 443137 724746 1154740 1796108 2732707
--7 -2 10 35 101 279 708 1620 3361 6404 11350 18913 29885 45077 65232 90906 122313 159130 200258 243535 285397
-15 31 51 71 86 90 76 36 -39 -159 -335 -579 -904 -1324 -1854 -2510 -3309 -4269 -5409 -6749 -8310
-5 11 34 86 184 367 726 1443 2833 5388 9838 17275 29436 49314 82366 138718 236933 410113 715354 1247868 2161432
-12 33 60 93 132 177 228 285 348 417 492 573 660 753 852 957 1068 1185 1308 1437 1572
-13 11 9 7 5 3 1 -1 -3 -5 -7 -9 -11 -13 -15 -17 -19 -21 -23 -25 -27
-23 47 92 177 331 607 1112 2057 3833 7137 13216 24387 45168 84690 161676 314408 620255 1233543 2458876 4893321 9701941
-7 7 3 -12 -51 -140 -320 -622 -966 -887 1087 8694 30271 82765 198179 433293 883429 1700687 3116311 5465452 9210333
-18 30 54 104 197 357 630 1121 2064 3936 7626 14670 27563 50159 88170 149775 246350 393330 611214 926724 1374129
-4 13 40 106 253 562 1175 2321 4346 7747 13210 21652 34267 52576 78481 114323 162944 227753 312796 422830 563401
-2 16 40 71 111 185 383 936 2336 5511 12077 24733 47983 89624 163920 298208 546006 1011706 1894870 3566283 6693583
-26 38 50 62 74 86 98 110 122 134 146 158 170 182 194 206 218 230 242 254 266
-4 21 45 75 122 216 417 839 1699 3406 6708 12918 24243 44243 78450 135180 226574 369907 589207 917229 1397832
--8 -18 -28 -19 48 236 644 1443 2957 5816 11217 21343 40011 73650 132751 233985 403254 680026 1123410 1820553 2898090
-25 37 64 116 202 337 552 919 1631 3222 7083 16554 39103 90537 202996 439959 924174 1889257 3774365 7396820 14265161
-14 15 23 53 133 320 720 1511 2968 5481 9550 15766 24902 38530 61169 106032 208307 453348 1034086 2366996 5329344
-30 46 73 117 186 303 526 975 1866 3552 6571 11701 20022 32985 52488 80959 121446 177714 254349 356869 491842
-7 2 12 55 167 412 891 1754 3226 5687 9926 17861 34341 71217 155842 347784 770197 1666568 3500268 7124627 14066671
-11 16 33 72 150 308 651 1430 3190 7020 14976 30832 61488 119689 229271 435065 821009 1542132 2879119 5328428 9746750
-5 16 39 82 153 260 411 614 877 1208 1615 2106 2689 3372 4163 5070 6101 7264 8567 10018 11625
-5 11 31 79 169 315 531 831 1229 1739 2375 3151 4081 5179 6459 7935 9621 11531 13679 16079 18745
-11 25 46 83 156 309 628 1264 2461 4589 8182 13981 22982 36489 56172 84130 122959 175825 246542 339655 460528
-7 17 37 84 196 451 987 2032 3962 7409 13440 23822 41377 70415 117212 190474 301697 465297 698343 1019680 1448178
--7 -12 -10 8 63 205 531 1203 2472 4736 8705 15821 29209 55687 109905 222838 459183 952588 1975388 4072510 8309999
--3 -5 -7 -13 -22 -10 102 505 1603 4185 9703 20699 41432 78764 143372 251361 426361 702199 1126245 1763539 2701814
-11 15 20 30 70 208 588 1490 3452 7527 15830 32692 67028 137005 278839 562647 1119837 2188659 4187404 7828486 14291454
--3 -6 -10 -5 27 110 270 529 897 1362 1878 2351 2623 2454 1502 -699 -4763 -11478 -21834 -37053 -58621
-6 28 62 108 166 236 318 412 518 636 766 908 1062 1228 1406 1596 1798 2012 2238 2476 2726
-11 27 62 128 243 438 770 1345 2361 4187 7500 13508 24293 43314 76116 131297 221791 366531 592562 937680 1453679
-9 5 -7 -21 -23 7 93 261 537 945 1505 2231 3129 4195 5413 6753 8169 9597 10953 12131 13001
-8 18 31 65 164 408 930 1951 3844 7238 13173 23317 40256 67868 111792 180003 283504 437146 660587 979401 1426348
-9 17 46 110 238 490 982 1933 3767 7338 14409 28639 57570 116539 236173 476286 950733 1870247 3613656 6842308 12678166
-19 46 93 168 277 424 629 975 1690 3262 6574 13032 24642 43972 73912 117119 175005 246094 323539 391552 420459
-6 5 14 57 173 416 855 1574 2672 4263 6476 9455 13359 18362 24653 32436 41930 53369 67002 83093 101921
-6 22 52 117 248 495 952 1814 3503 6941 14122 29255 60929 126002 256252 509262 985556 1854672 3393664 6042481 10481790
-25 42 74 145 291 563 1034 1806 3009 4780 7206 10211 13363 15573 14654 6704 -14727 -59730 -143438 -287787 -523693
-20 38 83 179 371 744 1462 2840 5462 10358 19253 34901 61517 105320 175200 283522 447080 688214 1036103 1528247 2212151
-9 14 28 57 119 268 642 1549 3610 7985 16717 33240 63110 115033 202281 344606 570783 921936 1455826 2252307 3420185
-6 4 1 9 61 230 656 1594 3521 7391 15228 31435 65523 137497 287977 596446 1213093 2413074 4687570 8895407 16515070
-3 2 11 44 121 268 517 906 1479 2286 3383 4832 6701 9064 12001 15598 19947 25146 31299 38516 46913
-19 38 72 123 193 284 398 537 703 898 1124 1383 1677 2008 2378 2789 3243 3742 4288 4883 5529
-16 21 27 34 42 51 61 72 84 97 111 126 142 159 177 196 216 237 259 282 306
--6 -6 1 28 102 286 716 1653 3550 7134 13503 24238 41530 68322 108466 166895 249810 364882 521469 730848 1006462
-8 3 8 29 74 154 292 566 1238 3067 7976 20352 49440 113652 248414 521011 1059980 2115267 4182768 8260087 16360846
-3 10 36 98 225 469 931 1806 3457 6550 12336 23281 44463 86522 171490 343511 687182 1356910 2621513 4930598 9016916
-24 44 72 113 188 350 705 1436 2842 5429 10133 18849 35662 69680 141457 297259 640012 1394806 3044098 6599693 14135664
-5 23 46 78 141 292 658 1507 3392 7442 15945 33512 69400 142119 288415 580329 1156575 2278345 4425344 8456050 15867759
-19 31 52 91 163 295 542 1029 2058 4356 9601 21479 47778 104593 224933 476452 997557 2069089 4251986 8642359 17327613
-4 2 -3 -11 -22 -36 -53 -73 -96 -122 -151 -183 -218 -256 -297 -341 -388 -438 -491 -547 -606
-1 5 12 26 58 141 367 960 2399 5605 12206 24894 47888 87517 152937 256996 417261 657221 1007680 1508354 2209686
-17 29 58 112 208 382 694 1224 2053 3222 4662 6102 7035 7065 7605 17388 67347 246313 779428 2188570 5601476
-1 21 53 109 230 501 1077 2228 4420 8467 15819 29101 53101 96539 175157 316980 571043 1021497 1809841 3169125 5475384
-6 21 40 63 90 121 156 195 238 285 336 391 450 513 580 651 726 805 888 975 1066
-7 9 18 42 92 195 412 861 1761 3546 7162 14781 31389 68112 148903 323701 694248 1465287 3047717 6268886 12802095
-5 20 44 81 151 296 585 1118 2029 3488 5702 8915 13407 19492 27515 37848 50885 67036 86720 110357 138359
-12 25 48 83 132 197 280 383 508 657 832 1035 1268 1533 1832 2167 2540 2953 3408 3907 4452
-9 18 27 36 45 54 63 72 81 90 99 108 117 126 135 144 153 162 171 180 189
-12 23 45 102 243 557 1202 2475 4967 9867 19502 38226 73800 139437 256721 459647 800070 1354895 2235387 3599030 5664417
-3 17 44 89 158 248 336 379 350 358 952 3825 13369 39967 106658 262035 604149 1323079 2774060 5599113 10923592
-11 23 52 107 213 422 827 1595 3059 5953 11953 24829 52768 112877 239639 500344 1022479 2041031 3978006 7572651 14089435
-12 14 16 19 27 62 192 572 1498 3474 7292 14125 25633 44082 72476 114702 175688 261574 379896 539783 752167
-28 48 74 105 145 217 399 897 2167 5090 11196 22932 43980 79652 137403 227465 363422 562048 840630 1207839 1640299
-16 35 63 113 218 437 868 1681 3189 5980 11135 20566 37573 67968 122808 225380 428334 852889 1772455 3780047 8105492
-1 14 31 52 91 183 397 865 1833 3743 7376 14140 26700 50375 96197 187510 374013 761176 1570593 3260648 6760765
-16 36 75 141 237 369 561 882 1499 2791 5603 11801 25433 55042 118055 248718 511768 1024907 1994131 3767032 6910279
-25 36 52 74 96 98 46 -91 -289 -338 465 3999 14786 43076 111239 266808 608598 1337857 2856019 5946873 12107729
-4 16 35 56 74 84 81 60 16 -56 -161 -304 -490 -724 -1011 -1356 -1764 -2240 -2789 -3416 -4126
-14 24 48 106 223 431 780 1365 2384 4273 8048 16163 34532 76973 174400 392907 867870 1865866 3893160 7879276 15477025
-12 27 58 128 271 536 991 1727 2876 4689 7779 13743 26594 55885 123345 276760 618632 1364483 2959404 6306378 13203773
-21 31 50 96 212 477 1014 1995 3643 6231 10078 15542 23010 32885 45570 61449 80865 104095 131322 162604 197840
-8 19 34 61 115 220 411 736 1258 2057 3232 4903 7213 10330 14449 19794 26620 35215 45902 59041 75031
-2 12 36 82 173 352 698 1374 2748 5657 11931 25388 53717 112108 230379 467029 936622 1861910 3670142 7165434 13827661
-7 31 77 155 276 461 764 1321 2445 4804 9758 20010 40874 82732 165735 328658 645299 1254301 2412331 4586949 8615296
-24 41 66 106 179 327 645 1339 2828 5907 11990 23454 44107 79805 139245 234963 384568 612245 950562 1442618 2144571
-14 17 19 26 55 143 366 890 2091 4810 10865 24038 51904 109084 222798 441980 851708 1595311 2907255 5160794 8935413
-5 16 32 63 132 279 569 1104 2039 3602 6118 10037 15966 24705 37287 55022 79545 112868 157436 216187 292616
-18 32 53 81 120 198 401 921 2118 4596 9293 17585 31404 53370 86937 136553 207834 307752 444837 629393 873728
-16 28 45 64 92 167 391 975 2296 4966 9913 18474 32500 54473 87635 136129 205152 301120 431845 606724 836940
-8 4 -7 -24 -39 -36 10 134 382 812 1495 2516 3975 5988 8688 12226 16772 22516 29669 38464 49157
-10 15 29 73 187 432 885 1625 2720 4258 6537 10673 20162 44465 106733 259895 619607 1431209 3205097 6988666 14907396
-15 24 40 64 113 230 501 1094 2350 4987 10534 22215 46697 97476 201316 410242 823341 1625334 3152912 6005630 11226269
-0 5 11 31 94 258 631 1405 2924 5837 11436 22352 43884 86372 169200 327234 620768 1150373 2078425 3659533 6282602
--1 9 40 102 205 359 574 860 1227 1685 2244 2914 3705 4627 5690 6904 8279 9825 11552 13470 15589
-0 3 14 33 60 95 138 189 248 315 390 473 564 663 770 885 1008 1139 1278 1425 1580
-3 17 38 81 176 380 802 1643 3253 6207 11402 20177 34458 56930 91238 142219 216167 321133 467262 667169 936356
-14 20 30 44 62 84 110 140 174 212 254 300 350 404 462 524 590 660 734 812 894
-17 31 64 121 211 349 556 867 1374 2370 4737 10865 26662 65748 157985 366599 822270 1790440 3802693 7909280 16155408
-15 36 84 186 389 774 1490 2822 5321 10057 19112 36507 69844 133018 250377 462624 834487 1463630 2489312 4097764 6518955
-13 26 40 57 79 108 146 195 257 334 428 541 675 832 1014 1223 1461 1730 2032 2369 2743
-6 11 20 33 50 71 96 125 158 195 236 281 330 383 440 501 566 635 708 785 866
--3 -2 8 37 95 192 338 543 817 1170 1612 2153 2803 3572 4470 5507 6693 8038 9552 11245 13127
-10 7 1 -9 -28 -57 -71 22 445 1647 4458 10395 22371 46454 96245 203494 442940 988347 2234695 5056651 11340670
-12 25 41 69 133 288 656 1489 3269 6859 13719 26195 47893 84220 143433 239205 397142 670373 1173977 2155505 4130321
--6 -9 -1 41 156 405 877 1695 3022 5067 8091 12413 18416 26553 37353 51427 69474 92287 120759 155889 198788
-10 27 63 135 272 524 984 1825 3348 6031 10571 17947 29674 48841 83611 157378 335212 787365 1932553 4745545 11391002
-15 25 43 69 103 145 195 253 319 393 475 565 663 769 883 1005 1135 1273 1419 1573 1735
-26 43 79 142 244 415 734 1402 2895 6248 13534 28615 58255 113698 212827 383033 664936 1117113 1822001 2893156 4484062
-19 31 41 57 109 262 642 1485 3217 6575 12787 23852 43034 75899 132773 234729 427691 813843 1617513 3315823 6885023
-11 38 88 177 326 561 913 1418 2117 3056 4286 5863 7848 10307 13311 16936 21263 26378 32372 39341 47386
-14 25 50 106 231 497 1030 2036 3827 6844 11699 19336 31610 53040 95472 189401 409732 931699 2148060 4908018 10992969
-2 10 29 66 130 228 359 501 592 521 166 -458 -840 769 8798 33101 94134 232596 527027 1125106 2297718
-10 16 24 34 55 128 377 1110 3015 7538 17609 39032 83124 171641 345765 682118 1320736 2513256 4705242 8676246 15777445
--1 0 16 62 165 371 752 1413 2499 4202 6768 10504 15785 23061 32864 45815 62631 84132 111248 145026 186637
-4 13 45 119 276 588 1169 2198 3969 6991 12172 21141 36826 64615 114998 209912 397708 786612 1614895 3388996 7139814
-5 21 44 84 159 301 579 1152 2369 4937 10182 20432 39555 73689 132205 228948 383805 624653 989744 1530588 2315399
-11 19 36 73 161 362 774 1536 2861 5159 9363 17649 34861 71134 146477 298464 594719 1152611 2169542 3968465 7064865
-20 46 96 190 372 723 1368 2468 4196 6726 10325 15739 25214 44701 88069 184501 390684 809934 1621030 3120276 5781176
-1 5 21 65 173 422 972 2139 4513 9138 17782 33365 60715 108031 189805 332561 585689 1040979 1866299 3361329 6046489
-16 42 87 160 272 436 667 982 1400 1942 2631 3492 4552 5840 7387 9226 11392 13922 16855 20232 24096
-15 26 57 134 309 681 1425 2829 5339 9612 16577 27504 44081 68499 103545 152703 220263 311438 432489 590858 795309
-7 17 37 79 165 343 715 1480 2993 5839 10919 19543 33523 55257 87793 134860 200851 290741 409921 563927 758041
-8 18 45 98 186 318 503 750 1068 1466 1953 2538 3230 4038 4971 6038 7248 8610 10133 11826 13698
-22 41 74 129 229 436 893 1897 4024 8347 16841 33195 64519 124952 243102 476797 941082 1859125 3651148 7084229 13516489
-7 17 42 99 225 488 1004 1985 3868 7619 15373 31669 65692 135206 273401 540985 1048083 1994786 3746952 6977130 12921917
-8 15 38 96 232 520 1072 2056 3755 6726 12167 22697 43966 87990 180160 374080 782726 1642429 3440152 7157650 14722640
-9 30 64 108 169 286 561 1193 2519 5090 9842 18456 34031 62212 112905 202639 357449 615843 1031246 1674479 2642949
-7 14 32 61 101 152 214 287 371 466 572 689 817 956 1106 1267 1439 1622 1816 2021 2237"
+    #[test]
+    fn part1_input() {
+        assert_eq!(1_955_513_104, part1(&Day9::parse(&input(9)).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(2, part2(&Day9::parse(&example(9)).unwrap()));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(1131, part2(&Day9::parse(&input(9)).unwrap()));
+    }
 }
@@ -0,0 +1,408 @@
+//! Points, directions and the arithmetic between them. The y-axis points down, like the rows of
+//! a puzzle input, so `Direction::Up` decreases y.
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// The integer types that can be used as coordinates.
+pub trait Integer:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + Ord
+    + Hash
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The distance between two values, which never underflows for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        match self > other {
+            true => self - other,
+            false => other - self,
+        }
+    }
+}
+
+/// The integer types that can be negative, and so can represent every direction.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! integer {
+    ($($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$type>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for isize {}
+
+/// One of the 4 orthogonal directions, listed clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The point one step away from the origin in this direction.
+    pub fn unit<T: Signed>(self) -> Point2<T> {
+        match self {
+            Direction::Up => Point2::new(T::ZERO, -T::ONE),
+            Direction::Right => Point2::new(T::ONE, T::ZERO),
+            Direction::Down => Point2::new(T::ZERO, T::ONE),
+            Direction::Left => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+
+    /// The offset of a step in this direction, as used by [`crate::grid::Grid::offset`].
+    pub fn offset(self) -> (isize, isize) {
+        self.unit::<isize>().into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Integer> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The neighbour in the given direction, unless it can't be represented, such as when
+    /// stepping left from `x = 0` with an unsigned type.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let Point2 { x, y } = self;
+
+        match direction {
+            Direction::Up => Some(Point2::new(x, y.checked_sub(T::ONE)?)),
+            Direction::Right => Some(Point2::new(x.checked_add(T::ONE)?, y)),
+            Direction::Down => Some(Point2::new(x, y.checked_add(T::ONE)?)),
+            Direction::Left => Some(Point2::new(x.checked_sub(T::ONE)?, y)),
+        }
+    }
+
+    /// The direction in which `other` lies, if it's on the same row or column.
+    pub fn direction_to(self, other: Self) -> Option<Direction> {
+        if self.x == other.x && other.y < self.y {
+            Some(Direction::Up)
+        } else if self.y == other.y && other.x > self.x {
+            Some(Direction::Right)
+        } else if self.x == other.x && other.y > self.y {
+            Some(Direction::Down)
+        } else if self.y == other.y && other.x < self.x {
+            Some(Direction::Left)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the coordinates to another integer type, if they fit.
+    pub fn cast<U: Integer + TryFrom<T>>(self) -> Option<Point2<U>> {
+        Some(Point2::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Signed> Point2<T> {
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.unit()
+    }
+
+    /// The 4 orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+}
+
+impl<T: Integer> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Signed> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Integer> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    /// The point without its z coordinate.
+    pub fn xy(self) -> Point2<T> {
+        Point2::new(self.x, self.y)
+    }
+
+    /// Converts the coordinates to another integer type, if they fit.
+    pub fn cast<U: Integer + TryFrom<T>>(self) -> Option<Point3<U>> {
+        Some(Point3::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+            U::try_from(self.z).ok()?,
+        ))
+    }
+}
+
+impl<T: Integer> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Signed> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Integer> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(direction.reverse().unit::<i32>(), -direction.unit::<i32>());
+        }
+
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!((0, -1), Direction::Up.offset());
+    }
+
+    #[test]
+    fn does_arithmetic() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+
+        assert_eq!(Point2::new(2, 2), a + b);
+        assert_eq!(Point2::new(4, -6), a - b);
+        assert_eq!(Point2::new(9, -6), a * 3);
+        assert_eq!(10, a.manhattan(b));
+        assert_eq!(
+            Point3::new(1, 2, 3),
+            Point3::new(3, 3, 3) - Point3::new(2, 1, 0)
+        );
+        assert_eq!(6, Point3::new(1u8, 2, 3).manhattan(Point3::new(3, 1, 0)));
+    }
+
+    #[test]
+    fn steps_in_directions() {
+        let origin = Point2::<usize>::new(0, 0);
+
+        assert_eq!(None, origin.checked_step(Direction::Left));
+        assert_eq!(
+            Some(Point2::new(0, 1)),
+            origin.checked_step(Direction::Down)
+        );
+        assert_eq!(Point2::new(1, 0), Point2::new(1, 1).step(Direction::Up));
+        assert_eq!(
+            Some(Direction::Left),
+            Point2::new(5, 2).direction_to(Point2::new(1, 2))
+        );
+        assert_eq!(None, Point2::new(5, 2).direction_to(Point2::new(1, 1)));
+    }
+
+    #[test]
+    fn converts_between_types() {
+        assert_eq!(Some(Point2::new(3usize, 4)), Point2::new(3i64, 4).cast());
+        assert_eq!(None, Point2::new(-1i64, 4).cast::<usize>());
+        assert_eq!((3, 4), Point2::new(3, 4).into());
+        assert_eq!(Point3::new(1, 2, 3), (1, 2, 3).into());
+    }
+}
@@ -0,0 +1,346 @@
+//! Searches over graphs that are described by a function listing the neighbours of a state, so
+//! the graph never has to be built up front. Searches remember the predecessor of every state
+//! they reach rather than the whole path to it, and rebuild paths on request.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a search found: the cost of the cheapest known way to every state it reached, and the
+/// predecessor of the state on that way.
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    reached: HashMap<S, (C, Option<S>)>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    /// The goal the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.reached.get(state).map(|(cost, _)| *cost)
+    }
+
+    /// Every state the search reached, with its cost.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, C)> {
+        self.reached.iter().map(|(state, (cost, _))| (state, *cost))
+    }
+
+    /// The states from the start up to and including the given one.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut predecessor = self.reached.get(state)?.1.clone();
+
+        while let Some(state) = predecessor {
+            predecessor = self.reached[&state].1.clone();
+            path.push(state);
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    fn new(start: S, zero: C) -> Self {
+        Paths {
+            reached: HashMap::from([(start, (zero, None))]),
+            goal: None,
+        }
+    }
+}
+
+/// Breadth-first search, where every step costs 1. Stops as soon as a goal is reached, pass
+/// `|_| false` to visit everything that can be reached.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+
+    if is_goal(&start) {
+        paths.goal = Some(start);
+        return paths;
+    }
+
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, steps)) = queue.pop_front() {
+        for next in neighbours(&state) {
+            if paths.reached.contains_key(&next) {
+                continue;
+            }
+
+            paths
+                .reached
+                .insert(next.clone(), (steps + 1, Some(state.clone())));
+
+            if is_goal(&next) {
+                paths.goal = Some(next);
+                return paths;
+            }
+
+            queue.push_back((next, steps + 1));
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, for neighbours that come with the cost of moving to them. Stops as soon
+/// as the cheapest way to a goal is known.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, where `heuristic` estimates the remaining cost to a goal. The estimate may never
+/// exceed the actual cost, or the path that is found might not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(Candidate { cost, state, .. }) = queue.pop() {
+        if paths.reached[&state].0 < cost {
+            // A cheaper way to this state was found after this one was queued.
+            continue;
+        }
+
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let cost = cost + step;
+
+            if paths
+                .reached
+                .get(&next)
+                .is_some_and(|&(known, _)| known <= cost)
+            {
+                continue;
+            }
+
+            paths
+                .reached
+                .insert(next.clone(), (cost, Some(state.clone())));
+            queue.push(Candidate {
+                estimate: cost + heuristic(&next),
+                cost,
+                state: next,
+            });
+        }
+    }
+
+    paths
+}
+
+/// The most expensive path from `start` to a goal that visits no state twice, with its cost.
+/// Paths end at the first goal they reach. Every such path is tried, so this is only feasible
+/// for small or very constrained graphs.
+pub fn longest_path<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    if is_goal(&start) {
+        return Some((C::default(), vec![start]));
+    }
+
+    let mut longest: Option<(C, Vec<S>)> = None;
+
+    // The path that is being explored, with the cost up to each state and the neighbours of each
+    // state that are yet to be tried.
+    let mut path = vec![start.clone()];
+    let mut costs = vec![C::default()];
+    let mut untried = vec![neighbours(&start).into_iter()];
+    let mut on_path = HashSet::from([start]);
+
+    while let Some(options) = untried.last_mut() {
+        let Some((next, step)) = options.next() else {
+            untried.pop();
+            costs.pop();
+            on_path.remove(&path.pop().unwrap());
+            continue;
+        };
+
+        if on_path.contains(&next) {
+            continue;
+        }
+
+        let cost = *costs.last().unwrap() + step;
+
+        if is_goal(&next) {
+            if longest.as_ref().is_none_or(|(best, _)| cost > *best) {
+                let mut best_path = path.clone();
+                best_path.push(next);
+                longest = Some((cost, best_path));
+            }
+
+            continue;
+        }
+
+        untried.push(neighbours(&next).into_iter());
+        costs.push(cost);
+        on_path.insert(next.clone());
+        path.push(next);
+    }
+
+    longest
+}
+
+/// An entry in the queue of [`astar`], ordered so the lowest estimate is popped first.
+struct Candidate<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Candidate<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Candidate<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Candidate<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Candidate<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph:
+    ///
+    /// ```text
+    /// a -1- b -1- c
+    ///  \         /
+    ///   ----5----
+    /// ```
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('a', 5), ('b', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn bfs_finds_the_fewest_steps() {
+        let paths = bfs('a', unweighted, |&node| node == 'c');
+
+        assert_eq!(Some(&'c'), paths.goal());
+        assert_eq!(Some(1), paths.cost(&'c'));
+        assert_eq!(Some(vec!['a', 'c']), paths.path(&'c'));
+    }
+
+    #[test]
+    fn bfs_visits_everything_without_a_goal() {
+        let paths = bfs('a', unweighted, |_| false);
+
+        assert_eq!(None, paths.goal());
+        assert_eq!(3, paths.reached().count());
+        assert_eq!(None, paths.path(&'z'));
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let paths = dijkstra('a', weighted, |&node| node == 'c');
+
+        assert_eq!(Some(2), paths.cost(&'c'));
+        assert_eq!(Some(vec!['a', 'b', 'c']), paths.path(&'c'));
+    }
+
+    #[test]
+    fn astar_finds_the_cheapest_path_on_a_grid() {
+        // A 10x10 grid with a wall at x = 5, except at y = 9.
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .filter(|&(x, y)| x != 5 || y == 9)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal = (9, 0);
+
+        let paths = astar(
+            (0, 0),
+            neighbours,
+            |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&state| state == goal,
+        );
+
+        assert_eq!(Some(27), paths.cost(&goal));
+        assert_eq!(28, paths.path(&goal).unwrap().len());
+    }
+
+    #[test]
+    fn finds_the_longest_path() {
+        assert_eq!(
+            Some((6, vec!['b', 'a', 'c'])),
+            longest_path('b', weighted, |&node| node == 'c')
+        );
+        assert_eq!(
+            Some((5, vec!['a', 'c'])),
+            longest_path('a', weighted, |&node| node == 'c')
+        );
+        assert_eq!(None, longest_path('a', weighted, |&node| node == 'z'));
+    }
+}
@@ -0,0 +1,369 @@
+//! A rectangular grid of cells, as used by most of the puzzles that have a map as input.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::Direction,
+    parsing::{self, ParseError},
+};
+
+/// The `(x, y)` position of a cell, where `(0, 0)` is the top left cell.
+pub type Position = (usize, usize);
+
+/// The offsets of the 4 orthogonal neighbours: left, right, up and down.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// The offsets of all 8 neighbours, row by row.
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A grid that stores its cells row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "A {width}x{height} grid needs {} cells",
+            width * height
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a cell per character, and a row per line. Fails on characters that
+    /// `parse_cell` doesn't recognise, and when the lines don't all have the same length.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in parsing::lines(input) {
+            let before = cells.len();
+
+            for (index, char) in line.char_indices() {
+                let cell = parse_cell(char).ok_or_else(|| {
+                    let snippet = &line[index..index + char.len_utf8()];
+                    ParseError::new(snippet, format!("unexpected '{char}'"))
+                })?;
+
+                cells.push(cell);
+            }
+
+            let line_width = cells.len() - before;
+            let width = *width.get_or_insert(line_width);
+
+            if width != line_width {
+                return Err(ParseError::new(
+                    line,
+                    format!(
+                        "this line has {line_width} cells, but the lines before it have {width}"
+                    ),
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[self.index(position)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => {
+                let index = self.index(position);
+                Some(&mut self.cells[index])
+            }
+            false => None,
+        }
+    }
+
+    pub fn swap(&mut self, a: Position, b: Position) {
+        let (a, b) = (self.index(a), self.index(b));
+        self.cells.swap(a, b);
+    }
+
+    /// The position `offset` away from the given one, if it's within the grid.
+    pub fn offset(&self, position: Position, offset: (isize, isize)) -> Option<Position> {
+        offset_within(position, offset, self.width, self.height)
+    }
+
+    /// The neighbour in the given direction, if it's within the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.offset())
+    }
+
+    /// The left, right, upper and lower neighbours of a position that are within the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> {
+        neighbours4(position, self.width, self.height)
+    }
+
+    /// All neighbours of a position that are within the grid, diagonals included.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> {
+        let (width, height) = (self.width, self.height);
+
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |offset| offset_within(position, offset, width, height))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches the predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside of the grid");
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index(&self, (x, y): Position) -> usize {
+        assert!(
+            self.contains((x, y)),
+            "({x}, {y}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        y * self.width + x
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid where every cell has the same value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Mirrors the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| self[(x, y)].clone()))
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The left, right, upper and lower neighbours of a position within a `width` by `height` area,
+/// for areas that are too large to store as a [`Grid`].
+pub fn neighbours4(
+    position: Position,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Position> {
+    ORTHOGONAL
+        .into_iter()
+        .filter_map(move |offset| offset_within(position, offset, width, height))
+}
+
+fn offset_within(
+    (x, y): Position,
+    (dx, dy): (isize, isize),
+    width: usize,
+    height: usize,
+) -> Option<Position> {
+    let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+    let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+
+    Some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_of_characters() {
+        let grid = grid();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(Some((1, 1)), grid.position(|&char| char == 'e'));
+        assert_eq!("abc\ndef\n", grid.to_string());
+    }
+
+    #[test]
+    fn refuses_ragged_rows() {
+        let error = Grid::parse("abc\nde", Some).unwrap_err();
+
+        assert_eq!(
+            "this line has 2 cells, but the lines before it have 3",
+            error.message
+        );
+        assert_eq!("de", error.snippet);
+    }
+
+    #[test]
+    fn refuses_unknown_cells() {
+        let error = Grid::parse("ab\nc?", |char| char.is_alphabetic().then_some(char))
+            .unwrap_err()
+            .locate("ab\nc?");
+
+        assert_eq!("unexpected '?'", error.message);
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+    #[test]
+    fn finds_neighbours_within_the_grid() {
+        let grid = grid();
+
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (2, 0), (0, 1), (2, 1)],
+            grid.neighbours8((1, 1))
+                .filter(|&position| position != (1, 0))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbours8((1, 0)).count());
+        assert_eq!(None, grid.offset((2, 1), (1, 0)));
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(vec!['b', 'e'], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = grid();
+
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_counter_clockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counter_clockwise());
+    }
+}
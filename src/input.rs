@@ -0,0 +1,133 @@
+use std::{
+    convert::Infallible,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::YEAR;
+
+/// The directory that holds the puzzle inputs, relative to the working directory.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/dayNN.txt`.
+    Default,
+    /// An explicit file, given with `--input PATH`.
+    File(PathBuf),
+    /// Standard input, given with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        match self {
+            InputSource::Default => read_file(&default_path(day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| format!("Could not read the input from stdin: {error}"))?;
+
+                Ok(input)
+            }
+        }
+    }
+
+    /// The name of the input in `answers.toml`, when it's one of the inputs of the day.
+    pub fn name(&self, day: u8) -> Option<String> {
+        let path = match self {
+            InputSource::Default => return Some("input".to_string()),
+            InputSource::File(path) => fs::canonicalize(path).ok()?,
+            InputSource::Stdin => return None,
+        };
+
+        named_inputs(day)
+            .into_iter()
+            .find(|(_, candidate)| fs::canonicalize(candidate).is_ok_and(|other| other == path))
+            .map(|(name, _)| name)
+    }
+}
+
+/// The SHA-256 of the input as lowercase hex, to tell inputs apart without sharing them.
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Ok(match raw {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// The path of the puzzle input for the given day, e.g. `inputs/day07.txt`.
+pub fn default_path(day: u8) -> PathBuf {
+    default_path_in(YEAR, day)
+}
+
+/// The path of an example of the given day. The first example is stored in
+/// `inputs/examples/dayNN.txt`, any further ones in `inputs/examples/dayNN-2.txt` and so on.
+pub fn example_path(day: u8, number: u8) -> PathBuf {
+    example_path_in(YEAR, day, number)
+}
+
+/// The directory with the inputs of a year: `inputs/` for the year of this repository, and
+/// `inputs/YYYY/` for days of other years that were added with `aoc new-day --year`.
+pub fn year_dir(year: u16) -> PathBuf {
+    match year {
+        YEAR => PathBuf::from(INPUT_DIR),
+        year => PathBuf::from(INPUT_DIR).join(year.to_string()),
+    }
+}
+
+/// Like [`default_path`], for a day of any year.
+pub fn default_path_in(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day{day:02}.txt"))
+}
+
+/// Like [`example_path`], for a day of any year.
+pub fn example_path_in(year: u16, day: u8, number: u8) -> PathBuf {
+    let name = match number {
+        1 => format!("day{day:02}.txt"),
+        number => format!("day{day:02}-{number}.txt"),
+    };
+
+    year_dir(year).join("examples").join(name)
+}
+
+/// Every input of the given day that exists, named `input` for the puzzle input and `example`,
+/// `example-2`, ... for the examples.
+pub fn named_inputs(day: u8) -> Vec<(String, PathBuf)> {
+    let examples = (1..)
+        .map(|number| match number {
+            1 => ("example".to_string(), example_path(day, number)),
+            number => (format!("example-{number}"), example_path(day, number)),
+        })
+        .take_while(|(_, path)| path.exists());
+
+    Some(("input".to_string(), default_path(day)))
+        .filter(|(_, path)| path.exists())
+        .into_iter()
+        .chain(examples)
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error| format!("Could not read the input from {}: {error}", path.display()))
+}
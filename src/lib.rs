@@ -0,0 +1,85 @@
+pub mod animation;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod parsing;
+pub mod progress;
+pub mod render;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+mod testing;
+pub mod watcher;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub use input::InputSource;
+pub use parsing::ParseError;
+pub use solution::{Answer, Failure, Part, PartOutcome, Puzzle, Solution};
+
+/// The year of the advent calendar these are the solutions for.
+pub const YEAR: u16 = 2023;
+
+/// Every day that has been solved so far, in calendar order.
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn puzzle(day: u8) -> Option<&'static dyn Puzzle> {
+    PUZZLES.iter().copied().find(|puzzle| puzzle.day() == day)
+}
@@ -0,0 +1,221 @@
+//! Levelled logging on top of the `log` crate. Every module logs to its own target, named after
+//! the module without the crate name, so `aoc --log day17=trace run 17` only shows the details of
+//! day 17. Log lines go to stderr, which keeps the answers on stdout clean.
+
+use std::str::FromStr;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Sets the level of a single target, like `day17=trace`, or of every target, like `debug`. A
+/// target without a level logs everything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive {
+    target: Option<String>,
+    level: LevelFilter,
+}
+
+impl FromStr for Directive {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let level = |level: &str| {
+            level.parse::<LevelFilter>().map_err(|_| {
+                format!(
+                    "Unknown log level '{level}', expected off, error, warn, info, debug or trace"
+                )
+            })
+        };
+
+        match value.split_once('=') {
+            Some((target, value)) => Ok(Directive {
+                target: Some(target.to_string()),
+                level: level(value)?,
+            }),
+            None => match level(value) {
+                Ok(level) => Ok(Directive {
+                    target: None,
+                    level,
+                }),
+                Err(_) => Ok(Directive {
+                    target: Some(value.to_string()),
+                    level: LevelFilter::Trace,
+                }),
+            },
+        }
+    }
+}
+
+/// The target of the progress lines of searches, which is shown at info level by default.
+pub const PROGRESS: &str = "progress";
+
+/// The level every target logs at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    /// Only warnings and errors by default, and a level more for every `-v`, followed by the
+    /// directives. Later directives win over earlier ones. Progress is always shown, unless a
+    /// directive for [`PROGRESS`] says otherwise.
+    pub fn new(verbosity: u8, directives: &[Directive]) -> Filter {
+        let default = match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+
+        let mut filter = Filter {
+            default,
+            targets: vec![(PROGRESS.to_string(), default.max(LevelFilter::Info))],
+        };
+
+        for directive in directives {
+            match &directive.target {
+                Some(target) => filter.targets.push((target.clone(), directive.level)),
+                None => filter.default = directive.level,
+            }
+        }
+
+        filter
+    }
+
+    /// The level of the target, from the last directive for it or for a module it's part of.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        let target = name(target);
+
+        self.targets
+            .iter()
+            .rev()
+            .find(|(prefix, _)| {
+                target == prefix
+                    || target
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    fn max(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+/// Sends the log lines that pass the filter to stderr. Only the first call has any effect.
+pub fn init(filter: Filter) {
+    let max = filter.max();
+
+    if log::set_logger(Box::leak(Box::new(Logger { filter }))).is_ok() {
+        log::set_max_level(max);
+    }
+}
+
+struct Logger {
+    filter: Filter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{:<5} {}: {}",
+                record.level(),
+                name(record.target()),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// The name of the target without the name of this crate, so `aoc::day17` is just `day17`.
+fn name(target: &str) -> &str {
+    target
+        .strip_prefix(concat!(env!("CARGO_CRATE_NAME"), "::"))
+        .unwrap_or(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(verbosity: u8, directives: &[&str]) -> Filter {
+        let directives: Vec<Directive> = directives
+            .iter()
+            .map(|directive| directive.parse().unwrap())
+            .collect();
+
+        Filter::new(verbosity, &directives)
+    }
+
+    #[test]
+    fn parses_directives() {
+        assert_eq!(
+            Ok(Directive {
+                target: Some("day17".to_string()),
+                level: LevelFilter::Trace
+            }),
+            "day17=trace".parse()
+        );
+        assert_eq!(
+            Ok(Directive {
+                target: None,
+                level: LevelFilter::Debug
+            }),
+            "DEBUG".parse()
+        );
+        assert_eq!(
+            Ok(Directive {
+                target: Some("day5".to_string()),
+                level: LevelFilter::Trace
+            }),
+            "day5".parse()
+        );
+        assert!("day5=loud".parse::<Directive>().is_err());
+    }
+
+    #[test]
+    fn raises_the_level_for_every_v() {
+        assert_eq!(LevelFilter::Warn, filter(0, &[]).level("aoc::day5"));
+        assert_eq!(LevelFilter::Debug, filter(2, &[]).level("aoc::day5"));
+        assert_eq!(LevelFilter::Trace, filter(7, &[]).level("aoc::day5"));
+    }
+
+    #[test]
+    fn picks_the_level_of_the_day() {
+        let filter = filter(1, &["day1=debug", "day17=trace", "day1=off"]);
+
+        assert_eq!(LevelFilter::Trace, filter.level("aoc::day17"));
+        assert_eq!(LevelFilter::Trace, filter.level("aoc::day17::paths"));
+        assert_eq!(LevelFilter::Off, filter.level("aoc::day1"));
+        assert_eq!(LevelFilter::Info, filter.level("aoc::day10"));
+        assert_eq!(LevelFilter::Info, filter.level("ureq"));
+        assert_eq!(LevelFilter::Trace, filter.max());
+    }
+
+    #[test]
+    fn shows_progress_unless_told_otherwise() {
+        assert_eq!(
+            LevelFilter::Info,
+            filter(0, &[]).level("aoc::progress::day5")
+        );
+        assert_eq!(
+            LevelFilter::Trace,
+            filter(3, &[]).level("aoc::progress::day5")
+        );
+        assert_eq!(
+            LevelFilter::Off,
+            filter(0, &["progress::day5=off"]).level("aoc::progress::day5")
+        );
+    }
+}
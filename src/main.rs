@@ -1,3 +1,1227 @@
-fn main() {
-    println!("Hello, advent of code!");
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    animation::{self, Recording},
+    answers::{Expected, Registry, Verdict, ANSWERS_FILE},
+    bench::{Stage, Stats},
+    client::{self, Client},
+    day1::{self, Vocabulary},
+    day2::{self, Cubes},
+    day3::{self, Classifier, Neighbours},
+    logging::{self, Directive, Filter},
+    progress::{self, LogLines},
+    render::{Crop, Format as ImageFormat},
+    Answer, Failure, InputSource, Part, PartOutcome, Puzzle,
+};
+use clap::{ArgAction, ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log more of what the solutions are doing: -v for info, -vv for debug and -vvv for trace.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Set the log level of a day, like `day17=trace`, or of everything, like `debug`.
+    #[arg(long, value_name = "DIRECTIVES", value_delimiter = ',', global = true)]
+    log: Vec<Directive>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the given days, e.g. `17`, `1..=25`, `3,5,7` or `all`.
+    Run {
+        /// Only solve this part of every day.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead of `inputs/dayNN.txt`, or from stdin when `-`.
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+
+        /// Draw the input of the day to a .png, .ppm or .svg file, for days 10, 11, 17 and 18.
+        #[arg(long, value_name = "PATH")]
+        render: Option<PathBuf>,
+
+        /// Record the simulation of the day as a .gif file, or as numbered .png files in the
+        /// given directory, for days 14, 16, 21 and 22.
+        #[arg(long, value_name = "PATH")]
+        animate: Option<PathBuf>,
+
+        /// Leave out this many steps of the simulation after every frame of the animation.
+        #[arg(long, value_name = "STEPS", default_value_t = 0, requires = "animate")]
+        frame_skip: usize,
+
+        /// Only animate these cells, given as `x,y,width,height`.
+        #[arg(long, value_name = "X,Y,WIDTH,HEIGHT", requires = "animate")]
+        crop: Option<Crop>,
+
+        #[command(flatten)]
+        budget: Budget,
+
+        /// Print the answers as sentences, or as a JSON array with a record per part.
+        #[arg(long, value_enum, default_value_t = RunFormat::Text, conflicts_with_all = ["render", "animate"])]
+        format: RunFormat,
+
+        /// Solve this many days at the same time. Their answers are still printed in order.
+        #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        jobs: usize,
+
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: Vec<Vec<u8>>,
+    },
+    /// Solve every input of the given days and compare the answers to `answers.toml`.
+    Check {
+        /// Only check this part of every day.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Write answers that aren't in `answers.toml` yet to it.
+        #[arg(long)]
+        record: bool,
+
+        #[command(flatten)]
+        budget: Budget,
+
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: Vec<Vec<u8>>,
+    },
+    /// Time parsing and solving the given days, to catch performance regressions.
+    Bench {
+        /// Only time this part of every day.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Untimed runs before the timed ones.
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+
+        /// Timed runs of every stage.
+        #[arg(long, default_value_t = 10, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        iterations: usize,
+
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+
+        #[command(flatten)]
+        budget: Budget,
+
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: Vec<Vec<u8>>,
+    },
+    /// Look into a day beyond the answers to its parts.
+    Explore {
+        #[command(subcommand)]
+        day: Explore,
+    },
+    /// Create `src/dayN.rs` from `src/dayx.stub`, with empty input files, and register the day.
+    /// Days of other years go in `src/yYYYY/` instead.
+    NewDay {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Days of other years go in `src/yYYYY/`, with their inputs in `inputs/YYYY/`.
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+    },
+    /// Download the inputs of the given days to `inputs/`, unless they're already there.
+    Fetch {
+        #[command(flatten)]
+        website: Website,
+
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: Vec<Vec<u8>>,
+    },
+    /// Submit an answer, or the answer to `inputs/dayNN.txt` when none is given.
+    Submit {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: Option<String>,
+
+        #[command(flatten)]
+        website: Website,
+    },
+}
+
+#[derive(Subcommand)]
+enum Explore {
+    /// Sum the calibration values of day 1, with the words of another vocabulary.
+    Day1 {
+        /// A TOML file with a value per word, like `een = 1`, instead of `one` through `nine`.
+        #[arg(long, value_name = "PATH")]
+        vocabulary: Option<PathBuf>,
+
+        /// Print every line with its calibration value and the digits that were found in it.
+        #[arg(long)]
+        explain: bool,
+
+        /// Read the input from this file instead of `inputs/day01.txt`, or from stdin when `-`.
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+    /// Find out which bags of cubes the games of day 2 could have been played with.
+    #[command(group(ArgGroup::new("query").required(true).multiple(true)))]
+    Day2 {
+        /// List the games that are possible with this bag, like `12 red, 13 green, 14 blue`.
+        #[arg(long, value_name = "CUBES", group = "query")]
+        bag: Option<Cubes>,
+
+        /// Print the fewest cubes of every colour that this game could have been played with.
+        #[arg(long, value_name = "GAME", group = "query")]
+        minimum_bag: Option<u32>,
+
+        /// Print the bag with the fewest cubes that this many games are possible with.
+        #[arg(long, value_name = "GAMES", group = "query")]
+        smallest_bag_for: Option<usize>,
+
+        /// Read the input from this file instead of `inputs/day02.txt`, or from stdin when `-`.
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+    /// List the symbols of the day 3 schematic with the numbers next to them, by type of symbol,
+    /// followed by the numbers that are next to more than one symbol.
+    Day3 {
+        /// Only list the symbols that are next to exactly this many numbers.
+        #[arg(long, value_name = "N")]
+        exactly: Option<usize>,
+
+        /// Print the graph of numbers and symbols as JSON instead.
+        #[arg(long, conflicts_with = "exactly")]
+        json: bool,
+
+        /// Only these characters are symbols, instead of everything that isn't a digit or a `.`.
+        #[arg(long, value_name = "CHARS")]
+        symbols: Option<String>,
+
+        /// Only the characters of this Unicode general category are symbols, like `P` or `Sm`.
+        #[arg(long, value_name = "NAME", conflicts_with = "symbols")]
+        symbol_category: Option<String>,
+
+        /// Whether the diagonals around a number are next to it (8), or not (4).
+        #[arg(long, value_name = "4|8", default_value = "8")]
+        neighbours: Neighbours,
+
+        /// Read the input from this file instead of `inputs/day03.txt`, or from stdin when `-`.
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+}
+
+#[derive(Args)]
+struct Website {
+    /// The `session` cookie of a browser that is logged in to Advent of Code. Only needed when
+    /// something has to be downloaded or submitted.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Where to send requests to, e.g. a local mock server.
+    #[arg(long, env = "AOC_BASE_URL", default_value = aoc::client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    #[arg(long, default_value_t = aoc::YEAR)]
+    year: u16,
+}
+
+impl Website {
+    fn client(&self) -> Client {
+        let last_request = Path::new(aoc::input::INPUT_DIR).join(client::LAST_REQUEST_FILE);
+
+        Client::new(&self.base_url, self.session.as_deref(), self.year)
+            .with_last_request_file(&last_request)
+    }
+}
+
+/// The budget of a part when `run` and `bench` aren't given one. `check` has none by default,
+/// as a part that's known to be slow shouldn't fail the check.
+const DEFAULT_BUDGET: Duration = Duration::from_secs(30);
+
+#[derive(Args)]
+struct Budget {
+    /// Cancel parts that run for longer than this many seconds, or never cancel them when 0.
+    /// Defaults to 30 for `run` and `bench`, and to 0 for `check`.
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
+}
+
+impl Budget {
+    fn duration(&self, default: Option<Duration>) -> Option<Duration> {
+        match self.timeout {
+            None => default,
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum RunFormat {
+    Text,
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(Filter::new(cli.verbose, &cli.log));
+
+    // Panics inside a solution are reported by the runner, so the default hook would only add noise.
+    std::panic::set_hook(Box::new(|_| {}));
+
+    match cli.command {
+        Command::Run {
+            part,
+            input,
+            render,
+            animate,
+            frame_skip,
+            crop,
+            budget,
+            format,
+            jobs,
+            days,
+        } => {
+            let days = days.concat();
+
+            if input.is_some() && days.len() != 1 {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            if let Some(path) = &render {
+                if days.len() != 1 {
+                    eprintln!("--render can only be used when running a single day");
+                    return ExitCode::FAILURE;
+                }
+
+                if let Err(message) = ImageFormat::from_path(path) {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if animate.is_some() && days.len() != 1 {
+                eprintln!("--animate can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let animation = animate.as_deref().map(|path| {
+                let options = animation::Options { frame_skip, crop };
+                (path, options)
+            });
+
+            // Answers that differ from `answers.toml` are reported as wrong.
+            let registry = match Registry::load(Path::new(ANSWERS_FILE)) {
+                Ok(registry) => registry,
+                Err(message) => {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let runner = Runner {
+                parts: parts(part),
+                registry,
+                format,
+                source: input.unwrap_or(InputSource::Default),
+                render: render.as_deref(),
+                animation,
+                budget: budget.duration(Some(DEFAULT_BUDGET)),
+                jobs,
+            };
+
+            run(&days, &runner)
+        }
+        Command::Check {
+            part,
+            record,
+            budget,
+            days,
+        } => check(&days.concat(), &parts(part), record, budget.duration(None)),
+        Command::Bench {
+            part,
+            warmup,
+            iterations,
+            format,
+            budget,
+            days,
+        } => bench(
+            &days.concat(),
+            &parts(part),
+            warmup,
+            iterations,
+            format,
+            budget.duration(Some(DEFAULT_BUDGET)),
+        ),
+        Command::Explore { day } => {
+            let explored = match day {
+                Explore::Day1 {
+                    vocabulary,
+                    explain,
+                    input,
+                } => {
+                    let vocabulary = match vocabulary {
+                        Some(path) => Vocabulary::load(&path),
+                        None => Ok(Vocabulary::english()),
+                    };
+
+                    vocabulary.and_then(|vocabulary| {
+                        explore(1, input, |input| day1::explore(input, &vocabulary, explain))
+                    })
+                }
+                Explore::Day2 {
+                    bag,
+                    minimum_bag,
+                    smallest_bag_for,
+                    input,
+                } => explore(2, input, |input| {
+                    day2::explore(input, bag.as_ref(), minimum_bag, smallest_bag_for)
+                }),
+                Explore::Day3 {
+                    exactly,
+                    json,
+                    symbols,
+                    symbol_category,
+                    neighbours,
+                    input,
+                } => {
+                    let classifier = match (symbols, symbol_category) {
+                        (Some(symbols), _) => Ok(Classifier::Set(symbols.chars().collect())),
+                        (_, Some(category)) => Classifier::category(&category),
+                        (None, None) => Ok(Classifier::NotDigitOrDot),
+                    };
+
+                    classifier.and_then(|classifier| {
+                        let options = day3::Options {
+                            classifier,
+                            neighbours,
+                        };
+
+                        explore(3, input, |input| {
+                            day3::explore(input, &options, exactly, json)
+                        })
+                    })
+                }
+            };
+
+            match explored {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(message) => {
+                    eprintln!("{message}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::NewDay { day, year } => new_day(year, day),
+        Command::Fetch { website, days } => {
+            fetch(&mut website.client(), website.year, &days.concat())
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            website,
+        } => {
+            let part = match part {
+                1 => Part::One,
+                _ => Part::Two,
+            };
+
+            submit(&mut website.client(), day, part, answer)
+        }
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// How `run` solves the days it's given.
+struct Runner<'a> {
+    parts: Vec<Part>,
+    registry: Registry,
+    format: RunFormat,
+    source: InputSource,
+    render: Option<&'a Path>,
+    animation: Option<(&'a Path, animation::Options)>,
+    budget: Option<Duration>,
+    /// The number of days that are solved at the same time.
+    jobs: usize,
+}
+
+/// What solving a day printed, and whether any of it failed.
+struct Report {
+    lines: Vec<String>,
+    records: Vec<RunRecord>,
+    failed: bool,
+}
+
+/// A part as `run --format json` reports it.
+#[derive(Serialize)]
+struct RunRecord {
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: Option<Answer>,
+    /// The answer in `answers.toml`, for wrong answers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<Answer>,
+    duration_ns: u64,
+    input_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    /// The answer matches `answers.toml`, or there is no known answer for the input.
+    Solved,
+    Wrong,
+    TimedOut,
+    Panicked,
+    Unimplemented,
+    /// The input couldn't be read or parsed.
+    InvalidInput,
+    /// The day couldn't be run at all, like a day that hasn't been solved yet.
+    Error,
+}
+
+impl RunRecord {
+    /// A record without an answer, for parts that never ran.
+    fn unsolved(day: u8, part: Part, status: Status, error: Option<String>) -> RunRecord {
+        RunRecord {
+            day,
+            part: part.number(),
+            status,
+            answer: None,
+            expected: None,
+            duration_ns: 0,
+            input_hash: None,
+            error,
+        }
+    }
+}
+
+/// Solves the days on `runner.jobs` threads. Days finish in any order, but are printed in the
+/// order they were given, as soon as every day before them is printed.
+fn run(days: &[u8], runner: &Runner) -> ExitCode {
+    let next = AtomicUsize::new(0);
+    let (sender, reports) = mpsc::channel();
+    let mut any_failed = false;
+    let mut records = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..runner.jobs.min(days.len()) {
+            let (next, sender) = (&next, sender.clone());
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, runner.day(day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut finished = HashMap::new();
+        let mut printed = 0;
+
+        for (index, report) in reports {
+            finished.insert(index, report);
+
+            while let Some(report) = finished.remove(&printed) {
+                match report {
+                    Ok(report) => {
+                        if runner.format == RunFormat::Text {
+                            for line in report.lines {
+                                println!("{line}");
+                            }
+                        }
+
+                        records.extend(report.records);
+                        any_failed |= report.failed;
+                    }
+                    Err(message) => {
+                        eprintln!("{message}");
+                        any_failed = true;
+
+                        records.extend(runner.parts.iter().map(|&part| {
+                            RunRecord::unsolved(
+                                days[printed],
+                                part,
+                                Status::Error,
+                                Some(message.clone()),
+                            )
+                        }));
+                    }
+                }
+
+                printed += 1;
+            }
+        }
+    });
+
+    if runner.format == RunFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&records).unwrap());
+    }
+
+    match any_failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+impl Runner<'_> {
+    fn day(&self, day: u8) -> Result<Report, String> {
+        let puzzle = aoc::puzzle(day).ok_or(format!("Day {day} has not been solved yet"))?;
+
+        let mut report = Report {
+            lines: vec![format!("Day {day}")],
+            records: vec![],
+            failed: false,
+        };
+
+        let input = match self.source.read(day) {
+            Ok(input) => input,
+            Err(message) => {
+                report.lines.push(format!("  {message}"));
+                report.records = self.invalid_input(day, None, &message);
+                report.failed = true;
+                return Ok(report);
+            }
+        };
+
+        let input_hash = aoc::input::hash(&input);
+
+        // Days that can't be drawn fail before their parts are solved, which may take a while.
+        let mut drawn = vec![];
+
+        if let Some(path) = self.render {
+            let rendered = match puzzle.render(&input) {
+                Ok(Some(picture)) => picture.save(path),
+                Ok(None) => Err(format!("Day {day} can't be rendered")),
+                Err(message) => Err(format!("Could not render the input: {message}")),
+            };
+
+            match rendered {
+                Ok(()) => drawn.push(format!("  Rendered to {}", path.display())),
+                Err(message) => {
+                    report.lines.push(format!("  {message}"));
+                    report.failed = true;
+                    return Ok(report);
+                }
+            }
+        }
+
+        if let Some((path, options)) = self.animation {
+            match animate(puzzle, &input, path, options) {
+                Ok(frames) => {
+                    drawn.push(format!("  Animated {frames} frames to {}", path.display()))
+                }
+                Err(message) => {
+                    report.lines.push(format!("  {message}"));
+                    report.failed = true;
+                    return Ok(report);
+                }
+            }
+        }
+
+        // Bars of days that run at the same time would draw over each other.
+        let reporter = match self.jobs {
+            1 => progress::reporter(day),
+            _ => Box::new(LogLines::new(day)),
+        };
+
+        let outcomes = progress::watch(reporter, || puzzle.solve(&input, &self.parts, self.budget));
+
+        let outcomes = match outcomes {
+            Ok(outcomes) => outcomes,
+            Err(error) => {
+                let message = format!("Could not parse the input of {error}");
+                report.lines.push(indent(&message));
+                report.records = self.invalid_input(day, Some(&input_hash), &message);
+                report.failed = true;
+                return Ok(report);
+            }
+        };
+
+        for outcome in outcomes {
+            let mut line = describe(&outcome);
+            let record = self.record(day, &input_hash, outcome);
+
+            if let Some(expected) = &record.expected {
+                line.push_str(&format!(", wrong: expected {expected}"));
+            }
+
+            report.failed |= matches!(
+                record.status,
+                Status::Wrong | Status::TimedOut | Status::Panicked
+            );
+            report.lines.push(format!("  {line}"));
+            report.records.push(record);
+        }
+
+        report.lines.extend(drawn);
+
+        Ok(report)
+    }
+
+    fn record(&self, day: u8, input_hash: &str, outcome: PartOutcome) -> RunRecord {
+        let name = self.source.name(day);
+        let verdict = name.as_deref().map(|name| {
+            self.registry
+                .verdict(day, name, outcome.part, &outcome.result)
+        });
+
+        let (status, expected, error) = match (&outcome.result, verdict) {
+            (Ok(Answer::Unimplemented), _) => (Status::Unimplemented, None, None),
+            (Ok(_), Some(Verdict::Fail(_))) => {
+                let expected = name
+                    .as_deref()
+                    .and_then(|name| self.registry.expected(day, name, outcome.part))
+                    .and_then(|expected| match expected {
+                        Expected::Number(number) => Some(Answer::Number(*number)),
+                        Expected::Text(text) => Some(Answer::Text(text.clone())),
+                        Expected::Skip { .. } => None,
+                    });
+
+                (Status::Wrong, expected, None)
+            }
+            (Ok(_), _) => (Status::Solved, None, None),
+            (Err(failure @ Failure::TimedOut(_)), _) => {
+                (Status::TimedOut, None, Some(failure.to_string()))
+            }
+            (Err(failure @ Failure::Panicked(_)), _) => {
+                (Status::Panicked, None, Some(failure.to_string()))
+            }
+        };
+
+        RunRecord {
+            day,
+            part: outcome.part.number(),
+            status,
+            answer: outcome
+                .result
+                .ok()
+                .filter(|answer| *answer != Answer::Unimplemented),
+            expected,
+            duration_ns: outcome.duration.as_nanos() as u64,
+            input_hash: Some(input_hash.to_string()),
+            error,
+        }
+    }
+
+    /// A record for every part of a day whose input couldn't be read or parsed.
+    fn invalid_input(&self, day: u8, input_hash: Option<&str>, message: &str) -> Vec<RunRecord> {
+        self.parts
+            .iter()
+            .map(|&part| RunRecord {
+                input_hash: input_hash.map(ToString::to_string),
+                ..RunRecord::unsolved(day, part, Status::InvalidInput, Some(message.to_string()))
+            })
+            .collect()
+    }
+}
+
+fn animate(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    path: &Path,
+    options: animation::Options,
+) -> Result<usize, String> {
+    let mut recording = Recording::new(path, options);
+
+    match puzzle.animate(input, &mut recording) {
+        Ok(true) => recording.finish(),
+        Ok(false) => Err(format!("Day {} can't be animated", puzzle.day())),
+        Err(message) => Err(format!("Could not animate the input: {message}")),
+    }
+}
+
+fn check(days: &[u8], parts: &[Part], record: bool, budget: Option<Duration>) -> ExitCode {
+    let path = Path::new(ANSWERS_FILE);
+
+    let mut registry = match Registry::load(path) {
+        Ok(registry) => registry,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Failures and new answers are listed below the matrix.
+    let mut details = vec![];
+    let (mut passed, mut failed, mut new, mut skipped, mut unimplemented) = (0, 0, 0, 0, 0);
+
+    let header: String = parts
+        .iter()
+        .map(|part| format!("{:<16}", format!("part {part}")))
+        .collect();
+    println!("{:<20}{}", "", header.trim_end());
+
+    for &day in days {
+        let Some(puzzle) = aoc::puzzle(day) else {
+            details.push(format!("Day {day} has not been solved yet"));
+            failed += 1;
+            continue;
+        };
+
+        for (name, path) in aoc::input::named_inputs(day) {
+            let input = match InputSource::File(path).read(day) {
+                Ok(input) => input,
+                Err(message) => {
+                    details.push(message);
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let to_solve: Vec<Part> = parts
+                .iter()
+                .copied()
+                .filter(|&part| !registry.is_skipped(day, &name, part))
+                .collect();
+            let outcomes = progress::watch(progress::reporter(day), || {
+                puzzle.solve(&input, &to_solve, budget)
+            });
+
+            let outcomes = match outcomes {
+                Ok(outcomes) => outcomes,
+                Err(error) => {
+                    details.push(format!("Could not parse the {name} of {error}"));
+                    failed += 1;
+                    println!("{:<20}unparsable", format!("Day {day} {name}"));
+                    continue;
+                }
+            };
+
+            let mut row = format!("{:<20}", format!("Day {day} {name}"));
+
+            for &part in parts {
+                let result = match outcomes.iter().find(|outcome| outcome.part == part) {
+                    Some(outcome) => outcome.result.clone(),
+                    None => Ok(Answer::Unimplemented),
+                };
+
+                let verdict = registry.verdict(day, &name, part, &result);
+
+                let cell = match &verdict {
+                    Verdict::Pass => {
+                        passed += 1;
+                        "pass"
+                    }
+                    Verdict::Fail(reason) => {
+                        details.push(format!("Day {day} {name} part {part}: {reason}"));
+                        failed += 1;
+                        "FAIL"
+                    }
+                    Verdict::New(answer) => {
+                        details.push(format!("Day {day} {name} part {part}: new answer {answer}"));
+                        new += 1;
+                        if record {
+                            registry.record(day, &name, part, answer);
+                        }
+                        "new"
+                    }
+                    Verdict::Skipped(_) => {
+                        skipped += 1;
+                        "skipped"
+                    }
+                    Verdict::Unimplemented => {
+                        unimplemented += 1;
+                        "unimplemented"
+                    }
+                };
+
+                row.push_str(&format!("{cell:<16}"));
+            }
+
+            println!("{}", row.trim_end());
+        }
+    }
+
+    println!();
+
+    for detail in &details {
+        println!("{detail}");
+    }
+
+    println!(
+        "{passed} passed, {failed} failed, {new} new, {skipped} skipped, {unimplemented} unimplemented"
+    );
+
+    if record && new > 0 {
+        if let Err(message) = registry.save(path) {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+
+        println!("Recorded {new} new answers in {ANSWERS_FILE}");
+    }
+
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn bench(
+    days: &[u8],
+    parts: &[Part],
+    warmup: usize,
+    iterations: usize,
+    format: Format,
+    budget: Option<Duration>,
+) -> ExitCode {
+    // Parts that never finish are skipped for the puzzle input in `answers.toml`.
+    let registry = match Registry::load(Path::new(ANSWERS_FILE)) {
+        Ok(registry) => registry,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut any_failed = false;
+    let mut rows: Vec<(u8, Stage, Stats)> = vec![];
+
+    for &day in days {
+        let Some(puzzle) = aoc::puzzle(day) else {
+            eprintln!("Day {day} has not been solved yet");
+            any_failed = true;
+            continue;
+        };
+
+        let input = match InputSource::Default.read(day) {
+            Ok(input) => input,
+            Err(message) => {
+                eprintln!("Day {day}: {message}");
+                any_failed = true;
+                continue;
+            }
+        };
+
+        let to_time: Vec<Part> = parts
+            .iter()
+            .copied()
+            .filter(|&part| !registry.is_skipped(day, "input", part))
+            .collect();
+
+        match puzzle.time(&input, &to_time, warmup + iterations, budget) {
+            Ok(timings) => {
+                for (stage, durations) in timings {
+                    let durations = match durations {
+                        Ok(durations) if durations.len() > warmup => durations,
+                        Ok(_) => continue,
+                        // Left out of the stats, and kept out of stdout for JSON and CSV.
+                        Err(failure) => {
+                            match format {
+                                Format::Table => {
+                                    println!("Day {day:<4}{:<10}{failure}", stage.to_string())
+                                }
+                                _ => eprintln!("Day {day} {stage}: {failure}"),
+                            }
+                            continue;
+                        }
+                    };
+
+                    let row = (day, stage, Stats::new(&durations[warmup..]));
+
+                    if format == Format::Table {
+                        print_bench_row(&row);
+                    }
+
+                    rows.push(row);
+                }
+            }
+            Err(message) => {
+                eprintln!("Day {day}: panicked: {message}");
+                any_failed = true;
+            }
+        }
+    }
+
+    match format {
+        Format::Table => {}
+        Format::Json => {
+            let records: Vec<BenchRecord> = rows
+                .iter()
+                .map(|(day, stage, stats)| BenchRecord {
+                    day: *day,
+                    stage: stage.key(),
+                    iterations,
+                    min_ns: stats.min.as_nanos() as u64,
+                    median_ns: stats.median.as_nanos() as u64,
+                    p95_ns: stats.p95.as_nanos() as u64,
+                })
+                .collect();
+
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
+        Format::Csv => {
+            println!("day,stage,iterations,min_ns,median_ns,p95_ns");
+
+            for (day, stage, stats) in &rows {
+                println!(
+                    "{day},{},{iterations},{},{},{}",
+                    stage.key(),
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.p95.as_nanos()
+                );
+            }
+        }
+    }
+
+    match any_failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+#[derive(Serialize)]
+struct BenchRecord {
+    day: u8,
+    stage: String,
+    iterations: usize,
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+}
+
+fn print_bench_row((day, stage, stats): &(u8, Stage, Stats)) {
+    println!(
+        "Day {:<4}{:<10}min {:<12}median {:<12}p95 {}",
+        day,
+        stage.to_string(),
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.p95)
+    );
+}
+
+/// Reads the input of the day, or the one from `source`, and prints what `explore` makes of it.
+fn explore(
+    day: u8,
+    source: Option<InputSource>,
+    explore: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    let input = source.unwrap_or(InputSource::Default).read(day)?;
+    println!("{}", explore(&input)?);
+
+    Ok(())
+}
+
+fn new_day(year: u16, day: u8) -> ExitCode {
+    match aoc::scaffold::new_day(Path::new("."), year, day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(client: &mut Client, year: u16, days: &[u8]) -> ExitCode {
+    let mut any_failed = false;
+
+    for &day in days {
+        let path = aoc::input::default_path_in(year, day);
+
+        match client.cache_input(day, &path) {
+            Ok(true) => println!("Day {day}: downloaded to {}", path.display()),
+            Ok(false) => println!("Day {day}: already in {}", path.display()),
+            Err(message) => {
+                println!("Day {day}: {message}");
+                any_failed = true;
+            }
+        }
+    }
+
+    match any_failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+fn submit(client: &mut Client, day: u8, part: Part, answer: Option<String>) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => match answer.parse::<i64>() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(answer),
+        },
+        None => match solve(day, part) {
+            Ok(answer) => answer,
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let verdict = match client.submit(day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Day {day} part {part}: {answer} is {verdict}");
+
+    if verdict != client::Verdict::Correct {
+        return ExitCode::FAILURE;
+    }
+
+    let path = Path::new(ANSWERS_FILE);
+    let saved = Registry::load(path).and_then(|mut registry| {
+        registry.record(day, "input", part, &answer);
+        registry.save(path)
+    });
+
+    match saved {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solves a single part of a day for its puzzle input.
+fn solve(day: u8, part: Part) -> Result<Answer, String> {
+    let puzzle = aoc::puzzle(day).ok_or(format!("Day {day} has not been solved yet"))?;
+    let input = InputSource::Default.read(day)?;
+
+    let mut outcomes = puzzle
+        .solve(&input, &[part], None)
+        .map_err(|error| format!("Could not parse the input of {error}"))?;
+
+    match outcomes.remove(0).result {
+        Ok(Answer::Unimplemented) => Err(format!("Day {day} part {part} is unimplemented")),
+        Ok(answer) => Ok(answer),
+        Err(failure) => Err(format!("Day {day} part {part} {failure}")),
+    }
+}
+
+fn describe(outcome: &PartOutcome) -> String {
+    match &outcome.result {
+        Ok(Answer::Unimplemented) => format!("Part {}: unimplemented", outcome.part),
+        Ok(answer) => format!(
+            "Part {}: {answer} ({})",
+            outcome.part,
+            format_duration(outcome.duration)
+        ),
+        Err(failure) => format!("Part {}: {failure}", outcome.part),
+    }
+}
+
+/// Indents every line by two spaces, to nest multi-line messages under their day.
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("  {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_duration(duration: Duration) -> String {
+    match duration.as_micros() {
+        0 => format!("{}ns", duration.as_nanos()),
+        micros if micros < 1_000 => format!("{micros}µs"),
+        micros if micros < 1_000_000 => format!("{:.2}ms", micros as f64 / 1_000.0),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+/// Parses a selection of days such as `17`, `1..=25`, `1..4`, `3,5,7` or `all`.
+fn parse_days(selection: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+
+    for item in selection.split(',') {
+        let range: RangeInclusive<u8> = if item == "all" {
+            1..=25
+        } else if let Some((start, end)) = item.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = item.split_once("..") {
+            // The end is exclusive, so it can be one past the last day.
+            let end = match end.trim().parse::<u8>() {
+                Ok(end @ 1..=26) => end,
+                _ => return Err(format!("'{end}' is not a day between 1 and 26")),
+            };
+
+            parse_day(start)?..=end - 1
+        } else {
+            let day = parse_day(item)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(format!("'{item}' does not contain any days"));
+        }
+
+        days.extend(range);
+    }
+
+    Ok(days)
+}
+
+fn parse_day(raw: &str) -> Result<u8, String> {
+    match raw.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("'{raw}' is not a day between 1 and 25")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_exclusive_ranges_up_to_the_last_day() {
+        assert_eq!(Ok((1..=25).collect()), parse_days("1..26"));
+        assert_eq!(Ok(vec![3, 4]), parse_days("3..5"));
+        assert!(parse_days("1..27").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("0..3").is_err());
+    }
 }
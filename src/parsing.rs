@@ -0,0 +1,401 @@
+//! Errors for malformed puzzle inputs, and helpers for the parsing steps that most days share.
+//!
+//! Parsers report an error about a slice of their input. Where that slice is in the input is
+//! worked out afterwards by [`ParseError::locate`], so the parsers don't have to keep track of
+//! line and column numbers themselves.
+//!
+//! Inputs are [`normalise`]d before they're parsed, so parsers only ever see `\n` line endings
+//! and no blank lines around the input.
+
+use std::{borrow::Cow, error::Error, fmt::Display, ops::Deref, str::FromStr};
+
+use regex::{Captures, Regex};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub message: String,
+    /// The offending text.
+    pub snippet: String,
+    /// The line and column of the snippet, counted from 1, or 0 until the error is located.
+    pub line: usize,
+    pub column: usize,
+    source_line: String,
+    /// Where the snippet was in memory, to find it back in the input.
+    address: usize,
+}
+
+impl ParseError {
+    /// An error about `snippet`, which should be a slice of the input that is being parsed.
+    pub fn new(snippet: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            message: message.into(),
+            snippet: snippet.to_string(),
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+            address: snippet.as_ptr() as usize,
+        }
+    }
+
+    /// Finds the line and column of the snippet in the input. Snippets that aren't a slice of
+    /// the input are looked up by their text instead.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+
+        let offset = match (start..=start + input.len()).contains(&self.address) {
+            true => Some(self.address - start),
+            false if self.snippet.is_empty() => None,
+            false => input.find(&self.snippet),
+        };
+
+        if let Some(offset) = offset {
+            let line_start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+            let line_end = input[offset..]
+                .find('\n')
+                .map_or(input.len(), |newline| offset + newline);
+
+            self.line = input[..offset].matches('\n').count() + 1;
+            self.column = input[line_start..offset].chars().count() + 1;
+            self.source_line = input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string();
+        }
+
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    /// Shows the error with the line it's on, and carets under the snippet:
+    ///
+    /// ```text
+    /// day 22, line 3, column 7: expected 3 coordinates
+    ///   |
+    /// 3 | 0,0,2~2,0
+    ///   |       ^^^
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        if self.line == 0 {
+            return write!(f, "{} at '{}'", self.message, self.snippet);
+        }
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let carets = "^".repeat(
+            self.snippet
+                .lines()
+                .next()
+                .unwrap_or("")
+                .chars()
+                .count()
+                .max(1),
+        );
+
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {}{carets}", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// An input without the differences that come from how it was saved, see [`normalise`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalised<'a> {
+    text: Cow<'a, str>,
+    /// The number of blank lines that were removed from the start.
+    skipped_lines: usize,
+}
+
+impl Normalised<'_> {
+    /// Locates an error about the normalised text in the original input.
+    pub fn locate(&self, error: ParseError) -> ParseError {
+        let mut error = error.locate(&self.text);
+
+        if error.line > 0 {
+            error.line += self.skipped_lines;
+        }
+
+        error
+    }
+
+    /// Parses the text the way the runner does, for the tools that parse an input of the day
+    /// themselves, like `aoc explore`. Errors are located in the original input.
+    pub fn parse_in_day<'s, T>(
+        &'s self,
+        day: u8,
+        parse: impl FnOnce(&'s str) -> Result<T, ParseError>,
+    ) -> Result<T, String> {
+        parse(&self.text).map_err(|error| {
+            format!(
+                "Could not parse the input of {}",
+                self.locate(error).in_day(day)
+            )
+        })
+    }
+}
+
+impl Deref for Normalised<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Turns Windows line endings into `\n`, and removes the blank lines before the input and all
+/// whitespace after it. Only copies the input when it has Windows line endings.
+pub fn normalise(input: &str) -> Normalised<'_> {
+    let first_line = input
+        .lines()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(0);
+    let start = input
+        .split_inclusive('\n')
+        .take(first_line)
+        .map(str::len)
+        .sum();
+    let text = input[start..].trim_end();
+
+    Normalised {
+        text: match text.contains('\r') {
+            true => Cow::Owned(text.replace("\r\n", "\n")),
+            false => Cow::Borrowed(text),
+        },
+        skipped_lines: first_line,
+    }
+}
+
+/// The lines of the input, without the whitespace at the end of each line that editors tend to
+/// leave behind.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+/// The blocks of lines that are separated by one or more blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        let blank: usize = rest
+            .split_inclusive('\n')
+            .take_while(|line| line.trim().is_empty())
+            .map(str::len)
+            .sum();
+
+        rest = &rest[blank..];
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest
+            .split_inclusive('\n')
+            .take_while(|line| !line.trim().is_empty())
+            .map(str::len)
+            .sum();
+        let block = rest[..end].trim_end_matches(['\r', '\n']);
+
+        rest = &rest[end..];
+
+        Some(block)
+    })
+}
+
+/// The tokens between the separators, without the whitespace and line breaks around them.
+pub fn tokens(input: &str, separator: char) -> impl Iterator<Item = &str> {
+    input
+        .split(separator)
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// Parses a number, such as `42` or `-7`.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, format!("'{text}' is not a valid number")))
+}
+
+/// Splits the text at the first occurrence of the delimiter, which has to be there.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("expected '{delimiter}'")))
+}
+
+/// Strips a prefix that has to be there.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let found = &text[..text.floor_char_boundary(prefix.len())];
+
+        ParseError::new(found, format!("expected '{prefix}'"))
+    })
+}
+
+/// Checks that the text is made up of exactly `N` parts, such as the 3 coordinates of a point.
+pub fn exactly<'a, const N: usize>(
+    text: &'a str,
+    parts: impl IntoIterator<Item = &'a str>,
+    what: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let parts: Vec<&str> = parts.into_iter().collect();
+    let count = parts.len();
+
+    parts
+        .try_into()
+        .map_err(|_| ParseError::new(text, format!("expected {N} {what}, found {count}")))
+}
+
+/// Matches the whole pattern against the text.
+pub fn captures<'a>(regex: &Regex, text: &'a str) -> Result<Captures<'a>, ParseError> {
+    regex
+        .captures(text)
+        .ok_or_else(|| ParseError::new(text, format!("expected a match for `{regex}`")))
+}
+
+/// Fails on the first line that isn't there, for inputs that have been cut short.
+pub fn truncated(input: &str, expected: &str) -> ParseError {
+    ParseError::new(
+        &input[input.len()..],
+        format!("the input ends, expected {expected}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "1,0,1~1,2,1\n0,0,2~2,0\n";
+
+    #[test]
+    fn locates_a_snippet_of_the_input() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = ParseError::new(&line[6..], "expected 3 coordinates")
+            .locate(INPUT)
+            .in_day(22);
+
+        assert_eq!((2, 7), (error.line, error.column));
+        assert_eq!(
+            "day 22, line 2, column 7: expected 3 coordinates\n  |\n2 | 0,0,2~2,0\n  |       ^^^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn locates_copied_text_by_searching() {
+        let copy = String::from("2~2");
+        let error = ParseError::new(&copy, "unexpected").locate(INPUT);
+
+        assert_eq!((2, 5), (error.line, error.column));
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_truncated_input() {
+        let error = truncated(INPUT, "a brick").locate(INPUT);
+
+        assert_eq!((3, 1), (error.line, error.column));
+        assert!(error.to_string().ends_with("3 | \n  | ^"), "{error}");
+    }
+
+    #[test]
+    fn reports_bad_numbers() {
+        let error = number::<u32>(&INPUT[2..5]).unwrap_err().locate(INPUT);
+
+        assert_eq!("'0,1' is not a valid number", error.message);
+        assert_eq!((1, 3), (error.line, error.column));
+        assert_eq!(Ok(-7), number::<i64>("-7"));
+    }
+
+    #[test]
+    fn reports_missing_delimiters_and_prefixes() {
+        assert_eq!(Ok(("a", "b")), split_once("a: b", ": "));
+        assert_eq!(
+            "expected ': '",
+            split_once("a b", ": ").unwrap_err().message
+        );
+        assert_eq!(Ok("1"), strip_prefix("Game 1", "Game "));
+        assert_eq!(
+            "expected 'Game '",
+            strip_prefix("Gam", "Game ").unwrap_err().message
+        );
+    }
+
+    #[test]
+    fn normalises_line_endings_and_blank_lines() {
+        let input = "\r\n  \r\n#.#\r\n.#.\r\n\r\n";
+        let normalised = normalise(input);
+
+        assert_eq!("#.#\n.#.", &*normalised);
+        assert!(matches!(normalise("#.#\n\n").text, Cow::Borrowed("#.#")));
+
+        let error = ParseError::new(&normalised[4..5], "unexpected '.'");
+        assert_eq!((4, 1), {
+            let error = normalised.locate(error);
+            (error.line, error.column)
+        });
+    }
+
+    #[test]
+    fn parses_in_a_day_like_the_runner() {
+        let normalised = normalise("\n1,2,x\n");
+        let parsed = normalised.parse_in_day(9, |input| {
+            tokens(input, ',')
+                .map(number::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        });
+
+        assert_eq!(
+            Err("Could not parse the input of day 9, line 2, column 5: \
+                'x' is not a valid number\n  |\n2 | 1,2,x\n  |     ^"
+                .to_string()),
+            parsed
+        );
+    }
+
+    #[test]
+    fn splits_into_blocks_lines_and_tokens() {
+        let input = "seeds: 1 2\n\n\na map:\n1 2 3  \n4 5 6\n  \nb map:\n7 8 9";
+
+        assert_eq!(
+            vec!["seeds: 1 2", "a map:\n1 2 3  \n4 5 6", "b map:\n7 8 9"],
+            blocks(input).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["a map:", "1 2 3", "4 5 6"],
+            lines(blocks(input).nth(1).unwrap()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["rn=1", "cm-", "qp=3"],
+            tokens("rn=1,cm-,\nqp=3\n", ',').collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn counts_parts() {
+        assert_eq!(
+            Ok(["1", "0", "1"]),
+            exactly("1,0,1", "1,0,1".split(','), "coordinates")
+        );
+        assert_eq!(
+            "expected 3 coordinates, found 2",
+            exactly::<3>("2,0", "2,0".split(','), "coordinates")
+                .unwrap_err()
+                .message
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Progress of long searches. A solver creates a [`Progress`] and advances it as it goes, and the
+//! runner shows the progress of the part it's solving through a [`Reporter`]: a live bar on a
+//! terminal, and periodic log lines otherwise. Without a runner, like in the tests, progress is
+//! counted but never shown.
+
+use std::{
+    cell::RefCell,
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use log::info;
+
+use crate::{logging, watcher};
+
+/// The number of characters in a progress bar.
+const BAR_WIDTH: usize = 30;
+
+/// The progress of the searches that are going on, with the innermost search last.
+type Searches = Mutex<Vec<Arc<Counter>>>;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Searches>>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug)]
+struct Counter {
+    label: String,
+    total: Option<u64>,
+    done: AtomicU64,
+    started: Instant,
+}
+
+/// The progress of a single search. Advancing it is cheap enough for hot loops, and it can be
+/// advanced from several threads at once.
+#[derive(Debug)]
+pub struct Progress {
+    counter: Arc<Counter>,
+    searches: Option<Arc<Searches>>,
+}
+
+impl Progress {
+    /// Progress through a known number of items, shown as `current/total`.
+    pub fn new(label: &str, total: usize) -> Progress {
+        Progress::start(label, Some(total as u64))
+    }
+
+    /// Progress through an unknown number of items, shown as the number of items processed.
+    pub fn unbounded(label: &str) -> Progress {
+        Progress::start(label, None)
+    }
+
+    fn start(label: &str, total: Option<u64>) -> Progress {
+        let counter = Arc::new(Counter {
+            label: label.to_string(),
+            total,
+            done: AtomicU64::new(0),
+            started: Instant::now(),
+        });
+
+        let searches = CURRENT.with_borrow(Clone::clone);
+
+        if let Some(searches) = &searches {
+            searches.lock().unwrap().push(Arc::clone(&counter));
+        }
+
+        Progress { counter, searches }
+    }
+
+    pub fn advance(&self, items: usize) {
+        self.counter.done.fetch_add(items as u64, Ordering::Relaxed);
+    }
+
+    /// The number of items processed so far.
+    pub fn done(&self) -> u64 {
+        self.counter.done.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(searches) = &self.searches {
+            searches
+                .lock()
+                .unwrap()
+                .retain(|counter| !Arc::ptr_eq(counter, &self.counter));
+        }
+    }
+}
+
+/// The progress of a search at some point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub label: String,
+    pub done: u64,
+    pub total: Option<u64>,
+    pub elapsed: Duration,
+}
+
+impl Snapshot {
+    fn of(counter: &Counter) -> Snapshot {
+        Snapshot {
+            label: counter.label.clone(),
+            done: counter.done.load(Ordering::Relaxed),
+            total: counter.total,
+            elapsed: counter.started.elapsed(),
+        }
+    }
+
+    fn percentage(&self) -> Option<f64> {
+        self.total
+            .map(|total| 100.0 * self.done as f64 / total.max(1) as f64)
+    }
+}
+
+/// Shows the progress of the innermost search every [`Reporter::interval`].
+pub trait Reporter: Send {
+    fn interval(&self) -> Duration;
+
+    fn report(&mut self, snapshot: &Snapshot);
+
+    /// Called once the work that was watched is done.
+    fn finish(&mut self) {}
+}
+
+/// A progress bar on stderr, which is redrawn in place.
+#[derive(Debug, Default)]
+pub struct Bar {
+    drawn: bool,
+}
+
+impl Reporter for Bar {
+    fn interval(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn report(&mut self, snapshot: &Snapshot) {
+        let line = match (snapshot.total, snapshot.percentage()) {
+            (Some(total), Some(percentage)) => {
+                let filled = (BAR_WIDTH as f64 * percentage / 100.0) as usize;
+
+                format!(
+                    "  {} [{:<BAR_WIDTH$}] {}/{total} ({percentage:.0}%)",
+                    snapshot.label,
+                    "#".repeat(filled.min(BAR_WIDTH)),
+                    snapshot.done
+                )
+            }
+            _ => format!(
+                "  {}: {} after {}s",
+                snapshot.label,
+                snapshot.done,
+                snapshot.elapsed.as_secs()
+            ),
+        };
+
+        // Clears the line before drawing, because the new line can be shorter than the old one.
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{line}");
+        let _ = stderr.flush();
+        self.drawn = true;
+    }
+
+    fn finish(&mut self) {
+        if self.drawn {
+            eprint!("\r\x1b[2K");
+        }
+    }
+}
+
+/// An info line every few seconds, for when stderr isn't a terminal, like in CI. The lines go to
+/// a target of their own, which the logging filter shows by default.
+#[derive(Debug)]
+pub struct LogLines {
+    target: String,
+}
+
+impl LogLines {
+    /// Logs to `progress::dayN`, so `--log progress::day5=off` hides the progress of day 5.
+    pub fn new(day: u8) -> LogLines {
+        LogLines {
+            target: format!(
+                "{}::{}::day{day}",
+                env!("CARGO_CRATE_NAME"),
+                logging::PROGRESS
+            ),
+        }
+    }
+}
+
+impl Reporter for LogLines {
+    fn interval(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
+    fn report(&mut self, snapshot: &Snapshot) {
+        match (snapshot.total, snapshot.percentage()) {
+            (Some(total), Some(percentage)) => info!(
+                target: &self.target,
+                "{}: {}/{total} ({percentage:.1}%) after {}s",
+                snapshot.label,
+                snapshot.done,
+                snapshot.elapsed.as_secs()
+            ),
+            _ => info!(
+                target: &self.target,
+                "{}: {} after {}s",
+                snapshot.label,
+                snapshot.done,
+                snapshot.elapsed.as_secs()
+            ),
+        }
+    }
+}
+
+/// A bar when stderr is a terminal, and log lines otherwise.
+pub fn reporter(day: u8) -> Box<dyn Reporter> {
+    match io::stderr().is_terminal() {
+        true => Box::new(Bar::default()),
+        false => Box::new(LogLines::new(day)),
+    }
+}
+
+/// Runs `f`, reporting the progress of the searches it starts from another thread.
+pub fn watch<T>(mut reporter: Box<dyn Reporter>, f: impl FnOnce() -> T) -> T {
+    let searches: Arc<Searches> = Arc::default();
+
+    let report = {
+        let searches = Arc::clone(&searches);
+
+        move |running: watcher::Running| {
+            while running.wait(reporter.interval()) {
+                let snapshot = searches
+                    .lock()
+                    .unwrap()
+                    .last()
+                    .map(|counter| Snapshot::of(counter));
+
+                if let Some(snapshot) = snapshot {
+                    reporter.report(&snapshot);
+                }
+            }
+
+            reporter.finish();
+        }
+    };
+
+    watcher::watch(&CURRENT, searches, report, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    /// Keeps every snapshot it's given.
+    struct Collect(Arc<Mutex<Vec<Snapshot>>>);
+
+    impl Reporter for Collect {
+        fn interval(&self) -> Duration {
+            Duration::from_millis(1)
+        }
+
+        fn report(&mut self, snapshot: &Snapshot) {
+            self.0.lock().unwrap().push(snapshot.clone());
+        }
+    }
+
+    #[test]
+    fn counts_without_a_reporter() {
+        let progress = Progress::new("seeds", 10);
+        progress.advance(3);
+        progress.advance(4);
+
+        assert_eq!(7, progress.done());
+    }
+
+    #[test]
+    fn reports_the_innermost_search() {
+        let snapshots = Arc::default();
+        let reporter = Box::new(Collect(Arc::clone(&snapshots)));
+
+        watch(reporter, || {
+            let outer = Progress::new("outer", 2);
+            outer.advance(1);
+
+            let inner = Progress::unbounded("inner");
+            inner.advance(5);
+            thread::sleep(Duration::from_millis(20));
+            drop(inner);
+
+            thread::sleep(Duration::from_millis(20));
+        });
+
+        let snapshots = snapshots.lock().unwrap();
+        let first = snapshots.first().unwrap();
+        let last = snapshots.last().unwrap();
+
+        assert_eq!(
+            ("inner", 5, None),
+            (first.label.as_str(), first.done, first.total)
+        );
+        assert_eq!(
+            ("outer", 1, Some(2)),
+            (last.label.as_str(), last.done, last.total)
+        );
+    }
+
+    #[test]
+    fn logs_lines_under_the_default_filter() {
+        let filter = logging::Filter::new(0, &[]);
+
+        assert!(log::Level::Info <= filter.level(&LogLines::new(5).target));
+    }
+
+    #[test]
+    fn calculates_percentages() {
+        let snapshot = Snapshot {
+            label: "bricks".to_string(),
+            done: 25,
+            total: Some(200),
+            elapsed: Duration::ZERO,
+        };
+
+        assert_eq!(Some(12.5), snapshot.percentage());
+    }
+}
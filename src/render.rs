@@ -0,0 +1,448 @@
+//! Pictures of grids, for the days that are easier to understand when you can see them. A
+//! [`Picture`] gives every cell a colour, with paths drawn on top, and is saved as a PNG, PPM or
+//! SVG file depending on the extension of the path it's saved to.
+
+use std::{fmt::Write as _, fs, path::Path, str::FromStr};
+
+use crate::grid::{Grid, Position};
+
+/// Pictures are scaled up until their longest side has about this many pixels.
+const TARGET_SIZE: usize = 1024;
+
+/// The largest number of pixels a single cell is scaled up to.
+const MAX_SCALE: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(96, 96, 96);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+
+    /// The colour at `fraction` of the way from `self` to `other`.
+    pub fn blend(self, other: Rgb, fraction: f64) -> Rgb {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * fraction) as u8;
+
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The formats a [`Picture`] can be saved as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Result<Format, String> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("png") => Ok(Format::Png),
+            Some("ppm") => Ok(Format::Ppm),
+            Some("svg") => Ok(Format::Svg),
+            _ => Err(format!(
+                "Cannot tell the image format of {}, expected a .png, .ppm or .svg file",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// A rectangle of cells to keep of a picture, given as `x,y,width,height` on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Crop {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<usize> = value
+            .split(',')
+            .map(|number| number.trim().parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|error| format!("Invalid number in '{value}': {error}"))?;
+
+        match numbers[..] {
+            [x, y, width, height] if width > 0 && height > 0 => Ok(Crop {
+                x,
+                y,
+                width,
+                height,
+            }),
+            [_, _, _, _] => Err(format!("The crop '{value}' is empty")),
+            _ => Err(format!("Expected x,y,width,height, found '{value}'")),
+        }
+    }
+}
+
+/// A grid of coloured cells, with paths through the cells drawn on top of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+    paths: Vec<(Vec<Position>, Rgb)>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Picture {
+            width,
+            height,
+            cells: vec![background; width * height],
+            paths: vec![],
+        }
+    }
+
+    /// A picture of the grid, with the colour of every cell picked by `colour`.
+    pub fn of_grid<T>(grid: &Grid<T>, colour: impl FnMut(&T) -> Rgb) -> Self {
+        Picture {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid.iter().map(|(_, cell)| cell).map(colour).collect(),
+            paths: vec![],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn colour(&self, (x, y): Position) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Colours the given cells. Cells outside of the picture are left out.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Position>, colour: Rgb) -> Self {
+        for (x, y) in cells {
+            if x < self.width && y < self.height {
+                self.cells[y * self.width + x] = colour;
+            }
+        }
+
+        self
+    }
+
+    /// Draws a line through the centres of the given cells, in order.
+    pub fn path(mut self, path: impl IntoIterator<Item = Position>, colour: Rgb) -> Self {
+        self.paths.push((path.into_iter().collect(), colour));
+        self
+    }
+
+    /// The cells of the picture inside the crop, as far as the crop overlaps the picture. Paths
+    /// are cut where they leave the crop.
+    pub fn crop(&self, crop: Crop) -> Picture {
+        let x = crop.x.min(self.width);
+        let y = crop.y.min(self.height);
+        let width = crop.width.min(self.width - x);
+        let height = crop.height.min(self.height - y);
+
+        let cells = (y..y + height)
+            .flat_map(|row| &self.cells[row * self.width + x..row * self.width + x + width])
+            .copied()
+            .collect();
+
+        let inside =
+            |&(px, py): &Position| (x..x + width).contains(&px) && (y..y + height).contains(&py);
+        let paths = self
+            .paths
+            .iter()
+            .flat_map(|(path, colour)| {
+                path.split(|point| !inside(point))
+                    .filter(|piece| !piece.is_empty())
+                    .map(|piece| {
+                        let piece = piece.iter().map(|&(px, py)| (px - x, py - y)).collect();
+                        (piece, *colour)
+                    })
+            })
+            .collect();
+
+        Picture {
+            width,
+            height,
+            cells,
+            paths,
+        }
+    }
+
+    /// Saves the picture in the format that matches the extension of the path.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = match Format::from_path(path)? {
+            Format::Png => self.to_png(),
+            Format::Ppm => self.to_ppm(),
+            Format::Svg => self.to_svg().into_bytes(),
+        };
+
+        fs::write(path, contents)
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))
+    }
+
+    /// A binary PPM image, which is about the simplest image format there is.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterise();
+        let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+        ppm.extend(pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        ppm
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterise();
+        let mut png = vec![];
+
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let data: Vec<u8> = pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+        let mut writer = encoder
+            .write_header()
+            .expect("Writing to memory doesn't fail");
+        writer
+            .write_image_data(&data)
+            .expect("The image data matches the size of the image");
+        writer.finish().expect("Writing to memory doesn't fail");
+
+        png
+    }
+
+    /// An SVG image with a unit square per cell, so it stays sharp at any size. Runs of cells
+    /// with the same colour are merged into a single rectangle to keep the file small.
+    pub fn to_svg(&self) -> String {
+        let scale = self.scale();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+            self.width,
+            self.height,
+            self.width * scale,
+            self.height * scale
+        );
+
+        for y in 0..self.height {
+            let row = &self.cells[y * self.width..(y + 1) * self.width];
+            let mut x = 0;
+
+            for run in row.chunk_by(|a, b| a == b) {
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    run.len(),
+                    run[0].hex()
+                );
+                x += run.len();
+            }
+        }
+
+        for (path, colour) in &self.paths {
+            let points: Vec<String> = path
+                .iter()
+                .map(|(x, y)| format!("{}.5,{}.5", x, y))
+                .collect();
+
+            let _ = writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.3\" stroke-linejoin=\"round\" stroke-linecap=\"round\" shape-rendering=\"auto\"/>",
+                points.join(" "),
+                colour.hex()
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// How many pixels wide every cell is in the raster formats.
+    fn scale(&self) -> usize {
+        (TARGET_SIZE / self.width.max(self.height).max(1)).clamp(1, MAX_SCALE)
+    }
+
+    /// The pixels of the picture, with the paths drawn on top of the cells.
+    pub(crate) fn rasterise(&self) -> (usize, usize, Vec<Rgb>) {
+        let scale = self.scale();
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut pixels = vec![Rgb::BLACK; width * height];
+
+        for (y, row) in pixels.chunks_mut(width).enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = self.cells[(y / scale) * self.width + x / scale];
+            }
+        }
+
+        let thickness = (scale / 3).max(1);
+        let centre = |(x, y): Position| {
+            (
+                (x * scale + scale / 2) as i64,
+                (y * scale + scale / 2) as i64,
+            )
+        };
+
+        for (path, colour) in &self.paths {
+            let centres: Vec<(i64, i64)> = path.iter().copied().map(centre).collect();
+
+            for (&(x1, y1), &(x2, y2)) in centres.iter().zip(centres.iter().skip(1)) {
+                let steps = (x2 - x1).abs().max((y2 - y1).abs()).max(1);
+
+                for step in 0..=steps {
+                    let x = x1 + (x2 - x1) * step / steps;
+                    let y = y1 + (y2 - y1) * step / steps;
+
+                    paint_square(&mut pixels, width, (x, y), thickness, *colour);
+                }
+            }
+
+            // A path of a single cell has no lines, so it's drawn as a dot.
+            if let [centre] = centres[..] {
+                paint_square(&mut pixels, width, centre, thickness, *colour);
+            }
+        }
+
+        (width, height, pixels)
+    }
+}
+
+/// Paints a square of pixels around the centre, as far as it's inside the image.
+fn paint_square(pixels: &mut [Rgb], width: usize, (x, y): (i64, i64), size: usize, colour: Rgb) {
+    let height = (pixels.len() / width) as i64;
+    let half = size as i64 / 2;
+
+    for py in y - half..y - half + size as i64 {
+        for px in x - half..x - half + size as i64 {
+            if (0..width as i64).contains(&px) && (0..height).contains(&py) {
+                pixels[py as usize * width + px as usize] = colour;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let grid = Grid::parse("#.\n.#", |char| Some(char == '#')).unwrap();
+
+        Picture::of_grid(&grid, |&wall| match wall {
+            true => Rgb::BLACK,
+            false => Rgb::WHITE,
+        })
+    }
+
+    #[test]
+    fn colours_cells() {
+        let picture = picture().highlight([(1, 0), (5, 5)], Rgb::RED);
+
+        assert_eq!(Some(Rgb::BLACK), picture.colour((0, 0)));
+        assert_eq!(Some(Rgb::RED), picture.colour((1, 0)));
+        assert_eq!(None, picture.colour((5, 5)));
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let picture = Picture::new(1, 1, Rgb::BLUE);
+        let ppm = picture.to_ppm();
+        // A single cell is scaled up as far as it goes.
+        let header = "P6\n16 16\n255\n";
+
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(header.len() + 16 * 16 * 3, ppm.len());
+        assert_eq!([38, 139, 210], ppm[header.len()..header.len() + 3]);
+    }
+
+    #[test]
+    fn draws_paths_over_cells() {
+        let picture = picture().path([(0, 0), (1, 0)], Rgb::GREEN);
+        let (width, _, pixels) = picture.rasterise();
+
+        // The cells are 16 pixels wide, so the centre of the top row is at y = 8.
+        assert_eq!(32, width);
+        assert_eq!(Rgb::GREEN, pixels[8 * width + 20]);
+        assert_eq!(Rgb::WHITE, pixels[width + 20]);
+    }
+
+    #[test]
+    fn writes_svg_with_merged_runs() {
+        let svg = Picture::new(3, 1, Rgb::WHITE)
+            .highlight([(2, 0)], Rgb::RED)
+            .path([(0, 0), (2, 0)], Rgb::BLUE)
+            .to_svg();
+
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#dc322f\"/>"));
+        assert!(svg.contains("points=\"0.5,0.5 2.5,0.5\""));
+    }
+
+    #[test]
+    fn picks_the_format_from_the_extension() {
+        assert_eq!(Ok(Format::Png), Format::from_path(Path::new("out.PNG")));
+        assert_eq!(Ok(Format::Svg), Format::from_path(Path::new("a/b.svg")));
+        assert!(Format::from_path(Path::new("out.gif")).is_err());
+    }
+
+    #[test]
+    fn crops_cells_and_paths() {
+        let picture = Picture::new(4, 4, Rgb::WHITE)
+            .highlight([(2, 2)], Rgb::RED)
+            .path([(0, 1), (1, 1), (2, 1), (2, 2), (3, 3)], Rgb::BLUE)
+            .crop(Crop {
+                x: 1,
+                y: 1,
+                width: 2,
+                height: 10,
+            });
+
+        assert_eq!((2, 3), (picture.width(), picture.height()));
+        assert_eq!(Some(Rgb::RED), picture.colour((1, 1)));
+        assert_eq!(
+            vec![(vec![(0, 0), (1, 0), (1, 1)], Rgb::BLUE)],
+            picture.paths
+        );
+    }
+
+    #[test]
+    fn parses_crops() {
+        assert_eq!(
+            Ok(Crop {
+                x: 1,
+                y: 2,
+                width: 30,
+                height: 40
+            }),
+            "1,2,30,40".parse()
+        );
+        assert!("1,2,0,40".parse::<Crop>().is_err());
+        assert!("1,2,30".parse::<Crop>().is_err());
+    }
+
+    #[test]
+    fn writes_png() {
+        let png = Picture::new(2, 2, Rgb::WHITE).to_png();
+
+        assert_eq!(b"\x89PNG", &png[0..4]);
+    }
+}
@@ -0,0 +1,261 @@
+//! `aoc new-day`: instantiates `src/dayx.stub` for a new day and registers it with the runner. Days
+//! of other years go in a module of their own year, `src/yYYYY/`, made from `src/yearx.stub`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{default_path_in, example_path_in},
+    YEAR,
+};
+
+const STUB: &str = include_str!("dayx.stub");
+const YEAR_STUB: &str = include_str!("yearx.stub");
+
+/// Creates the source of the day from the stub, empty input and example files, and registers the
+/// day with the other days of its year. Returns the files that were created or changed. Days that
+/// already exist are never overwritten, and the source of the day is removed again when it can't
+/// be registered.
+///
+/// Days of this repository's year go in `src/dayN.rs` and `src/lib.rs`. Days of other years go in
+/// `src/yYYYY/dayN.rs` and `src/yYYYY/mod.rs`, which is added to `src/lib.rs` for the first day
+/// of the year.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let directory = match year {
+        YEAR => root.join("src"),
+        year => root.join(format!("src/y{year}")),
+    };
+    let source = directory.join(format!("day{day}.rs"));
+    let lib = root.join("src/lib.rs");
+    let year_module = directory.join("mod.rs");
+
+    if source.exists() {
+        return Err(format!("Day {day} already exists in {}", source.display()));
+    }
+
+    // The files to change, with their new contents.
+    let registrations = match year {
+        YEAR => vec![(lib.clone(), register(&read(&lib)?, day)?)],
+        _ if year_module.exists() => {
+            let registered = register(&read(&year_module)?, day)?;
+            vec![(year_module, registered)]
+        }
+        year => vec![
+            (year_module, instantiate_year(year, day)),
+            (lib.clone(), register_year(&read(&lib)?, year)?),
+        ],
+    };
+
+    write(&source, &instantiate(year, day))?;
+    let mut changed = vec![source.clone()];
+
+    for (path, contents) in registrations {
+        if let Err(message) = write(&path, &contents) {
+            let _ = fs::remove_file(&source);
+            return Err(message);
+        }
+
+        changed.push(path);
+    }
+
+    for path in [default_path_in(year, day), example_path_in(year, day, 1)] {
+        let path = root.join(path);
+
+        if !path.exists() {
+            write(&path, "")?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+fn instantiate(year: u16, day: u8) -> String {
+    let testing = match year {
+        YEAR => "crate::testing".to_string(),
+        year => format!("crate::y{year}::testing"),
+    };
+
+    STUB.replace("{{day}}", &day.to_string())
+        .replace("{{year}}", &year.to_string())
+        .replace("{{testing}}", &testing)
+}
+
+fn instantiate_year(year: u16, day: u8) -> String {
+    YEAR_STUB
+        .replace("{{day}}", &day.to_string())
+        .replace("{{year}}", &year.to_string())
+}
+
+/// Adds the module of a new year to the source of `src/lib.rs`, after the modules of the days.
+fn register_year(lib: &str, year: u16) -> Result<String, String> {
+    let module = format!("pub mod y{year};");
+
+    if lib.lines().any(|line| line == module) {
+        return Err(format!("{year} is already registered in src/lib.rs"));
+    }
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+
+    let last_module = lines
+        .iter()
+        .rposition(|line| {
+            line.starts_with("pub mod day")
+                || line.starts_with("pub mod y") && *line < module.as_str()
+        })
+        .ok_or("Could not find the day modules in src/lib.rs")?;
+    lines.insert(last_module + 1, &module);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the module of the day and its entry in `PUZZLES` to the source of `src/lib.rs`, or of the
+/// module of another year, in the same order as the days that are already there.
+fn register(lib: &str, day: u8) -> Result<String, String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!("    &day{day}::Day{day},");
+
+    if lib.lines().any(|line| line == module) {
+        return Err(format!("Day {day} is already registered in src/lib.rs"));
+    }
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+
+    // Modules are sorted the way rustfmt sorts them, so `day10` comes before `day2`.
+    let modules = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day"))
+        .ok_or("Could not find the day modules in src/lib.rs")?;
+    let module_position = lines[modules..]
+        .iter()
+        .position(|line| !line.starts_with("pub mod day") || *line > module.as_str())
+        .map(|offset| modules + offset)
+        .unwrap();
+    lines.insert(module_position, &module);
+
+    // Puzzles are listed in calendar order.
+    let puzzles = lines
+        .iter()
+        .position(|line| line.starts_with("pub static PUZZLES"))
+        .ok_or("Could not find PUZZLES in src/lib.rs")?;
+    let entry_position = lines[puzzles + 1..]
+        .iter()
+        .position(|line| puzzle_day(line).is_none_or(|other| other > day))
+        .map(|offset| puzzles + 1 + offset)
+        .unwrap();
+    lines.insert(entry_position, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The day of an entry in `PUZZLES`, such as `    &day7::Day7,`.
+fn puzzle_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("&day")?
+        .split_once("::")?
+        .0
+        .parse()
+        .ok()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Could not read {}: {error}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)
+            .map_err(|error| format!("Could not create {}: {error}", directory.display()))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|error| format!("Could not write {}: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+pub mod solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day3;
+
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &day1::Day1,
+    &day3::Day3,
+    &day10::Day10,
+];
+";
+
+    #[test]
+    fn registers_a_day_in_order() {
+        assert_eq!(
+            "\
+pub mod solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day10::Day10,
+];
+",
+            register(LIB, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn registers_a_day_after_all_others() {
+        let lib = register(LIB, 25).unwrap();
+
+        assert!(lib.contains("pub mod day10;\npub mod day25;\npub mod day3;"));
+        assert!(lib.contains("    &day10::Day10,\n    &day25::Day25,\n];"));
+    }
+
+    #[test]
+    fn refuses_to_register_a_day_twice() {
+        assert!(register(LIB, 3).is_err());
+    }
+
+    #[test]
+    fn instantiates_the_stub() {
+        let source = instantiate(2023, 7);
+
+        assert!(source.starts_with("//! https://adventofcode.com/2023/day/7\n"));
+        assert!(source.contains("impl Solution for Day7 {"));
+        assert!(source.contains("use crate::testing::{example, input, unknown};"));
+        assert!(source.contains("part1(&example(7))"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn puts_days_of_other_years_in_a_module_of_their_own() {
+        let source = instantiate(2024, 3);
+        let year = instantiate_year(2024, 3);
+
+        assert!(source.starts_with("//! https://adventofcode.com/2024/day/3\n"));
+        assert!(source.contains("use crate::y2024::testing::{example, input, unknown};"));
+        assert!(year.contains("pub mod day3;"));
+        assert!(year.contains("    &day3::Day3,\n];"));
+        assert!(year.contains("example_path_in(2024, day, 1)"));
+        assert!(!year.contains("{{"));
+
+        assert!(register(&year, 1)
+            .unwrap()
+            .contains("pub mod day1;\npub mod day3;"));
+        assert!(register_year(LIB, 2024)
+            .unwrap()
+            .contains("pub mod day3;\npub mod y2024;\n\npub static"));
+        assert!(register_year(LIB, 2024).is_ok_and(|lib| register_year(&lib, 2024).is_err()));
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    fmt::Display,
+    hint,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    animation::Recording,
+    bench::Stage,
+    cancel,
+    parsing::{self, ParseError},
+    render::Picture,
+};
+
+/// The answer to a single part of a puzzle. Serialised as a bare number or string, and an
+/// unimplemented part as `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unimplemented,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day of the advent calendar.
+///
+/// The raw puzzle input is parsed once, after which both parts are solved from the parsed input.
+/// The runner [`parsing::normalise`]s the input before it's handed to [`Solution::parse`].
+/// Malformed input is reported with a [`ParseError`] about the offending part of the input.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Answer;
+
+    /// A picture of the input, for days that can be drawn with `aoc run --render`.
+    fn render(_input: &Self::Input<'_>) -> Option<Picture> {
+        None
+    }
+
+    /// Records the simulation of the day for `aoc run --animate`. Days that don't simulate
+    /// anything return `false`.
+    fn animate(_input: &Self::Input<'_>, _recording: &mut Recording) -> bool {
+        false
+    }
+}
+
+/// Why a part didn't come up with an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    /// The part was cancelled when it ran out of its time budget.
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut(budget) => write!(f, "timed out after {budget:?}"),
+        }
+    }
+}
+
+/// The result of solving a single part.
+#[derive(Debug, Clone)]
+pub struct PartOutcome {
+    pub part: Part,
+    pub result: Result<Answer, Failure>,
+    pub duration: Duration,
+}
+
+/// The durations of every run of a stage, or why the stage stopped early.
+pub type Timings = Vec<(Stage, Result<Vec<Duration>, Failure>)>;
+
+/// Object safe version of [`Solution`], so every day can be driven by the runner.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    /// Solves the given parts, unless the input can't be parsed. Parts that check for
+    /// [`cancel::Token`]s are cancelled when they take longer than the budget.
+    fn solve(
+        &self,
+        input: &str,
+        parts: &[Part],
+        budget: Option<Duration>,
+    ) -> Result<Vec<PartOutcome>, ParseError>;
+
+    /// Draws the input, if the day supports it.
+    fn render(&self, input: &str) -> Result<Option<Picture>, String>;
+
+    /// Records the simulation of the day, if it has one.
+    fn animate(&self, input: &str, recording: &mut Recording) -> Result<bool, String>;
+
+    /// Parses the input and solves the given parts `runs` times each, timing every run.
+    /// Unimplemented parts are left out after their first run, and parts stop at the first run
+    /// that takes longer than the budget.
+    fn time(
+        &self,
+        input: &str,
+        parts: &[Part],
+        runs: usize,
+        budget: Option<Duration>,
+    ) -> Result<Timings, String>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        parts: &[Part],
+        budget: Option<Duration>,
+    ) -> Result<Vec<PartOutcome>, ParseError> {
+        let input = parsing::normalise(input);
+        let parsed = match catch_panic(|| S::parse(&input)) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(error)) => return Err(input.locate(error).in_day(S::DAY)),
+            Err(message) => {
+                return Ok(parts
+                    .iter()
+                    .map(|&part| PartOutcome {
+                        part,
+                        result: Err(Failure::Panicked(message.clone())),
+                        duration: Duration::ZERO,
+                    })
+                    .collect())
+            }
+        };
+
+        let outcomes = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let result = catch_panic(|| {
+                    cancel::with_budget(budget, || match part {
+                        Part::One => S::part1(&parsed),
+                        Part::Two => S::part2(&parsed),
+                    })
+                });
+                let result = match result {
+                    Ok(Some(answer)) => Ok(answer),
+                    Ok(None) => Err(Failure::TimedOut(budget.unwrap_or_default())),
+                    Err(message) => Err(Failure::Panicked(message)),
+                };
+
+                PartOutcome {
+                    part,
+                    result,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(outcomes)
+    }
+
+    fn render(&self, input: &str) -> Result<Option<Picture>, String> {
+        let input = parsing::normalise(input);
+        let parsed = catch_panic(|| S::parse(&input))?
+            .map_err(|error| input.locate(error).in_day(S::DAY).to_string())?;
+
+        catch_panic(|| S::render(&parsed))
+    }
+
+    fn animate(&self, input: &str, recording: &mut Recording) -> Result<bool, String> {
+        let input = parsing::normalise(input);
+        let parsed = catch_panic(|| S::parse(&input))?
+            .map_err(|error| input.locate(error).in_day(S::DAY).to_string())?;
+
+        catch_panic(|| S::animate(&parsed, recording))
+    }
+
+    fn time(
+        &self,
+        input: &str,
+        parts: &[Part],
+        runs: usize,
+        budget: Option<Duration>,
+    ) -> Result<Timings, String> {
+        let input = parsing::normalise(input);
+        let parsed = catch_panic(|| S::parse(&input))?
+            .map_err(|error| input.locate(error).in_day(S::DAY).to_string())?;
+
+        catch_panic(|| {
+            let mut timings = vec![(Stage::Parse, Ok(time_runs(runs, || S::parse(&input))))];
+
+            'parts: for &part in parts {
+                // One watchdog for all runs, as starting a thread per run costs more than the
+                // quickest parts take. A run that overran stops the stage.
+                let durations = cancel::with_budget_per_run(budget, |each| {
+                    let mut durations = vec![];
+
+                    for _ in 0..runs {
+                        each.start();
+                        let start = Instant::now();
+                        let answer = match part {
+                            Part::One => S::part1(&parsed),
+                            Part::Two => S::part2(&parsed),
+                        };
+                        durations.push(start.elapsed());
+
+                        if answer == Answer::Unimplemented {
+                            return None;
+                        }
+
+                        if cancel::Token::current().is_cancelled() {
+                            break;
+                        }
+                    }
+
+                    Some(durations)
+                });
+
+                let durations = match durations {
+                    Some(Some(durations)) => Ok(durations),
+                    Some(None) => continue 'parts,
+                    None => Err(Failure::TimedOut(budget.unwrap_or_default())),
+                };
+
+                timings.push((Stage::Part(part), durations));
+            }
+
+            timings
+        })
+    }
+}
+
+fn time_runs<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            hint::black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        }
+    })
+}
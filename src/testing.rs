@@ -0,0 +1,40 @@
+//! Fixtures and helpers shared by the tests of every day. Fixtures are normalised the way the
+//! runner normalises inputs, so they can be saved with any line endings.
+
+use std::{fmt::Display, fs, path::Path};
+
+use crate::{
+    input::{default_path, example_path},
+    parsing,
+};
+
+/// The example from the puzzle description, stored in `inputs/examples/dayNN.txt`.
+pub fn example(day: u8) -> String {
+    read(&example_path(day, 1))
+}
+
+/// The second example of days whose parts are explained with different examples, stored in
+/// `inputs/examples/dayNN-2.txt`.
+pub fn second_example(day: u8) -> String {
+    read(&example_path(day, 2))
+}
+
+/// The actual puzzle input, stored in `inputs/dayNN.txt`.
+pub fn input(day: u8) -> String {
+    read(&default_path(day))
+}
+
+/// Takes the place of an assertion for a part whose correct answer we don't know yet. The
+/// computed answer is printed, so it can be checked with `cargo test -- --ignored --nocapture`.
+pub fn unknown(answer: impl Display) {
+    println!("Computed {answer}, but the correct answer is unknown");
+}
+
+fn read(path: &Path) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+
+    let fixture = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Could not read fixture {}: {error}", path.display()));
+
+    parsing::normalise(&fixture).to_string()
+}
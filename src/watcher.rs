@@ -0,0 +1,103 @@
+//! Work that runs with some state in a thread-local, while a watcher thread looks after it. The
+//! runner uses this to give every part its cancellation token and its progress, which the solver
+//! finds through the thread-local and the watcher acts on from the side.
+
+use std::{
+    cell::RefCell,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread::{self, LocalKey},
+    time::Duration,
+};
+
+/// The thread-local that holds the state of the work that runs on this thread.
+pub type Current<S> = LocalKey<RefCell<Option<S>>>;
+
+/// Tells a watcher whether the work it watches is still running.
+#[derive(Debug)]
+pub struct Running(Receiver<()>);
+
+impl Running {
+    /// Waits until `timeout` has passed or the work is done, whichever comes first. Returns
+    /// whether the work is still running.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        matches!(self.0.recv_timeout(timeout), Err(RecvTimeoutError::Timeout))
+    }
+}
+
+/// Runs `f` with `state` in `current`, while `watcher` runs on another thread. The previous state
+/// is put back once `f` returns, even when it panics, and the watcher is joined before returning.
+pub fn watch<S: 'static, T>(
+    current: &'static Current<S>,
+    state: S,
+    watcher: impl FnOnce(Running) + Send + 'static,
+    f: impl FnOnce() -> T,
+) -> T {
+    struct Restore<S: 'static> {
+        current: &'static Current<S>,
+        previous: Option<S>,
+    }
+
+    impl<S> Drop for Restore<S> {
+        fn drop(&mut self) {
+            self.current.set(self.previous.take());
+        }
+    }
+
+    let (done, finished) = mpsc::channel::<()>();
+    let watcher = thread::spawn(move || watcher(Running(finished)));
+
+    let restore = Restore {
+        current,
+        previous: current.replace(Some(state)),
+    };
+    let result = f();
+    drop(restore);
+
+    // Hanging up wakes the watcher, so it doesn't outlive the work.
+    drop(done);
+    let _ = watcher.join();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static NAME: RefCell<Option<&'static str>> = const { RefCell::new(None) };
+    }
+
+    #[test]
+    fn sets_the_state_while_the_work_runs() {
+        let name = watch(
+            &NAME,
+            "outer",
+            |_| {},
+            || watch(&NAME, "inner", |_| {}, || NAME.with_borrow(|name| *name)),
+        );
+
+        assert_eq!(Some("inner"), name);
+        assert_eq!(None, NAME.with_borrow(|name| *name));
+    }
+
+    #[test]
+    fn stops_waiting_once_the_work_is_done() {
+        let (ticks, counted) = mpsc::channel();
+
+        watch(
+            &NAME,
+            "counting",
+            move |running| {
+                let mut count = 0;
+                while running.wait(Duration::from_millis(1)) {
+                    count += 1;
+                }
+                ticks.send(count).unwrap();
+            },
+            || thread::sleep(Duration::from_millis(20)),
+        );
+
+        assert!(counted.recv().unwrap() > 0);
+    }
+}